@@ -17,6 +17,7 @@ pub struct KeeperConfig {
 pub struct KeeperSettings {
     pub wallet_path: String,
     pub stake_amount: u64,
+    pub keeper_tier: Option<u8>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -25,6 +26,7 @@ pub struct RpcSettings {
     pub fallback_urls: Vec<String>,
     pub request_timeout_ms: Option<u64>,
     pub max_retries: Option<u32>,
+    pub ws_url: Option<String>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -33,6 +35,8 @@ pub struct MonitoringSettings {
     pub max_concurrent_jobs: usize,
     pub job_cache_ttl_seconds: u64,
     pub enable_websocket: Option<bool>,
+    pub failure_backoff_base_seconds: Option<u64>,
+    pub max_failure_backoff_seconds: Option<u64>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -42,6 +46,9 @@ pub struct ExecutionSettings {
     pub retry_delay_ms: u64,
     pub max_compute_units: u32,
     pub simulation_enabled: Option<bool>,
+    pub reaper_enabled: Option<bool>,
+    pub reap_interval_seconds: Option<u64>,
+    pub job_reap_delay_seconds: Option<i64>,
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -138,14 +145,56 @@ impl KeeperConfig {
         std::time::Duration::from_millis(self.execution.retry_delay_ms)
     }
 
+    /// WebSocket endpoint for subscriptions, derived from the primary URL if unset
+    pub fn get_ws_url(&self) -> String {
+        match &self.rpc.ws_url {
+            Some(url) => url.clone(),
+            None => self.rpc.primary_url
+                .replacen("https://", "wss://", 1)
+                .replacen("http://", "ws://", 1),
+        }
+    }
+
+    /// Highest keeper tier this node's stake and reputation qualify for
+    pub fn get_keeper_tier(&self) -> u8 {
+        self.keeper.keeper_tier.unwrap_or(0)
+    }
+
     pub fn websocket_enabled(&self) -> bool {
         self.monitoring.enable_websocket.unwrap_or(true)
     }
 
+    /// Backoff before re-checking a failing job, doubling with each consecutive failure
+    pub fn get_failure_backoff(&self, consecutive_failures: u32) -> std::time::Duration {
+        if consecutive_failures == 0 {
+            return std::time::Duration::ZERO;
+        }
+
+        let base = self.monitoring.failure_backoff_base_seconds.unwrap_or(30);
+        let max = self.monitoring.max_failure_backoff_seconds.unwrap_or(3600);
+        let exponent = (consecutive_failures - 1).min(16);
+        std::time::Duration::from_secs(base.saturating_mul(1 << exponent).min(max))
+    }
+
     pub fn simulation_enabled(&self) -> bool {
         self.execution.simulation_enabled.unwrap_or(true)
     }
 
+    /// Whether to crank `reap_job` on dead jobs for the rent bounty
+    pub fn reaper_enabled(&self) -> bool {
+        self.execution.reaper_enabled.unwrap_or(false)
+    }
+
+    pub fn get_reap_interval(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(self.execution.reap_interval_seconds.unwrap_or(3600))
+    }
+
+    /// Seconds a drained inactive job must wait before it can be reaped; should
+    /// match the registry's `job_reap_delay`
+    pub fn get_job_reap_delay(&self) -> i64 {
+        self.execution.job_reap_delay_seconds.unwrap_or(2_592_000)
+    }
+
     pub fn get_max_db_connections(&self) -> u32 {
         self.database.max_connections.unwrap_or(10)
     }
@@ -1,4 +1,5 @@
 use sqlx::{PgPool, Pool, Postgres, Row};
+use sqlx::postgres::PgRow;
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
 use crate::config::KeeperConfig;
@@ -20,9 +21,82 @@ pub struct JobRecord {
     pub last_executed: Option<DateTime<Utc>>,
     pub execution_count: i64,
     pub failed_count: i64,
+    pub consecutive_failures: i32,
+    pub is_paused: bool,
+    pub next_scheduled_slot: Option<i64>,
+    pub next_epoch: Option<i64>,
+    /// Base58 hash of the watched account's data at the last execution
+    pub last_data_hash: Option<String>,
+    /// Target view instruction simulated before each run
+    pub check_instruction: Option<String>,
+    /// Lamports paid to the executing keeper on top of the base fee
+    pub tip_lamports: i64,
+    /// Execution fee cap (0 = no cap)
+    pub max_fee_per_execution: i64,
+    /// Funding pool paying the job's fees, if any
+    pub funding_pool: Option<String>,
+    /// Display name from the job's metadata account
+    pub name: Option<String>,
+    /// Tags from the job's metadata account
+    pub tags: Vec<String>,
+    /// Off-chain metadata document from the job's metadata account
+    pub metadata_uri: Option<String>,
+    /// Lowest keeper tier allowed to execute (0 = any keeper)
+    pub min_keeper_tier: i16,
+    /// Unix time after which the job expires (0 = never)
+    pub end_at: i64,
+    /// Unix time the job was cancelled or deactivated (0 = active)
+    pub deactivated_at: i64,
+    /// Stored target accounts as `[{pubkey, is_signer, is_writable}]`
+    pub target_accounts: serde_json::Value,
     pub cached_data: Option<serde_json::Value>,
 }
 
+/// Columns selected when loading a `JobRecord`
+const JOB_COLUMNS: &str = "job_id, owner, target_program, target_instruction, trigger_type, \
+    trigger_params, balance, gas_limit, min_balance, is_active, \
+    last_checked, last_executed, execution_count, failed_count, \
+    consecutive_failures, is_paused, next_scheduled_slot, next_epoch, last_data_hash, check_instruction, \
+    tip_lamports, max_fee_per_execution, funding_pool, name, tags, metadata_uri, min_keeper_tier, end_at, deactivated_at, target_accounts, cached_data";
+
+impl JobRecord {
+    fn from_row(row: &PgRow) -> Self {
+        JobRecord {
+            job_id: row.get("job_id"),
+            owner: row.get("owner"),
+            target_program: row.get("target_program"),
+            target_instruction: row.get("target_instruction"),
+            trigger_type: row.get("trigger_type"),
+            trigger_params: row.get("trigger_params"),
+            balance: row.get("balance"),
+            gas_limit: row.get("gas_limit"),
+            min_balance: row.get("min_balance"),
+            is_active: row.get("is_active"),
+            last_checked: row.get("last_checked"),
+            last_executed: row.get("last_executed"),
+            execution_count: row.get("execution_count"),
+            failed_count: row.get("failed_count"),
+            consecutive_failures: row.get("consecutive_failures"),
+            is_paused: row.get("is_paused"),
+            next_scheduled_slot: row.get("next_scheduled_slot"),
+            next_epoch: row.get("next_epoch"),
+            last_data_hash: row.get("last_data_hash"),
+            check_instruction: row.get("check_instruction"),
+            tip_lamports: row.get("tip_lamports"),
+            max_fee_per_execution: row.get("max_fee_per_execution"),
+            funding_pool: row.get("funding_pool"),
+            name: row.get("name"),
+            tags: row.get("tags"),
+            metadata_uri: row.get("metadata_uri"),
+            min_keeper_tier: row.get("min_keeper_tier"),
+            end_at: row.get("end_at"),
+            deactivated_at: row.get("deactivated_at"),
+            target_accounts: row.get("target_accounts"),
+            cached_data: row.get("cached_data"),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ExecutionRecord {
     pub id: i32,
@@ -32,8 +106,10 @@ pub struct ExecutionRecord {
     pub success: bool,
     pub signature: Option<String>,
     pub error: Option<String>,
+    pub error_code: Option<i64>,
     pub gas_used: Option<i64>,
     pub fee_paid: Option<i64>,
+    pub return_data: Option<Vec<u8>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +153,22 @@ impl Database {
                 last_executed TIMESTAMP WITH TIME ZONE,
                 execution_count BIGINT NOT NULL DEFAULT 0,
                 failed_count BIGINT NOT NULL DEFAULT 0,
+                consecutive_failures INTEGER NOT NULL DEFAULT 0,
+                is_paused BOOLEAN NOT NULL DEFAULT false,
+                next_scheduled_slot BIGINT,
+                next_epoch BIGINT,
+                last_data_hash TEXT,
+                check_instruction TEXT,
+                tip_lamports BIGINT NOT NULL DEFAULT 0,
+                max_fee_per_execution BIGINT NOT NULL DEFAULT 0,
+                funding_pool TEXT,
+                name TEXT,
+                tags TEXT[] NOT NULL DEFAULT '{}',
+                metadata_uri TEXT,
+                min_keeper_tier SMALLINT NOT NULL DEFAULT 0,
+                end_at BIGINT NOT NULL DEFAULT 0,
+                deactivated_at BIGINT NOT NULL DEFAULT 0,
+                target_accounts JSONB NOT NULL DEFAULT '[]',
                 cached_data JSONB,
                 created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW(),
                 updated_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
@@ -84,6 +176,80 @@ impl Database {
             "#
         ).execute(pool).await?;
 
+        // Add failure tracking to jobs tables created before it existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS consecutive_failures INTEGER NOT NULL DEFAULT 0"
+        ).execute(pool).await?;
+
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS is_paused BOOLEAN NOT NULL DEFAULT false"
+        ).execute(pool).await?;
+
+        // Add slot and epoch schedules to jobs tables created before they existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS next_scheduled_slot BIGINT"
+        ).execute(pool).await?;
+
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS next_epoch BIGINT"
+        ).execute(pool).await?;
+
+        // Add account-change tracking to jobs tables created before it existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS last_data_hash TEXT"
+        ).execute(pool).await?;
+
+        // Add check instructions to jobs tables created before they existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS check_instruction TEXT"
+        ).execute(pool).await?;
+
+        // Add keeper tips and fee caps to jobs tables created before they existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS tip_lamports BIGINT NOT NULL DEFAULT 0"
+        ).execute(pool).await?;
+
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS max_fee_per_execution BIGINT NOT NULL DEFAULT 0"
+        ).execute(pool).await?;
+
+        // Add funding pools to jobs tables created before they existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS funding_pool TEXT"
+        ).execute(pool).await?;
+
+        // Add job metadata to jobs tables created before it existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS name TEXT"
+        ).execute(pool).await?;
+
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS tags TEXT[] NOT NULL DEFAULT '{}'"
+        ).execute(pool).await?;
+
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS metadata_uri TEXT"
+        ).execute(pool).await?;
+
+        // Add keeper tiers to jobs tables created before they existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS min_keeper_tier SMALLINT NOT NULL DEFAULT 0"
+        ).execute(pool).await?;
+
+        // Add job expiry to jobs tables created before it existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS end_at BIGINT NOT NULL DEFAULT 0"
+        ).execute(pool).await?;
+
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS deactivated_at BIGINT NOT NULL DEFAULT 0"
+        ).execute(pool).await?;
+
+        // Add stored target accounts to jobs tables created before they existed
+        sqlx::query(
+            "ALTER TABLE jobs ADD COLUMN IF NOT EXISTS target_accounts JSONB NOT NULL DEFAULT '[]'"
+        ).execute(pool).await?;
+
         // Create executions table
         sqlx::query(
             r#"
@@ -95,13 +261,24 @@ impl Database {
                 success BOOLEAN NOT NULL,
                 signature TEXT,
                 error TEXT,
+                error_code BIGINT,
                 gas_used BIGINT,
                 fee_paid BIGINT,
+                return_data BYTEA,
                 created_at TIMESTAMP WITH TIME ZONE DEFAULT NOW()
             )
             "#
         ).execute(pool).await?;
 
+        // Add error_code to executions tables created before it existed
+        sqlx::query(
+            "ALTER TABLE executions ADD COLUMN IF NOT EXISTS error_code BIGINT"
+        ).execute(pool).await?;
+
+        sqlx::query(
+            "ALTER TABLE executions ADD COLUMN IF NOT EXISTS return_data BYTEA"
+        ).execute(pool).await?;
+
         // Create keeper_stats table
         sqlx::query(
             r#"
@@ -122,6 +299,9 @@ impl Database {
         sqlx::query("CREATE INDEX IF NOT EXISTS idx_jobs_last_checked ON jobs(last_checked)")
             .execute(pool).await?;
         
+        sqlx::query("CREATE INDEX IF NOT EXISTS idx_jobs_tags ON jobs USING GIN(tags)")
+            .execute(pool).await?;
+        
         sqlx::query("CREATE INDEX IF NOT EXISTS idx_executions_job_id ON executions(job_id)")
             .execute(pool).await?;
         
@@ -137,8 +317,10 @@ impl Database {
             INSERT INTO jobs (
                 job_id, owner, target_program, target_instruction, trigger_type, 
                 trigger_params, balance, gas_limit, min_balance, is_active,
-                last_executed, execution_count, failed_count, cached_data
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14)
+                last_executed, execution_count, failed_count, consecutive_failures,
+                is_paused, next_scheduled_slot, next_epoch, last_data_hash, check_instruction,
+                tip_lamports, max_fee_per_execution, funding_pool, name, tags, metadata_uri, min_keeper_tier, end_at, deactivated_at, target_accounts, cached_data
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18, $19, $20, $21, $22, $23, $24, $25, $26, $27, $28, $29, $30)
             ON CONFLICT (job_id) DO UPDATE SET
                 owner = EXCLUDED.owner,
                 target_program = EXCLUDED.target_program,
@@ -152,6 +334,22 @@ impl Database {
                 last_executed = EXCLUDED.last_executed,
                 execution_count = EXCLUDED.execution_count,
                 failed_count = EXCLUDED.failed_count,
+                consecutive_failures = EXCLUDED.consecutive_failures,
+                is_paused = EXCLUDED.is_paused,
+                next_scheduled_slot = EXCLUDED.next_scheduled_slot,
+                next_epoch = EXCLUDED.next_epoch,
+                last_data_hash = EXCLUDED.last_data_hash,
+                check_instruction = EXCLUDED.check_instruction,
+                tip_lamports = EXCLUDED.tip_lamports,
+                max_fee_per_execution = EXCLUDED.max_fee_per_execution,
+                funding_pool = EXCLUDED.funding_pool,
+                name = EXCLUDED.name,
+                tags = EXCLUDED.tags,
+                metadata_uri = EXCLUDED.metadata_uri,
+                min_keeper_tier = EXCLUDED.min_keeper_tier,
+                end_at = EXCLUDED.end_at,
+                deactivated_at = EXCLUDED.deactivated_at,
+                target_accounts = EXCLUDED.target_accounts,
                 cached_data = EXCLUDED.cached_data,
                 updated_at = NOW()
             "#
@@ -169,6 +367,22 @@ impl Database {
         .bind(job.last_executed)
         .bind(job.execution_count)
         .bind(job.failed_count)
+        .bind(job.consecutive_failures)
+        .bind(job.is_paused)
+        .bind(job.next_scheduled_slot)
+        .bind(job.next_epoch)
+        .bind(&job.last_data_hash)
+        .bind(&job.check_instruction)
+        .bind(job.tip_lamports)
+        .bind(job.max_fee_per_execution)
+        .bind(&job.funding_pool)
+        .bind(&job.name)
+        .bind(&job.tags)
+        .bind(&job.metadata_uri)
+        .bind(job.min_keeper_tier)
+        .bind(job.end_at)
+        .bind(job.deactivated_at)
+        .bind(&job.target_accounts)
         .bind(&job.cached_data)
         .execute(&self.pool)
         .await?;
@@ -177,87 +391,96 @@ impl Database {
     }
 
     pub async fn get_active_jobs(&self) -> KeeperResult<Vec<JobRecord>> {
-        let rows = sqlx::query(
+        let rows = sqlx::query(&format!(
             r#"
-            SELECT job_id, owner, target_program, target_instruction, trigger_type,
-                   trigger_params, balance, gas_limit, min_balance, is_active,
-                   last_checked, last_executed, execution_count, failed_count, cached_data
+            SELECT {}
             FROM jobs 
             WHERE is_active = true 
             ORDER BY last_checked ASC NULLS FIRST
-            "#
-        )
+            "#,
+            JOB_COLUMNS
+        ))
         .fetch_all(&self.pool)
         .await?;
 
-        let mut jobs = Vec::new();
-        for row in rows {
-            jobs.push(JobRecord {
-                job_id: row.get("job_id"),
-                owner: row.get("owner"),
-                target_program: row.get("target_program"),
-                target_instruction: row.get("target_instruction"),
-                trigger_type: row.get("trigger_type"),
-                trigger_params: row.get("trigger_params"),
-                balance: row.get("balance"),
-                gas_limit: row.get("gas_limit"),
-                min_balance: row.get("min_balance"),
-                is_active: row.get("is_active"),
-                last_checked: row.get("last_checked"),
-                last_executed: row.get("last_executed"),
-                execution_count: row.get("execution_count"),
-                failed_count: row.get("failed_count"),
-                cached_data: row.get("cached_data"),
-            });
-        }
+        Ok(rows.iter().map(JobRecord::from_row).collect())
+    }
+
+    /// Jobs carrying `tag` in their metadata, active or not
+    pub async fn get_jobs_by_tag(&self, tag: &str) -> KeeperResult<Vec<JobRecord>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM jobs 
+            WHERE $1 = ANY(tags)
+            ORDER BY job_id ASC
+            "#,
+            JOB_COLUMNS
+        ))
+        .bind(tag)
+        .fetch_all(&self.pool)
+        .await?;
 
-        Ok(jobs)
+        Ok(rows.iter().map(JobRecord::from_row).collect())
     }
 
     pub async fn get_eligible_jobs(&self, keeper_address: &str) -> KeeperResult<Vec<JobRecord>> {
         let now = Utc::now();
         
-        let rows = sqlx::query(
+        let rows = sqlx::query(&format!(
             r#"
-            SELECT job_id, owner, target_program, target_instruction, trigger_type,
-                   trigger_params, balance, gas_limit, min_balance, is_active,
-                   last_checked, last_executed, execution_count, failed_count, cached_data
+            SELECT {}
             FROM jobs 
             WHERE is_active = true 
+              AND is_paused = false
               AND balance > min_balance
+              AND (end_at = 0 OR end_at > $2)
               AND (last_checked IS NULL OR last_checked < $1 - INTERVAL '30 seconds')
             ORDER BY 
               CASE WHEN last_executed IS NULL THEN 0 ELSE 1 END,
               last_executed ASC NULLS FIRST
             LIMIT 50
-            "#
-        )
+            "#,
+            JOB_COLUMNS
+        ))
         .bind(now)
+        .bind(now.timestamp())
         .fetch_all(&self.pool)
         .await?;
 
-        let mut jobs = Vec::new();
-        for row in rows {
-            jobs.push(JobRecord {
-                job_id: row.get("job_id"),
-                owner: row.get("owner"),
-                target_program: row.get("target_program"),
-                target_instruction: row.get("target_instruction"),
-                trigger_type: row.get("trigger_type"),
-                trigger_params: row.get("trigger_params"),
-                balance: row.get("balance"),
-                gas_limit: row.get("gas_limit"),
-                min_balance: row.get("min_balance"),
-                is_active: row.get("is_active"),
-                last_checked: row.get("last_checked"),
-                last_executed: row.get("last_executed"),
-                execution_count: row.get("execution_count"),
-                failed_count: row.get("failed_count"),
-                cached_data: row.get("cached_data"),
-            });
-        }
+        Ok(rows.iter().map(JobRecord::from_row).collect())
+    }
+
+    /// Jobs the registry would let anyone reap: past their end time, or
+    /// inactive with no balance since before `reap_delay` seconds ago
+    pub async fn get_reapable_jobs(&self, now: i64, reap_delay: i64) -> KeeperResult<Vec<JobRecord>> {
+        let rows = sqlx::query(&format!(
+            r#"
+            SELECT {}
+            FROM jobs
+            WHERE (end_at > 0 AND end_at <= $1)
+               OR (is_active = false AND balance = 0 AND deactivated_at <= $1 - $2)
+            ORDER BY job_id ASC
+            LIMIT 50
+            "#,
+            JOB_COLUMNS
+        ))
+        .bind(now)
+        .bind(reap_delay)
+        .fetch_all(&self.pool)
+        .await?;
+
+        Ok(rows.iter().map(JobRecord::from_row).collect())
+    }
+
+    /// Forget a job whose account has been closed
+    pub async fn delete_job(&self, job_id: i64) -> KeeperResult<()> {
+        sqlx::query("DELETE FROM jobs WHERE job_id = $1")
+            .bind(job_id)
+            .execute(&self.pool)
+            .await?;
 
-        Ok(jobs)
+        Ok(())
     }
 
     pub async fn update_job_checked(&self, job_id: i64) -> KeeperResult<()> {
@@ -271,13 +494,34 @@ impl Database {
         Ok(())
     }
 
+    /// Update a job's execution and failure counters after an attempt
+    pub async fn record_job_outcome(&self, job_id: i64, success: bool) -> KeeperResult<()> {
+        sqlx::query(
+            r#"
+            UPDATE jobs SET
+                execution_count = execution_count + CASE WHEN $2 THEN 1 ELSE 0 END,
+                failed_count = failed_count + CASE WHEN $2 THEN 0 ELSE 1 END,
+                consecutive_failures = CASE WHEN $2 THEN 0 ELSE consecutive_failures + 1 END,
+                last_executed = CASE WHEN $2 THEN NOW() ELSE last_executed END,
+                updated_at = NOW()
+            WHERE job_id = $1
+            "#
+        )
+        .bind(job_id)
+        .bind(success)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
     pub async fn record_execution(&self, execution: &ExecutionRecord) -> KeeperResult<()> {
         sqlx::query(
             r#"
             INSERT INTO executions (
                 job_id, keeper_address, timestamp, success, 
-                signature, error, gas_used, fee_paid
-            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8)
+                signature, error, error_code, gas_used, fee_paid, return_data
+            ) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
             "#
         )
         .bind(execution.job_id)
@@ -286,8 +530,10 @@ impl Database {
         .bind(execution.success)
         .bind(&execution.signature)
         .bind(&execution.error)
+        .bind(execution.error_code)
         .bind(execution.gas_used)
         .bind(execution.fee_paid)
+        .bind(&execution.return_data)
         .execute(&self.pool)
         .await?;
 
@@ -330,7 +576,7 @@ impl Database {
         let rows = sqlx::query(
             r#"
             SELECT id, job_id, keeper_address, timestamp, success, 
-                   signature, error, gas_used, fee_paid
+                   signature, error, error_code, gas_used, fee_paid, return_data
             FROM executions 
             WHERE job_id = $1 
             ORDER BY timestamp DESC 
@@ -352,8 +598,10 @@ impl Database {
                 success: row.get("success"),
                 signature: row.get("signature"),
                 error: row.get("error"),
+                error_code: row.get("error_code"),
                 gas_used: row.get("gas_used"),
                 fee_paid: row.get("fee_paid"),
+                return_data: row.get("return_data"),
             });
         }
 
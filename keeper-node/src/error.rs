@@ -1,4 +1,6 @@
 use thiserror::Error;
+use solana_sdk::instruction::InstructionError;
+use solana_sdk::transaction::TransactionError;
 
 #[derive(Error, Debug)]
 pub enum KeeperError {
@@ -72,4 +74,25 @@ impl From<anchor_lang::error::Error> for KeeperError {
     }
 }
 
+impl KeeperError {
+    /// Program error code of the failed instruction, if the error came from a transaction
+    pub fn program_error_code(&self) -> Option<u32> {
+        match self {
+            KeeperError::SolanaClientError(e) => e
+                .get_transaction_error()
+                .as_ref()
+                .and_then(transaction_error_code),
+            _ => None,
+        }
+    }
+}
+
+/// Extract the custom program error code from a transaction error
+pub fn transaction_error_code(error: &TransactionError) -> Option<u32> {
+    match error {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => Some(*code),
+        _ => None,
+    }
+}
+
 pub type KeeperResult<T> = Result<T, KeeperError>;
\ No newline at end of file
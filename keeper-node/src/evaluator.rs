@@ -1,12 +1,22 @@
 use serde_json::Value;
 use chrono::{DateTime, Utc};
 use log::{debug, warn};
+use std::sync::Arc;
+use base64::Engine;
 use crate::database::JobRecord;
 use crate::rpc::RpcManager;
+use crate::watcher::AccountWatcher;
+use crate::oracle::PriceComparison;
 use crate::error::{KeeperError, KeeperResult};
 
+/// Approximate slot duration used to turn slot distances into check times
+const APPROX_SLOT_MILLIS: i64 = 400;
+
 pub struct TriggerEvaluator {
     rpc_manager: RpcManager,
+    account_watcher: Option<Arc<AccountWatcher>>,
+    /// Highest keeper tier this node qualifies for
+    keeper_tier: u8,
 }
 
 #[derive(Debug, Clone)]
@@ -14,11 +24,13 @@ pub struct EvaluationResult {
     pub should_execute: bool,
     pub reason: String,
     pub next_check_time: Option<DateTime<Utc>>,
+    /// Data returned by the job's check instruction, forwarded to execute_job
+    pub perform_data: Vec<u8>,
 }
 
 impl TriggerEvaluator {
-    pub fn new(rpc_manager: RpcManager) -> Self {
-        Self { rpc_manager }
+    pub fn new(rpc_manager: RpcManager, account_watcher: Option<Arc<AccountWatcher>>, keeper_tier: u8) -> Self {
+        Self { rpc_manager, account_watcher, keeper_tier }
     }
 
     pub async fn evaluate_job(&self, job: &JobRecord) -> KeeperResult<EvaluationResult> {
@@ -30,6 +42,37 @@ impl TriggerEvaluator {
                 should_execute: false,
                 reason: "Job is not active".to_string(),
                 next_check_time: None,
+                perform_data: Vec::new(),
+            });
+        }
+
+        // Paused jobs keep their balance and schedule but must not run
+        if job.is_paused {
+            return Ok(EvaluationResult {
+                should_execute: false,
+                reason: "Job is paused".to_string(),
+                next_check_time: None,
+                perform_data: Vec::new(),
+            });
+        }
+
+        // Expired jobs can only be reaped, not executed
+        if job.end_at > 0 && now.timestamp() >= job.end_at {
+            return Ok(EvaluationResult {
+                should_execute: false,
+                reason: "Job has expired".to_string(),
+                next_check_time: None,
+                perform_data: Vec::new(),
+            });
+        }
+
+        // The registry rejects keepers below the job's tier, so don't spend fees trying
+        if job.min_keeper_tier > self.keeper_tier as i16 {
+            return Ok(EvaluationResult {
+                should_execute: false,
+                reason: format!("Job requires keeper tier {}", job.min_keeper_tier),
+                next_check_time: None,
+                perform_data: Vec::new(),
             });
         }
 
@@ -38,24 +81,119 @@ impl TriggerEvaluator {
                 should_execute: false,
                 reason: "Insufficient balance".to_string(),
                 next_check_time: None,
+                perform_data: Vec::new(),
             });
         }
 
         // Evaluate based on trigger type
-        match job.trigger_type.as_str() {
+        let mut result = match job.trigger_type.as_str() {
             "time" => self.evaluate_time_trigger(job, now).await,
             "conditional" => self.evaluate_conditional_trigger(job, now).await,
             "log" => self.evaluate_log_trigger(job, now).await,
             "hybrid" => self.evaluate_hybrid_trigger(job, now).await,
+            "slot" => self.evaluate_slot_trigger(job, now).await,
+            "epoch" => self.evaluate_epoch_trigger(job, now).await,
+            "account_change" => self.evaluate_account_change_trigger(job, now).await,
+            "price" => self.evaluate_price_trigger(job, now).await,
             _ => {
                 warn!("Unknown trigger type: {}", job.trigger_type);
                 Ok(EvaluationResult {
                     should_execute: false,
                     reason: format!("Unknown trigger type: {}", job.trigger_type),
                     next_check_time: None,
+                    perform_data: Vec::new(),
                 })
             }
+        }?;
+
+        // Jobs with a check instruction only run when the target reports work to do
+        if result.should_execute {
+            if let Some(check_instruction) = &job.check_instruction {
+                match self.simulate_check(job, check_instruction).await? {
+                    Some(perform_data) => result.perform_data = perform_data,
+                    None => {
+                        result.should_execute = false;
+                        result.reason = format!("Check {} reported no work", check_instruction);
+                        result.next_check_time = Some(now + chrono::Duration::seconds(30));
+                    }
+                }
+            }
         }
+
+        Ok(result)
+    }
+
+    /// Simulate the target's check instruction, returning its perform data if
+    /// work is needed. The check returns Borsh `(bool, Vec<u8>)` as return data.
+    async fn simulate_check(&self, job: &JobRecord, check_instruction: &str) -> KeeperResult<Option<Vec<u8>>> {
+        let target_program = job.target_program.parse::<solana_sdk::pubkey::Pubkey>()
+            .map_err(|e| KeeperError::InvalidJobError(format!("Invalid target program: {}", e)))?;
+        // The owner funds the job, so its account exists to pay for the simulation
+        let payer = job.owner.parse::<solana_sdk::pubkey::Pubkey>()
+            .map_err(|e| KeeperError::InvalidJobError(format!("Invalid job owner: {}", e)))?;
+
+        // Anchor discriminator of the check instruction, which reads the same
+        // accounts as the target instruction
+        let data = solana_sdk::hash::hash(format!("global:{}", check_instruction).as_bytes())
+            .to_bytes()[..8]
+            .to_vec();
+        let instruction = solana_sdk::instruction::Instruction {
+            program_id: target_program,
+            accounts: Self::check_accounts(job)?,
+            data,
+        };
+
+        let simulation = self.rpc_manager.simulate_instruction(instruction, &payer).await?;
+        if let Some(err) = simulation.err {
+            warn!("Check {} failed for job {}: {:?}", check_instruction, job.job_id, err);
+            return Ok(None);
+        }
+
+        let return_data = simulation.return_data
+            .filter(|return_data| return_data.program_id == job.target_program)
+            .ok_or_else(|| KeeperError::InvalidTriggerError(
+                format!("Check {} returned no data", check_instruction)
+            ))?;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(&return_data.data.0)
+            .map_err(|e| KeeperError::InvalidTriggerError(format!("Invalid check return data: {}", e)))?;
+        let (upkeep_needed, perform_data) = <(bool, Vec<u8>) as anchor_lang::AnchorDeserialize>::try_from_slice(&bytes)
+            .map_err(|e| KeeperError::InvalidTriggerError(format!("Invalid check return data: {}", e)))?;
+
+        debug!(
+            "Check {} for job {}: upkeep_needed={}, perform_data={} bytes",
+            check_instruction, job.job_id, upkeep_needed, perform_data.len()
+        );
+
+        Ok(upkeep_needed.then_some(perform_data))
+    }
+
+    /// The job's stored target accounts with signer flags dropped, since only
+    /// the payer signs the simulation
+    fn check_accounts(job: &JobRecord) -> KeeperResult<Vec<solana_sdk::instruction::AccountMeta>> {
+        let metas = job.target_accounts
+            .as_array()
+            .ok_or_else(|| KeeperError::InvalidJobError(
+                format!("Job {} has malformed target accounts", job.job_id)
+            ))?;
+
+        metas.iter()
+            .map(|meta| {
+                let pubkey = meta.get("pubkey")
+                    .and_then(|v| v.as_str())
+                    .and_then(|s| s.parse::<solana_sdk::pubkey::Pubkey>().ok())
+                    .ok_or_else(|| KeeperError::InvalidJobError(
+                        format!("Job {} has an invalid target account", job.job_id)
+                    ))?;
+                let is_writable = meta.get("is_writable").and_then(|v| v.as_bool()).unwrap_or(false);
+
+                Ok(if is_writable {
+                    solana_sdk::instruction::AccountMeta::new(pubkey, false)
+                } else {
+                    solana_sdk::instruction::AccountMeta::new_readonly(pubkey, false)
+                })
+            })
+            .collect()
     }
 
     async fn evaluate_time_trigger(
@@ -101,6 +239,7 @@ impl TriggerEvaluator {
             should_execute,
             reason,
             next_check_time,
+            perform_data: Vec::new(),
         })
     }
 
@@ -129,6 +268,7 @@ impl TriggerEvaluator {
             should_execute: result.0,
             reason: result.1,
             next_check_time: Some(Utc::now() + chrono::Duration::seconds(60)), // Check again in 1 minute
+            perform_data: Vec::new(),
         })
     }
 
@@ -163,6 +303,7 @@ impl TriggerEvaluator {
                 "Waiting for event".to_string()
             },
             next_check_time: Some(Utc::now() + chrono::Duration::seconds(30)), // Check again in 30 seconds
+            perform_data: Vec::new(),
         })
     }
 
@@ -228,6 +369,226 @@ impl TriggerEvaluator {
             should_execute,
             reason: reasons.join("; "),
             next_check_time: Some(now + chrono::Duration::seconds(30)),
+            perform_data: Vec::new(),
+        })
+    }
+
+    async fn evaluate_slot_trigger(
+        &self,
+        job: &JobRecord,
+        now: DateTime<Utc>,
+    ) -> KeeperResult<EvaluationResult> {
+        let params = &job.trigger_params;
+
+        let slot_interval = params
+            .get("slot_interval")
+            .and_then(|v| v.as_u64())
+            .filter(|interval| *interval > 0)
+            .ok_or_else(|| KeeperError::InvalidTriggerError(
+                "Missing or invalid slot_interval in slot trigger".to_string()
+            ))?;
+
+        // The registry enforces Clock::slot, so estimate from the cluster's current slot
+        let current_slot = self.rpc_manager.get_epoch_info().await?.absolute_slot;
+        let next_slot = job.next_scheduled_slot.map(|slot| slot.max(0) as u64).unwrap_or(0);
+
+        debug!(
+            "Evaluating slot trigger for job {}: interval={}, current_slot={}, next_slot={}",
+            job.job_id, slot_interval, current_slot, next_slot
+        );
+
+        let should_execute = current_slot >= next_slot;
+        let next_check_time = if should_execute {
+            None
+        } else {
+            let slots_remaining = (next_slot - current_slot) as i64;
+            Some(now + chrono::Duration::milliseconds(slots_remaining * APPROX_SLOT_MILLIS))
+        };
+
+        Ok(EvaluationResult {
+            should_execute,
+            reason: if should_execute {
+                "Slot interval elapsed".to_string()
+            } else {
+                format!("Waiting for slot {} (current {})", next_slot, current_slot)
+            },
+            next_check_time,
+            perform_data: Vec::new(),
+        })
+    }
+
+    async fn evaluate_epoch_trigger(
+        &self,
+        job: &JobRecord,
+        now: DateTime<Utc>,
+    ) -> KeeperResult<EvaluationResult> {
+        let params = &job.trigger_params;
+
+        // Slots after the boundary the run may happen in (0 = whole epoch)
+        let window = params.get("window").and_then(|v| v.as_u64()).unwrap_or(0);
+
+        let epoch_info = self.rpc_manager.get_epoch_info().await?;
+        let next_epoch = job.next_epoch.map(|epoch| epoch.max(0) as u64).unwrap_or(0);
+
+        debug!(
+            "Evaluating epoch trigger for job {}: epoch={}, slot_index={}, next_epoch={}",
+            job.job_id, epoch_info.epoch, epoch_info.slot_index, next_epoch
+        );
+
+        let in_window = window == 0 || epoch_info.slot_index <= window;
+        let should_execute = epoch_info.epoch >= next_epoch && in_window;
+
+        // Otherwise wait for the next boundary
+        let next_check_time = if should_execute {
+            None
+        } else {
+            let slots_remaining = epoch_info.slots_in_epoch.saturating_sub(epoch_info.slot_index) as i64;
+            Some(now + chrono::Duration::milliseconds(slots_remaining * APPROX_SLOT_MILLIS))
+        };
+
+        let reason = if should_execute {
+            format!("Epoch {} started", epoch_info.epoch)
+        } else if epoch_info.epoch < next_epoch {
+            format!("Waiting for epoch {}", next_epoch)
+        } else {
+            format!("Epoch {} window of {} slots has passed", epoch_info.epoch, window)
+        };
+
+        Ok(EvaluationResult {
+            should_execute,
+            reason,
+            next_check_time,
+            perform_data: Vec::new(),
+        })
+    }
+
+    async fn evaluate_account_change_trigger(
+        &self,
+        job: &JobRecord,
+        now: DateTime<Utc>,
+    ) -> KeeperResult<EvaluationResult> {
+        let account = job.trigger_params
+            .get("account")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<solana_sdk::pubkey::Pubkey>().ok())
+            .ok_or_else(|| KeeperError::InvalidTriggerError(
+                "Missing or invalid account in account change trigger".to_string()
+            ))?;
+
+        // Changes arrive over the subscription; until the first notification,
+        // fall back to reading the account once
+        let current_hash = match &self.account_watcher {
+            Some(watcher) => {
+                watcher.watch(account).await;
+                watcher.latest_hash(&account).await
+            }
+            None => None,
+        };
+        let current_hash = match current_hash {
+            Some(hash) => Some(hash),
+            None => self.rpc_manager.get_account_data(&account).await?
+                .map(|data| solana_sdk::hash::hash(&data.data)),
+        };
+
+        let Some(current_hash) = current_hash else {
+            return Ok(EvaluationResult {
+                should_execute: false,
+                reason: format!("Watched account {} does not exist", account),
+                next_check_time: Some(now + chrono::Duration::seconds(60)),
+                perform_data: Vec::new(),
+            });
+        };
+
+        debug!("Evaluating account change trigger for job {}: account={}", job.job_id, account);
+
+        // The registry stores zeroes until the first execution
+        let last_hash = job.last_data_hash.clone()
+            .unwrap_or_else(|| solana_sdk::hash::Hash::default().to_string());
+        let should_execute = current_hash.to_string() != last_hash;
+
+        // With a subscription, notifications mark the job due, so only re-check as a safety net
+        let next_check_time = if should_execute {
+            None
+        } else if self.account_watcher.is_some() {
+            Some(now + chrono::Duration::hours(1))
+        } else {
+            Some(now + chrono::Duration::seconds(30))
+        };
+
+        Ok(EvaluationResult {
+            should_execute,
+            reason: if should_execute {
+                format!("Account {} data changed", account)
+            } else {
+                format!("Waiting for account {} to change", account)
+            },
+            next_check_time,
+            perform_data: Vec::new(),
+        })
+    }
+
+    async fn evaluate_price_trigger(
+        &self,
+        job: &JobRecord,
+        now: DateTime<Utc>,
+    ) -> KeeperResult<EvaluationResult> {
+        let params = &job.trigger_params;
+
+        let price_account = params
+            .get("price_account")
+            .and_then(|v| v.as_str())
+            .and_then(|s| s.parse::<solana_sdk::pubkey::Pubkey>().ok())
+            .ok_or_else(|| KeeperError::InvalidTriggerError(
+                "Missing or invalid price_account in price trigger".to_string()
+            ))?;
+        let comparison = match params.get("comparison").and_then(|v| v.as_str()) {
+            Some("above") => PriceComparison::Above,
+            Some("below") => PriceComparison::Below,
+            _ => return Err(KeeperError::InvalidTriggerError(
+                "Comparison in price trigger must be \"above\" or \"below\"".to_string()
+            )),
+        };
+        let threshold = params
+            .get("threshold")
+            .and_then(|v| v.as_i64())
+            .ok_or_else(|| KeeperError::InvalidTriggerError(
+                "Missing or invalid threshold in price trigger".to_string()
+            ))?;
+        let max_staleness = params
+            .get("max_staleness")
+            .and_then(|v| v.as_u64())
+            .filter(|staleness| *staleness > 0)
+            .ok_or_else(|| KeeperError::InvalidTriggerError(
+                "Missing or invalid max_staleness in price trigger".to_string()
+            ))?;
+
+        let price = self.rpc_manager.get_pyth_price(&price_account).await?;
+
+        debug!(
+            "Evaluating price trigger for job {}: price={}e{}, threshold={}, comparison={:?}",
+            job.job_id, price.price, price.expo, threshold, comparison
+        );
+
+        // The registry rejects prices it considers stale, so don't submit them
+        let (should_execute, reason) = if !price.is_trading() {
+            (false, format!("Price account {} is not trading", price_account))
+        } else if !price.is_fresh(now.timestamp(), max_staleness) {
+            (false, format!("Price is older than {}s", max_staleness))
+        } else if comparison.is_met(price.price, threshold) {
+            (true, format!("Price {} crossed threshold {}", price.price, threshold))
+        } else {
+            (false, format!("Price {} has not crossed threshold {}", price.price, threshold))
+        };
+
+        Ok(EvaluationResult {
+            should_execute,
+            reason,
+            next_check_time: if should_execute {
+                None
+            } else {
+                Some(now + chrono::Duration::seconds(5)) // Prices move quickly
+            },
+            perform_data: Vec::new(),
         })
     }
 
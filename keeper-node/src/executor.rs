@@ -8,6 +8,7 @@ use solana_sdk::{
     transaction::Transaction,
     instruction::{Instruction, AccountMeta},
     system_program,
+    sysvar,
 };
 use anchor_client::{Client, Program, Cluster};
 use log::{info, warn, error, debug};
@@ -17,7 +18,7 @@ use crate::config::KeeperConfig;
 use crate::database::{Database, ExecutionRecord};
 use crate::rpc::RpcManager;
 use crate::monitor::{ExecutionRequest, ExecutionPriority};
-use crate::error::{KeeperError, KeeperResult};
+use crate::error::{transaction_error_code, KeeperError, KeeperResult};
 
 pub struct JobExecutor {
     config: KeeperConfig,
@@ -60,8 +61,11 @@ pub struct ExecutionResult {
     pub success: bool,
     pub signature: Option<String>,
     pub error: Option<String>,
+    pub error_code: Option<u32>,
     pub gas_used: u64,
     pub fee_paid: u64,
+    /// Target return data captured by the registry
+    pub return_data: Option<Vec<u8>>,
 }
 
 impl JobExecutor {
@@ -117,6 +121,18 @@ impl JobExecutor {
             ).await
         });
         
+        // Optionally crank reap_job on dead jobs for the rent bounty
+        if self.config.reaper_enabled() {
+            let database = self.database.clone();
+            let rpc_manager = self.rpc_manager.clone();
+            let keeper_keypair = self.keeper_keypair.clone();
+            let config = self.config.clone();
+
+            tokio::spawn(async move {
+                Self::run_reaper(database, rpc_manager, keeper_keypair, config).await
+            });
+        }
+        
         // Receive and queue execution requests
         let queue = self.execution_queue.clone();
         loop {
@@ -198,6 +214,106 @@ impl JobExecutor {
         }
     }
 
+    async fn run_reaper(
+        database: Arc<Database>,
+        rpc_manager: Arc<RpcManager>,
+        keeper_keypair: Arc<Keypair>,
+        config: KeeperConfig,
+    ) {
+        info!("Starting job reaper every {:?}", config.get_reap_interval());
+
+        loop {
+            let now = Utc::now().timestamp();
+            match database.get_reapable_jobs(now, config.get_job_reap_delay()).await {
+                Ok(jobs) => {
+                    for job in jobs {
+                        if let Err(e) = Self::reap_job(&job, &database, &rpc_manager, &keeper_keypair).await {
+                            warn!("Failed to reap job {}: {:?}", job.job_id, e);
+                        }
+                    }
+                }
+                Err(e) => error!("Failed to load reapable jobs: {:?}", e),
+            }
+
+            sleep(config.get_reap_interval()).await;
+        }
+    }
+
+    async fn reap_job(
+        job: &crate::database::JobRecord,
+        database: &Database,
+        rpc_manager: &RpcManager,
+        keeper_keypair: &Keypair,
+    ) -> KeeperResult<()> {
+        let instruction = Self::build_reap_instruction(job, keeper_keypair)?;
+        let blockhash = rpc_manager.get_latest_blockhash().await?;
+        let transaction = Transaction::new_signed_with_payer(
+            &[instruction],
+            Some(&keeper_keypair.pubkey()),
+            &[keeper_keypair],
+            blockhash,
+        );
+
+        let signature = rpc_manager.send_and_confirm_transaction(&transaction).await?;
+        info!("Reaped job {}: {}", job.job_id, signature);
+
+        // The job account is closed, so stop tracking it
+        database.delete_job(job.job_id).await
+    }
+
+    fn build_reap_instruction(
+        job: &crate::database::JobRecord,
+        keeper_keypair: &Keypair,
+    ) -> KeeperResult<Instruction> {
+        let registry_program_id = "Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS"
+            .parse::<Pubkey>()
+            .map_err(|e| KeeperError::ConfigError(format!("Invalid program ID: {}", e)))?;
+
+        let owner = job.owner.parse::<Pubkey>()
+            .map_err(|e| KeeperError::InvalidJobError(format!("Invalid job owner: {}", e)))?;
+
+        let (registry_state, _) = Pubkey::find_program_address(
+            &[b"registry"],
+            &registry_program_id,
+        );
+
+        let (automation_job, _) = Pubkey::find_program_address(
+            &[b"job", &job.job_id.to_le_bytes()],
+            &registry_program_id,
+        );
+
+        let (job_metadata, _) = Pubkey::find_program_address(
+            &[b"job_metadata", &job.job_id.to_le_bytes()],
+            &registry_program_id,
+        );
+
+        let accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new(automation_job, false),
+            AccountMeta::new(job_metadata, false),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(keeper_keypair.pubkey(), true),
+        ];
+
+        // reap_job takes no arguments beyond its discriminator
+        let instruction_data = Self::instruction_sighash("reap_job").to_vec();
+
+        Ok(Instruction {
+            program_id: registry_program_id,
+            accounts,
+            data: instruction_data,
+        })
+    }
+
+    /// Anchor's instruction discriminator: the first 8 bytes of sha256("global:<name>")
+    fn instruction_sighash(name: &str) -> [u8; 8] {
+        let mut sighash = [0u8; 8];
+        sighash.copy_from_slice(
+            &solana_sdk::hash::hash(format!("global:{}", name).as_bytes()).to_bytes()[..8],
+        );
+        sighash
+    }
+
     async fn execute_job(
         request: &ExecutionRequest,
         database: &Database,
@@ -212,6 +328,7 @@ impl JobExecutor {
         // Build the execution instruction
         let instruction_result = Self::build_execution_instruction(
             job,
+            &request.perform_data,
             keeper_keypair,
         ).await;
         
@@ -223,8 +340,10 @@ impl JobExecutor {
                     success: false,
                     signature: None,
                     error: Some(format!("Failed to build instruction: {}", e)),
+                    error_code: None,
                     gas_used: 0,
                     fee_paid: 0,
+                    return_data: None,
                 };
             }
         };
@@ -238,8 +357,10 @@ impl JobExecutor {
                     success: false,
                     signature: None,
                     error: Some(format!("Failed to get blockhash: {}", e)),
+                    error_code: None,
                     gas_used: 0,
                     fee_paid: 0,
+                    return_data: None,
                 };
             }
         };
@@ -256,15 +377,25 @@ impl JobExecutor {
         if config.simulation_enabled() {
             match rpc_manager.simulate_transaction(&transaction).await {
                 Ok(simulation) => {
-                    if simulation.value.err.is_some() {
+                    if let Some(err) = simulation.value.err {
                         warn!("Transaction simulation failed for job {}: {:?}", 
-                              job.job_id, simulation.value.err);
+                              job.job_id, err);
+                        // A failing target aborts execute_job and leaves nothing
+                        // on-chain; the failure is only recorded in the keeper's
+                        // database, which drives its backoff for the job
+                        let error_code = transaction_error_code(&err);
+
                         return ExecutionResult {
                             success: false,
                             signature: None,
-                            error: Some(format!("Simulation failed: {:?}", simulation.value.err)),
+                            error: Some(Self::describe_failure(
+                                format!("Simulation failed: {:?}", err),
+                                error_code,
+                            )),
+                            error_code,
                             gas_used: 0,
                             fee_paid: 0,
+                            return_data: None,
                         };
                     } else {
                         debug!("Transaction simulation succeeded for job {}", job.job_id);
@@ -279,20 +410,33 @@ impl JobExecutor {
         
         // Execute transaction with retries
         let mut last_error = None;
+        let mut last_error_code = None;
         for attempt in 0..config.execution.max_retries {
             match rpc_manager.send_and_confirm_transaction(&transaction).await {
                 Ok(signature) => {
                     info!("Job {} executed successfully: {}", job.job_id, signature);
+
+                    // The target's return data is the last set in the transaction
+                    let return_data = match rpc_manager.get_transaction_return_data(&signature).await {
+                        Ok(return_data) => return_data,
+                        Err(e) => {
+                            warn!("Failed to fetch return data for job {}: {:?}", job.job_id, e);
+                            None
+                        }
+                    };
                     return ExecutionResult {
                         success: true,
                         signature: Some(signature.to_string()),
                         error: None,
+                        error_code: None,
                         gas_used: 0, // Would need to parse transaction logs for actual value
                         fee_paid: 5000, // Placeholder - would calculate actual fee
+                        return_data,
                     };
                 }
                 Err(e) => {
-                    last_error = Some(e.to_string());
+                    last_error_code = e.program_error_code();
+                    last_error = Some(Self::describe_failure(e.to_string(), last_error_code));
                     warn!("Job {} execution failed (attempt {}): {:?}", 
                           job.job_id, attempt + 1, e);
                     
@@ -309,13 +453,24 @@ impl JobExecutor {
             success: false,
             signature: None,
             error: last_error,
+            error_code: last_error_code,
             gas_used: 0,
             fee_paid: 0,
+            return_data: None,
+        }
+    }
+
+    /// Prefix a failure message with the program error code when one is known
+    fn describe_failure(message: String, error_code: Option<u32>) -> String {
+        match error_code {
+            Some(code) => format!("Program error {} (0x{:x}): {}", code, code, message),
+            None => message,
         }
     }
 
     async fn build_execution_instruction(
         job: &crate::database::JobRecord,
+        perform_data: &[u8],
         keeper_keypair: &Keypair,
     ) -> KeeperResult<Instruction> {
         // Build the execute_job instruction for the registry program
@@ -342,6 +497,22 @@ impl JobExecutor {
             &registry_program_id,
         );
 
+        // Registry PDA that signs the execution engine CPI
+        let (execution_authority, _) = Pubkey::find_program_address(
+            &[b"execution_authority"],
+            &registry_program_id,
+        );
+
+        // Insurance pool receiving its share of the protocol fee
+        let (insurance_pool, _) = Pubkey::find_program_address(
+            &[b"insurance_pool"],
+            &registry_program_id,
+        );
+
+        let execution_program_id = "ExecNqpXiPPjs7m5wbuTCxZE8PJzgdW2cWEw23kcKJKm"
+            .parse::<Pubkey>()
+            .map_err(|e| KeeperError::ConfigError(format!("Invalid program ID: {}", e)))?;
+
         // This is a simplified version - in a full implementation, we'd need to:
         // 1. Get the current execution count from registry state
         // 2. Derive the execution record PDA properly
@@ -357,6 +528,28 @@ impl JobExecutor {
             &registry_program_id,
         );
 
+        // Account-change jobs pass the watched account; otherwise the registry program ID
+        // stands in for the absent optional account
+        let trigger_account = match job.trigger_type.as_str() {
+            "account_change" => job.trigger_params
+                .get("account")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse::<Pubkey>().ok())
+                .ok_or_else(|| KeeperError::InvalidJobError(
+                    format!("Job {} has no valid watched account", job.job_id)
+                ))?,
+            _ => registry_program_id,
+        };
+
+        // Pooled jobs pay fees from their funding pool
+        let funding_pool = job.funding_pool
+            .as_deref()
+            .map(|pool| pool.parse::<Pubkey>())
+            .transpose()
+            .map_err(|e| KeeperError::InvalidJobError(
+                format!("Job {} has an invalid funding pool: {}", job.job_id, e)
+            ))?;
+
         let accounts = vec![
             AccountMeta::new(registry_state, false),
             AccountMeta::new(automation_job, false),
@@ -364,15 +557,27 @@ impl JobExecutor {
             AccountMeta::new(execution_record, false),
             AccountMeta::new(keeper_keypair.pubkey(), true),
             AccountMeta::new_readonly(target_program_id, false),
+            AccountMeta::new_readonly(execution_authority, false),
+            AccountMeta::new_readonly(execution_program_id, false),
+            AccountMeta::new(insurance_pool, false),
             AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(sysvar::instructions::ID, false),
+            AccountMeta::new_readonly(trigger_account, false),
+            match funding_pool {
+                Some(funding_pool) => AccountMeta::new(funding_pool, false),
+                None => AccountMeta::new_readonly(registry_program_id, false),
+            },
         ];
 
-        // Build instruction data (discriminator + job_id)
+        // Build instruction data (discriminator + job_id + perform_data)
         let mut instruction_data = Vec::new();
         // execute_job instruction discriminator (8 bytes)
         // This would need to be computed from the instruction name
         instruction_data.extend_from_slice(&[0u8; 8]); // Placeholder discriminator
         instruction_data.extend_from_slice(&job.job_id.to_le_bytes());
+        // perform_data as a Borsh Vec<u8>
+        instruction_data.extend_from_slice(&(perform_data.len() as u32).to_le_bytes());
+        instruction_data.extend_from_slice(perform_data);
 
         Ok(Instruction {
             program_id: registry_program_id,
@@ -395,11 +600,14 @@ impl JobExecutor {
             success: result.success,
             signature: result.signature,
             error: result.error,
+            error_code: result.error_code.map(i64::from),
             gas_used: Some(result.gas_used as i64),
             fee_paid: Some(result.fee_paid as i64),
+            return_data: result.return_data,
         };
 
         database.record_execution(&execution_record).await?;
+        database.record_job_outcome(request.job.job_id, result.success).await?;
         
         // Update keeper stats
         let today = Utc::now().date_naive();
@@ -10,6 +10,11 @@ mod rpc;
 mod database;
 mod keeper;
 mod error;
+mod watcher;
+// Pyth parser shared with the registry program so both read prices identically
+#[path = "../../programs/registry/src/oracle.rs"]
+#[allow(dead_code)]
+mod oracle;
 
 use config::KeeperConfig;
 use keeper::KeeperNode;
@@ -192,6 +197,8 @@ fallback_urls = [
 poll_interval_ms = 1000
 max_concurrent_jobs = 10
 job_cache_ttl_seconds = 60
+failure_backoff_base_seconds = 30
+max_failure_backoff_seconds = 3600
 
 [execution]
 priority_fee_percentile = 50
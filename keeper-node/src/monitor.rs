@@ -1,6 +1,6 @@
 use std::sync::Arc;
 use std::collections::HashMap;
-use tokio::sync::{mpsc, RwLock};
+use tokio::sync::{mpsc, Mutex, RwLock};
 use tokio::time::{interval, Duration, Instant};
 use chrono::Utc;
 use log::{info, warn, error, debug};
@@ -9,8 +9,12 @@ use crate::config::KeeperConfig;
 use crate::database::{Database, JobRecord};
 use crate::rpc::RpcManager;
 use crate::evaluator::{TriggerEvaluator, EvaluationResult};
+use crate::watcher::AccountWatcher;
 use crate::error::{KeeperError, KeeperResult};
 
+/// Tip at which a job is executed ahead of untipped work (0.0001 SOL)
+const HIGH_PRIORITY_TIP_LAMPORTS: i64 = 100_000;
+
 pub struct JobMonitor {
     config: KeeperConfig,
     database: Arc<Database>,
@@ -18,6 +22,7 @@ pub struct JobMonitor {
     evaluator: Arc<TriggerEvaluator>,
     job_cache: Arc<RwLock<HashMap<i64, CachedJob>>>,
     execution_sender: mpsc::UnboundedSender<ExecutionRequest>,
+    account_changes: Mutex<Option<mpsc::UnboundedReceiver<solana_sdk::pubkey::Pubkey>>>,
 }
 
 #[derive(Clone, Debug)]
@@ -34,6 +39,8 @@ pub struct ExecutionRequest {
     pub job: JobRecord,
     pub reason: String,
     pub priority: ExecutionPriority,
+    /// Appended to the target instruction data on execution
+    pub perform_data: Vec<u8>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -51,7 +58,15 @@ impl JobMonitor {
         rpc_manager: Arc<RpcManager>,
         execution_sender: mpsc::UnboundedSender<ExecutionRequest>,
     ) -> Self {
-        let evaluator = Arc::new(TriggerEvaluator::new(rpc_manager.as_ref().clone()));
+        // Account-change triggers are driven by subscriptions when websockets are enabled
+        let (change_sender, change_receiver) = mpsc::unbounded_channel();
+        let account_watcher = config.websocket_enabled()
+            .then(|| Arc::new(AccountWatcher::new(config.get_ws_url(), change_sender)));
+        let evaluator = Arc::new(TriggerEvaluator::new(
+            rpc_manager.as_ref().clone(),
+            account_watcher,
+            config.get_keeper_tier(),
+        ));
         
         Self {
             config,
@@ -60,6 +75,7 @@ impl JobMonitor {
             evaluator,
             job_cache: Arc::new(RwLock::new(HashMap::new())),
             execution_sender,
+            account_changes: Mutex::new(Some(change_receiver)),
         }
     }
 
@@ -75,6 +91,9 @@ impl JobMonitor {
         // Cleanup interval (every hour)
         let mut cleanup_interval = interval(Duration::from_secs(3600));
 
+        let mut account_changes = self.account_changes.lock().await.take()
+            .ok_or_else(|| KeeperError::InternalError("Job monitor already started".to_string()))?;
+
         loop {
             tokio::select! {
                 _ = monitoring_interval.tick() => {
@@ -92,6 +111,9 @@ impl JobMonitor {
                         error!("Error cleaning up cache: {:?}", e);
                     }
                 }
+                Some(account) = account_changes.recv() => {
+                    self.mark_watching_jobs_due(&account).await;
+                }
             }
         }
     }
@@ -155,6 +177,16 @@ impl JobMonitor {
             let now_utc = Utc::now();
             
             for cached_job in cache.values() {
+                if cached_job.job.is_paused {
+                    continue;
+                }
+
+                // Back off exponentially from jobs that keep failing
+                let backoff = self.config.get_failure_backoff(cached_job.consecutive_failures);
+                if now.duration_since(cached_job.last_evaluation) < backoff {
+                    continue;
+                }
+
                 // Check if it's time to evaluate this job
                 let should_check = if let Some(next_check) = cached_job.next_check_time {
                     now_utc >= next_check
@@ -208,10 +240,11 @@ impl JobMonitor {
                 last_evaluation: Instant::now(),
                 next_check_time: None,
                 evaluation_count: 0,
-                consecutive_failures: 0,
+                consecutive_failures: job.consecutive_failures.max(0) as u32,
             });
             
             cached_job.job = job.clone();
+            cached_job.consecutive_failures = job.consecutive_failures.max(0) as u32;
             cached_job.last_evaluation = Instant::now();
             cached_job.next_check_time = evaluation.next_check_time;
             cached_job.evaluation_count += 1;
@@ -225,6 +258,7 @@ impl JobMonitor {
                 job,
                 reason: evaluation.reason,
                 priority,
+                perform_data: evaluation.perform_data,
             };
             
             if let Err(e) = execution_sender.send(execution_request) {
@@ -259,8 +293,13 @@ impl JobMonitor {
             return ExecutionPriority::Critical;
         }
         
-        // Low priority for frequently executed jobs
-        if job.execution_count > 100 {
+        // The tip is paid to the executing keeper in full, so well-tipped jobs go first
+        if job.tip_lamports >= HIGH_PRIORITY_TIP_LAMPORTS {
+            return ExecutionPriority::High;
+        }
+        
+        // Low priority for frequently executed jobs that don't tip
+        if job.execution_count > 100 && job.tip_lamports == 0 {
             return ExecutionPriority::Low;
         }
         
@@ -283,16 +322,17 @@ impl JobMonitor {
             match cache.get_mut(&job.job_id) {
                 Some(cached_job) => {
                     // Update existing cached job
+                    cached_job.consecutive_failures = job.consecutive_failures.max(0) as u32;
                     cached_job.job = job;
                 }
                 None => {
                     // Add new job to cache
                     cache.insert(job.job_id, CachedJob {
+                        consecutive_failures: job.consecutive_failures.max(0) as u32,
                         job,
                         last_evaluation: Instant::now(),
                         next_check_time: None,
                         evaluation_count: 0,
-                        consecutive_failures: 0,
                     });
                 }
             }
@@ -310,6 +350,23 @@ impl JobMonitor {
         Ok(())
     }
 
+    /// Make account-change jobs watching `account` due on the next monitoring cycle
+    async fn mark_watching_jobs_due(&self, account: &solana_sdk::pubkey::Pubkey) {
+        let account = account.to_string();
+        let mut cache = self.job_cache.write().await;
+
+        for cached_job in cache.values_mut() {
+            let watches_account = cached_job.job.trigger_type == "account_change"
+                && cached_job.job.trigger_params.get("account").and_then(|v| v.as_str())
+                    == Some(account.as_str());
+
+            if watches_account {
+                debug!("Account {} changed, marking job {} due", account, cached_job.job.job_id);
+                cached_job.next_check_time = Some(Utc::now());
+            }
+        }
+    }
+
     async fn cleanup_cache(&self) -> KeeperResult<()> {
         debug!("Cleaning up job cache");
         
@@ -338,7 +395,7 @@ impl JobMonitor {
         let total_jobs = cache.len();
         let active_jobs = cache.values().filter(|j| j.job.is_active).count();
         let pending_jobs = cache.values().filter(|j| {
-            j.job.is_active && j.next_check_time.map_or(true, |t| Utc::now() >= t)
+            j.job.is_active && !j.job.is_paused && j.next_check_time.map_or(true, |t| Utc::now() >= t)
         }).count();
         
         (total_jobs, active_jobs, pending_jobs)
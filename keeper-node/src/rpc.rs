@@ -6,6 +6,7 @@ use tokio::sync::RwLock;
 use log::{warn, info, debug};
 use crate::config::KeeperConfig;
 use crate::error::{KeeperError, KeeperResult};
+use crate::oracle::{parse_pyth_price, PythPrice};
 
 #[derive(Clone)]
 pub struct RpcManager {
@@ -207,8 +208,9 @@ impl RpcManager {
     ) -> KeeperResult<solana_sdk::signature::Signature> {
         let transaction = transaction.clone();
         self.execute_with_retry(move |client| {
+            // Keep the client error so the program error code can be recovered
             client.send_and_confirm_transaction(&transaction)
-                .map_err(|e| KeeperError::RpcError(format!("Failed to send transaction: {}", e)))
+                .map_err(KeeperError::SolanaClientError)
         }).await
     }
 
@@ -223,6 +225,59 @@ impl RpcManager {
         }).await
     }
 
+    /// Simulate a single instruction without signatures, paid for by `payer`
+    pub async fn simulate_instruction(
+        &self,
+        instruction: solana_sdk::instruction::Instruction,
+        payer: &solana_sdk::pubkey::Pubkey,
+    ) -> KeeperResult<solana_client::rpc_response::RpcSimulateTransactionResult> {
+        let transaction = solana_sdk::transaction::Transaction::new_with_payer(&[instruction], Some(payer));
+        self.execute_with_retry(move |client| {
+            client.simulate_transaction_with_config(
+                &transaction,
+                solana_client::rpc_config::RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    ..Default::default()
+                },
+            )
+            .map(|response| response.value)
+            .map_err(|e| KeeperError::RpcError(format!("Failed to simulate instruction: {}", e)))
+        }).await
+    }
+
+    /// Return data of a confirmed transaction, if any was set
+    pub async fn get_transaction_return_data(
+        &self,
+        signature: &solana_sdk::signature::Signature,
+    ) -> KeeperResult<Option<Vec<u8>>> {
+        use base64::Engine;
+        use solana_transaction_status::option_serializer::OptionSerializer;
+
+        let signature = *signature;
+        let transaction = self.execute_with_retry(move |client| {
+            client.get_transaction_with_config(
+                &signature,
+                solana_client::rpc_config::RpcTransactionConfig {
+                    encoding: Some(solana_transaction_status::UiTransactionEncoding::Base64),
+                    commitment: Some(CommitmentConfig::confirmed()),
+                    max_supported_transaction_version: Some(0),
+                },
+            )
+            .map_err(|e| KeeperError::RpcError(format!("Failed to get transaction: {}", e)))
+        }).await?;
+
+        let return_data = match transaction.transaction.meta.map(|meta| meta.return_data) {
+            Some(OptionSerializer::Some(return_data)) => return_data,
+            _ => return Ok(None),
+        };
+
+        base64::engine::general_purpose::STANDARD
+            .decode(&return_data.data.0)
+            .map(Some)
+            .map_err(|e| KeeperError::RpcError(format!("Invalid return data: {}", e)))
+    }
+
     pub async fn get_account_data(
         &self,
         pubkey: &solana_sdk::pubkey::Pubkey,
@@ -260,6 +315,27 @@ impl RpcManager {
         }).await
     }
 
+    pub async fn get_epoch_info(&self) -> KeeperResult<solana_sdk::epoch_info::EpochInfo> {
+        self.execute_with_retry(|client| {
+            client.get_epoch_info()
+                .map_err(|e| KeeperError::RpcError(format!("Failed to get epoch info: {}", e)))
+        }).await
+    }
+
+    /// Aggregate price of a Pyth price account, parsed as the registry does
+    pub async fn get_pyth_price(
+        &self,
+        pubkey: &solana_sdk::pubkey::Pubkey,
+    ) -> KeeperResult<PythPrice> {
+        let account = self.get_account_data(pubkey).await?
+            .ok_or_else(|| KeeperError::RpcError(format!("Price account {} not found", pubkey)))?;
+
+        parse_pyth_price(&account.data)
+            .ok_or_else(|| KeeperError::InvalidTriggerError(
+                format!("Account {} is not a Pyth price account", pubkey)
+            ))
+    }
+
     pub async fn get_balance(
         &self,
         pubkey: &solana_sdk::pubkey::Pubkey,
@@ -0,0 +1,102 @@
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use futures::StreamExt;
+use log::{debug, info, warn};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::hash::{hash, Hash};
+use solana_sdk::pubkey::Pubkey;
+use tokio::sync::{mpsc, RwLock};
+
+/// Watches accounts read by account-change triggers over `accountSubscribe`
+/// and keeps the hash of each account's latest data
+pub struct AccountWatcher {
+    ws_url: String,
+    latest_hashes: Arc<RwLock<HashMap<Pubkey, Hash>>>,
+    subscribed: Arc<RwLock<HashSet<Pubkey>>>,
+    change_sender: mpsc::UnboundedSender<Pubkey>,
+}
+
+impl AccountWatcher {
+    pub fn new(ws_url: String, change_sender: mpsc::UnboundedSender<Pubkey>) -> Self {
+        Self {
+            ws_url,
+            latest_hashes: Arc::new(RwLock::new(HashMap::new())),
+            subscribed: Arc::new(RwLock::new(HashSet::new())),
+            change_sender,
+        }
+    }
+
+    /// Subscribe to an account if it is not already watched
+    pub async fn watch(&self, pubkey: Pubkey) {
+        if !self.subscribed.write().await.insert(pubkey) {
+            return;
+        }
+
+        info!("Subscribing to account {}", pubkey);
+
+        let ws_url = self.ws_url.clone();
+        let latest_hashes = self.latest_hashes.clone();
+        let subscribed = self.subscribed.clone();
+        let change_sender = self.change_sender.clone();
+
+        tokio::spawn(async move {
+            if let Err(e) = Self::run_subscription(&ws_url, pubkey, latest_hashes, change_sender).await {
+                warn!("Account subscription for {} ended: {}", pubkey, e);
+            }
+            // Allow the next evaluation to resubscribe
+            subscribed.write().await.remove(&pubkey);
+        });
+    }
+
+    /// Hash of the account's data from its latest notification, if any
+    pub async fn latest_hash(&self, pubkey: &Pubkey) -> Option<Hash> {
+        self.latest_hashes.read().await.get(pubkey).copied()
+    }
+
+    async fn run_subscription(
+        ws_url: &str,
+        pubkey: Pubkey,
+        latest_hashes: Arc<RwLock<HashMap<Pubkey, Hash>>>,
+        change_sender: mpsc::UnboundedSender<Pubkey>,
+    ) -> Result<(), String> {
+        let client = PubsubClient::new(ws_url)
+            .await
+            .map_err(|e| format!("Failed to connect to {}: {}", ws_url, e))?;
+
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(CommitmentConfig::confirmed()),
+            ..RpcAccountInfoConfig::default()
+        };
+
+        let (mut notifications, unsubscribe) = client
+            .account_subscribe(&pubkey, Some(config))
+            .await
+            .map_err(|e| format!("Failed to subscribe: {}", e))?;
+
+        while let Some(response) = notifications.next().await {
+            let Some(account) = response.value.decode::<Account>() else {
+                warn!("Could not decode account notification for {}", pubkey);
+                continue;
+            };
+
+            let data_hash = hash(&account.data);
+            let previous = latest_hashes.write().await.insert(pubkey, data_hash);
+            if previous == Some(data_hash) {
+                continue;
+            }
+
+            debug!("Account {} changed at slot {}", pubkey, response.context.slot);
+            if change_sender.send(pubkey).is_err() {
+                break;
+            }
+        }
+
+        unsubscribe().await;
+        Ok(())
+    }
+}
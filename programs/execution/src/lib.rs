@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use anchor_lang::solana_program::instruction::AccountMeta as SolanaAccountMeta;
+use solcron_registry::state::{AutomationJob, ExecutionRecord};
 
 declare_id!("ExecNqpXiPPjs7m5wbuTCxZE8PJzgdW2cWEw23kcKJKm");
 
@@ -32,7 +33,8 @@ impl From<AccountMeta> for SolanaAccountMeta {
 pub mod solcron_execution {
     use super::*;
 
-    /// Execute a cross-program invocation with proper validation and error handling
+    /// Execute a cross-program invocation with proper validation and error handling.
+    /// Only callable by the registry on behalf of a live automation job.
     pub fn execute_cpi_call(
         ctx: Context<ExecuteCpiCall>,
         program_id: Pubkey,
@@ -47,6 +49,12 @@ pub mod solcron_execution {
         require!(!accounts.is_empty(), ExecutionError::NoAccountsProvided);
         require!(accounts.len() <= 32, ExecutionError::TooManyAccounts);
 
+        // The CPI must target the program stored on the job
+        require!(
+            program_id == ctx.accounts.automation_job.target_program,
+            ExecutionError::UnauthorizedExecution
+        );
+
         // Convert custom AccountMeta to Solana AccountMeta
         let solana_accounts: Vec<SolanaAccountMeta> = accounts.into_iter().map(|meta| meta.into()).collect();
         
@@ -57,90 +65,19 @@ pub mod solcron_execution {
             data,
         };
 
-        // Execute the CPI call with remaining accounts
-        // Use remaining_accounts directly to avoid lifetime issues
-        match anchor_lang::solana_program::program::invoke(&instruction, ctx.remaining_accounts) {
-            Ok(()) => {
-                emit!(CpiExecutionSuccess {
-                    target_program: ctx.accounts.target_program.key(),
-                    executor: ctx.accounts.execution_authority.key(),
-                });
-                
-                msg!("CPI call executed successfully to program: {}", ctx.accounts.target_program.key());
-                Ok(())
-            }
-            Err(err) => {
-                emit!(CpiExecutionFailed {
-                    target_program: ctx.accounts.target_program.key(),
-                    executor: ctx.accounts.execution_authority.key(),
-                    error_code: 1, // Generic error code
-                });
-                
-                msg!("CPI call failed to program: {}, error: {:?}", 
-                     ctx.accounts.target_program.key(), err);
-                Err(ExecutionError::CpiCallFailed.into())
-            }
-        }
-    }
-
-    /// Execute a CPI call with signed accounts (for PDA authorities)
-    pub fn execute_cpi_call_with_seeds(
-        ctx: Context<ExecuteCpiCallWithSeeds>,
-        program_id: Pubkey,
-        data: Vec<u8>,
-        accounts: Vec<AccountMeta>,
-        seeds: Vec<Vec<u8>>,
-    ) -> Result<()> {
-        // Validate inputs
-        require!(data.len() <= 1024, ExecutionError::InstructionDataTooLarge);
-        require!(!data.is_empty(), ExecutionError::EmptyInstructionData);
-        require!(!accounts.is_empty(), ExecutionError::NoAccountsProvided);
-        require!(accounts.len() <= 32, ExecutionError::TooManyAccounts);
+        // Execute the CPI call with remaining accounts. A failing target aborts
+        // the transaction, so only success is observed here.
+        anchor_lang::solana_program::program::invoke(&instruction, ctx.remaining_accounts)?;
 
-        // Convert custom AccountMeta to Solana AccountMeta
-        let solana_accounts: Vec<SolanaAccountMeta> = accounts.into_iter().map(|meta| meta.into()).collect();
-        
-        // Build the instruction
-        let instruction = anchor_lang::solana_program::instruction::Instruction {
-            program_id,
-            accounts: solana_accounts,
-            data,
-        };
+        emit!(CpiExecutionSuccess {
+            job_id: ctx.accounts.automation_job.job_id,
+            target_program: ctx.accounts.target_program.key(),
+            executor: ctx.accounts.execution_authority.key(),
+            return_data: target_return_data(&program_id),
+        });
 
-        // Use remaining_accounts directly for CPI with seeds
-
-        // Convert seeds to the correct format for invoke_signed
-        let seeds_refs: Vec<&[u8]> = seeds.iter().map(|seed| seed.as_slice()).collect();
-        let signer_seeds = &[seeds_refs.as_slice()];
-
-        // Execute signed CPI call with remaining accounts
-        match anchor_lang::solana_program::program::invoke_signed(
-            &instruction,
-            ctx.remaining_accounts,
-            signer_seeds,
-        ) {
-            Ok(()) => {
-                emit!(SignedCpiExecutionSuccess {
-                    target_program: ctx.accounts.target_program.key(),
-                    authority: ctx.accounts.execution_authority.key(),
-                });
-                
-                msg!("Signed CPI call executed successfully to program: {}", 
-                     ctx.accounts.target_program.key());
-                Ok(())
-            }
-            Err(err) => {
-                emit!(SignedCpiExecutionFailed {
-                    target_program: ctx.accounts.target_program.key(),
-                    authority: ctx.accounts.execution_authority.key(),
-                    error_code: 1, // Generic error code
-                });
-                
-                msg!("Signed CPI call failed to program: {}, error: {:?}", 
-                     ctx.accounts.target_program.key(), err);
-                Err(ExecutionError::CpiCallFailed.into())
-            }
-        }
+        msg!("CPI call executed successfully to program: {}", ctx.accounts.target_program.key());
+        Ok(())
     }
 
     /// Validate that a program can be called (security check)
@@ -162,15 +99,24 @@ pub mod solcron_execution {
 
 #[derive(Accounts)]
 pub struct ExecuteCpiCall<'info> {
+    /// Registry PDA, only signable by the registry program
+    #[account(
+        seeds = [b"execution_authority"],
+        bump,
+        seeds::program = solcron_registry::ID
+    )]
     pub execution_authority: Signer<'info>,
-    /// CHECK: Target program to invoke
-    pub target_program: AccountInfo<'info>,
-}
-
-#[derive(Accounts)]
-pub struct ExecuteCpiCallWithSeeds<'info> {
-    pub execution_authority: Signer<'info>,
-    /// CHECK: Target program to invoke
+    
+    #[account(
+        constraint = automation_job.is_active @ ExecutionError::InactiveJob,
+        constraint = !automation_job.is_paused @ ExecutionError::PausedJob
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+    
+    /// CHECK: Target program to invoke, must match the job's stored target
+    #[account(
+        constraint = target_program.key() == automation_job.target_program @ ExecutionError::UnauthorizedExecution
+    )]
     pub target_program: AccountInfo<'info>,
 }
 
@@ -180,31 +126,24 @@ pub struct ValidateTargetProgram<'info> {
     pub target_program: AccountInfo<'info>,
 }
 
-// Events
-#[event]
-pub struct CpiExecutionSuccess {
-    pub target_program: Pubkey,
-    pub executor: Pubkey,
+/// Return data set by the target during the CPI, bounded like the registry's execution record
+fn target_return_data(target_program: &Pubkey) -> Vec<u8> {
+    match anchor_lang::solana_program::program::get_return_data() {
+        Some((program_id, mut data)) if program_id == *target_program => {
+            data.truncate(ExecutionRecord::MAX_RETURN_DATA);
+            data
+        }
+        _ => Vec::new(),
+    }
 }
 
+// Events
 #[event]
-pub struct CpiExecutionFailed {
+pub struct CpiExecutionSuccess {
+    pub job_id: u64,
     pub target_program: Pubkey,
     pub executor: Pubkey,
-    pub error_code: u32,
-}
-
-#[event]
-pub struct SignedCpiExecutionSuccess {
-    pub target_program: Pubkey,
-    pub authority: Pubkey,
-}
-
-#[event]
-pub struct SignedCpiExecutionFailed {
-    pub target_program: Pubkey,
-    pub authority: Pubkey,
-    pub error_code: u32,
+    pub return_data: Vec<u8>,
 }
 
 // Errors
@@ -233,4 +172,10 @@ pub enum ExecutionError {
     
     #[msg("Invalid signer seeds provided")]
     InvalidSignerSeeds,
+    
+    #[msg("Automation job is not active")]
+    InactiveJob,
+    
+    #[msg("Automation job is paused")]
+    PausedJob,
 }
\ No newline at end of file
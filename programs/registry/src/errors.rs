@@ -61,4 +61,74 @@ pub enum SolCronError {
     
     #[msg("Invalid instruction data: Malformed instruction parameters")]
     InvalidInstructionData,
-}
\ No newline at end of file
+    
+    #[msg("Job paused: Too many consecutive failures")]
+    JobPaused,
+    
+    #[msg("Account not migrated: Run the migrate instruction for this account first")]
+    AccountNotMigrated,
+    
+    #[msg("Account already migrated: Layout is at the current version")]
+    AccountAlreadyMigrated,
+    
+    #[msg("Registry paused: Operations are suspended by the pauser")]
+    RegistryPaused,
+    
+    #[msg("Timelock active: Queued parameters cannot be executed yet")]
+    TimelockNotExpired,
+    
+    #[msg("Invalid trigger account: Missing or does not match the job's trigger")]
+    InvalidTriggerAccount,
+    
+    #[msg("Invalid price account: Not a Pyth price account")]
+    InvalidPriceAccount,
+    
+    #[msg("Stale price: Price is not trading or older than the max staleness")]
+    StalePrice,
+    
+    #[msg("Fee exceeds cap: Execution fee is above the job's max fee per execution")]
+    FeeExceedsCap,
+    
+    #[msg("Dispute window open: Slash cannot be finalized until it closes")]
+    DisputeWindowOpen,
+    
+    #[msg("Dispute window closed: Slash can no longer be appealed")]
+    DisputeWindowClosed,
+    
+    #[msg("Slash under appeal: Must be resolved by the admin")]
+    SlashUnderAppeal,
+    
+    #[msg("Unbonding in progress: Delegation cannot be withdrawn until the unbonding period ends")]
+    UnbondingInProgress,
+    
+    #[msg("Insufficient delegation: Not enough bonded or unbonding shares")]
+    InsufficientDelegation,
+    
+    #[msg("Invalid funding pool: Missing or not the pool the job draws from")]
+    InvalidFundingPool,
+    
+    #[msg("Pool spend cap exceeded: Job has used up its funding pool allowance")]
+    PoolSpendCapExceeded,
+
+    #[msg("Invalid metadata: Name, tags or URI too long, or metadata account missing")]
+    InvalidMetadata,
+
+    #[msg("Keeper tier too low: Job requires more keeper stake or reputation")]
+    KeeperTierTooLow,
+
+    #[msg("Job expired: Job is past its end time")]
+    JobExpired,
+
+    #[msg("Job not reapable: Job is not expired or inactive and drained long enough, or has sponsors left to refund")]
+    JobNotReapable,
+
+    #[msg("Invalid target account: Target accounts cannot be signers or registry PDAs")]
+    InvalidTargetAccount,
+
+    #[msg("Slash already claimed: The slash has already backed an insurance payout")]
+    SlashAlreadyClaimed,
+
+    #[msg("Claim exceeds slash: Payout is larger than the slash's insurance share")]
+    ClaimExceedsSlash,
+}
+
@@ -1,6 +1,7 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::*;
+use crate::instructions::insurance::{deposit_to_insurance, InsuranceSource};
 
 // Slash Keeper
 #[derive(Accounts)]
@@ -9,7 +10,8 @@ pub struct SlashKeeper<'info> {
     #[account(
         seeds = [b"registry"],
         bump = registry_state.bump,
-        constraint = registry_state.admin == admin.key() @ SolCronError::Unauthorized
+        constraint = registry_state.slasher == slasher.key() @ SolCronError::Unauthorized,
+        constraint = registry_state.version == RegistryState::VERSION @ SolCronError::AccountNotMigrated
     )]
     pub registry_state: Account<'info, RegistryState>,
     
@@ -21,15 +23,17 @@ pub struct SlashKeeper<'info> {
     )]
     pub keeper: Account<'info, Keeper>,
     
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    
-    /// CHECK: Treasury account to receive slashed funds
     #[account(
-        mut,
-        constraint = treasury.key() == registry_state.treasury @ SolCronError::InvalidParameters
+        init,
+        payer = slasher,
+        space = PendingSlash::MAX_SIZE,
+        seeds = [b"pending_slash", keeper.key().as_ref()],
+        bump
     )]
-    pub treasury: AccountInfo<'info>,
+    pub pending_slash: Account<'info, PendingSlash>,
+    
+    #[account(mut)]
+    pub slasher: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
@@ -42,96 +46,627 @@ pub fn slash_keeper(
 ) -> Result<()> {
     require!(slash_amount > 0, SolCronError::InvalidParameters);
     require!(!reason.is_empty(), SolCronError::InvalidParameters);
+    require!(reason.len() <= PendingSlash::MAX_REASON_LEN, SolCronError::InvalidParameters);
 
     let keeper = &mut ctx.accounts.keeper;
+    let pending_slash = &mut ctx.accounts.pending_slash;
+    let clock = Clock::get()?;
     
-    // Ensure we don't slash more than available stake + rewards
-    let available_balance = keeper.stake_amount + keeper.pending_rewards;
+    // Ensure we don't slash more than available stake + rewards + delegations
+    let available_balance = keeper.stake_amount + keeper.pending_rewards + keeper.delegated_stake;
     let actual_slash_amount = std::cmp::min(slash_amount, available_balance);
     
     require!(actual_slash_amount > 0, SolCronError::SlashingFailed);
 
-    // Transfer slashed funds to treasury
-    if actual_slash_amount > 0 {
-        **keeper.to_account_info().try_borrow_mut_lamports()? -= actual_slash_amount;
-        **ctx.accounts.treasury.try_borrow_mut_lamports()? += actual_slash_amount;
-    }
+    // Escrow the slashed funds until the dispute is settled
+    **keeper.to_account_info().try_borrow_mut_lamports()? -= actual_slash_amount;
+    **pending_slash.to_account_info().try_borrow_mut_lamports()? += actual_slash_amount;
+
+    // Delegators bear their pro-rata share; the keeper's part comes from stake
+    // first, then pending rewards, with any shortfall taken from delegations
+    let keeper_amount = actual_slash_amount - keeper.delegator_portion(actual_slash_amount);
+    let stake_amount = std::cmp::min(keeper.stake_amount, keeper_amount);
+    let rewards_amount = std::cmp::min(keeper.pending_rewards, keeper_amount - stake_amount);
+    let delegated_amount = actual_slash_amount - stake_amount - rewards_amount;
+    keeper.stake_amount -= stake_amount;
+    keeper.pending_rewards -= rewards_amount;
+    keeper.delegated_stake -= delegated_amount;
+
+    let dispute_deadline = clock.unix_timestamp
+        .checked_add(ctx.accounts.registry_state.slash_dispute_window)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    pending_slash.keeper = keeper.key();
+    pending_slash.keeper_address = keeper.address;
+    pending_slash.slasher = ctx.accounts.slasher.key();
+    pending_slash.stake_amount = stake_amount;
+    pending_slash.rewards_amount = rewards_amount;
+    pending_slash.delegated_amount = delegated_amount;
+    pending_slash.reason = reason.clone();
+    pending_slash.proposed_at = clock.unix_timestamp;
+    pending_slash.dispute_deadline = dispute_deadline;
+    pending_slash.appeal = None;
+    pending_slash.bump = ctx.bumps.pending_slash;
+
+    emit!(SlashProposed {
+        keeper: keeper.address,
+        slasher: pending_slash.slasher,
+        slash_amount: actual_slash_amount,
+        delegated_amount,
+        reason: reason.clone(),
+        dispute_deadline,
+    });
+
+    msg!("Slash of {} lamports proposed for keeper {}, disputable until {}: {}", 
+         actual_slash_amount, keeper.address, dispute_deadline, reason);
+
+    Ok(())
+}
+
+// Appeal Slash
+#[derive(Accounts)]
+pub struct AppealSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"pending_slash", pending_slash.keeper.as_ref()],
+        bump = pending_slash.bump,
+        constraint = pending_slash.keeper_address == keeper_account.key() @ SolCronError::Unauthorized
+    )]
+    pub pending_slash: Account<'info, PendingSlash>,
+    
+    pub keeper_account: Signer<'info>,
+}
+
+pub fn appeal_slash(ctx: Context<AppealSlash>, appeal: String) -> Result<()> {
+    require!(!appeal.is_empty(), SolCronError::InvalidParameters);
+    require!(appeal.len() <= PendingSlash::MAX_REASON_LEN, SolCronError::InvalidParameters);
+
+    let pending_slash = &mut ctx.accounts.pending_slash;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp <= pending_slash.dispute_deadline,
+        SolCronError::DisputeWindowClosed
+    );
+    require!(pending_slash.appeal.is_none(), SolCronError::SlashUnderAppeal);
+
+    pending_slash.appeal = Some(appeal.clone());
+
+    emit!(SlashAppealed {
+        keeper: pending_slash.keeper_address,
+        appeal,
+    });
+
+    msg!("Keeper {} appealed its pending slash", pending_slash.keeper_address);
+
+    Ok(())
+}
 
-    // Reduce keeper's stake and rewards
-    if keeper.stake_amount >= actual_slash_amount {
-        keeper.stake_amount -= actual_slash_amount;
+// Resolve Slash
+#[derive(Accounts)]
+pub struct ResolveSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.admin == admin.key() @ SolCronError::Unauthorized,
+        constraint = registry_state.version == RegistryState::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    #[account(
+        mut,
+        address = pending_slash.keeper @ SolCronError::InvalidKeeper
+    )]
+    pub keeper: Account<'info, Keeper>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending_slash", pending_slash.keeper.as_ref()],
+        bump = pending_slash.bump,
+        close = slasher
+    )]
+    pub pending_slash: Account<'info, PendingSlash>,
+    
+    /// CHECK: Slasher that proposed the slash, receives the pending account rent
+    #[account(
+        mut,
+        address = pending_slash.slasher @ SolCronError::InvalidParameters
+    )]
+    pub slasher: AccountInfo<'info>,
+    
+    /// CHECK: Treasury account to receive upheld slashes
+    #[account(
+        mut,
+        address = registry_state.treasury @ SolCronError::InvalidParameters
+    )]
+    pub treasury: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    /// CHECK: Keeper's wallet, refunded directly if the keeper has since been deactivated
+    #[account(
+        mut,
+        address = pending_slash.keeper_address @ SolCronError::InvalidParameters
+    )]
+    pub keeper_account: AccountInfo<'info>,
+    
+    /// Record of the applied slash; only needed when upholding
+    #[account(
+        init,
+        payer = admin,
+        space = SlashRecord::MAX_SIZE,
+        seeds = [b"slash_record", registry_state.slash_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub slash_record: Option<Account<'info, SlashRecord>>,
+    
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn resolve_slash(ctx: Context<ResolveSlash>, uphold: bool) -> Result<()> {
+    if uphold {
+        let slash_record = ctx.accounts.slash_record.as_mut().ok_or(SolCronError::InvalidParameters)?;
+        slash_record.bump = ctx.bumps.slash_record.unwrap_or_default();
+        apply_slash(
+            &mut ctx.accounts.registry_state,
+            &mut ctx.accounts.keeper,
+            &ctx.accounts.pending_slash,
+            &ctx.accounts.treasury,
+            &mut ctx.accounts.insurance_pool,
+            slash_record,
+        )
     } else {
-        let remaining_slash = actual_slash_amount - keeper.stake_amount;
-        keeper.stake_amount = 0;
-        keeper.pending_rewards = keeper.pending_rewards.saturating_sub(remaining_slash);
+        release_slash(
+            &mut ctx.accounts.keeper,
+            &ctx.accounts.pending_slash,
+            &ctx.accounts.keeper_account,
+            ctx.accounts.admin.key(),
+        )
     }
+}
+
+// Finalize Slash
+#[derive(Accounts)]
+pub struct FinalizeSlash<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.version == RegistryState::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    #[account(
+        mut,
+        address = pending_slash.keeper @ SolCronError::InvalidKeeper
+    )]
+    pub keeper: Account<'info, Keeper>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending_slash", pending_slash.keeper.as_ref()],
+        bump = pending_slash.bump,
+        close = slasher
+    )]
+    pub pending_slash: Account<'info, PendingSlash>,
+    
+    /// CHECK: Slasher that proposed the slash, receives the pending account rent
+    #[account(
+        mut,
+        address = pending_slash.slasher @ SolCronError::InvalidParameters
+    )]
+    pub slasher: AccountInfo<'info>,
+    
+    /// CHECK: Treasury account to receive slashed funds
+    #[account(
+        mut,
+        address = registry_state.treasury @ SolCronError::InvalidParameters
+    )]
+    pub treasury: AccountInfo<'info>,    
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = SlashRecord::MAX_SIZE,
+        seeds = [b"slash_record", registry_state.slash_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+    
+    /// Anyone may finalize; pays the slash record rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn finalize_slash(ctx: Context<FinalizeSlash>) -> Result<()> {
+    let pending_slash = &ctx.accounts.pending_slash;
+    let clock = Clock::get()?;
+
+    // Unappealed slashes apply once the dispute window has closed
+    require!(
+        clock.unix_timestamp > pending_slash.dispute_deadline,
+        SolCronError::DisputeWindowOpen
+    );
+    require!(pending_slash.appeal.is_none(), SolCronError::SlashUnderAppeal);
+
+    ctx.accounts.slash_record.bump = ctx.bumps.slash_record;
+    apply_slash(
+        &mut ctx.accounts.registry_state,
+        &mut ctx.accounts.keeper,
+        pending_slash,
+        &ctx.accounts.treasury,
+        &mut ctx.accounts.insurance_pool,
+        &mut ctx.accounts.slash_record,
+    )
+}
+
+/// Split escrowed funds between the insurance pool and the treasury,
+/// penalize the keeper and record the slash for insurance claims
+fn apply_slash<'info>(
+    registry_state: &mut RegistryState,
+    keeper: &mut Account<'info, Keeper>,
+    pending_slash: &Account<'info, PendingSlash>,
+    treasury: &AccountInfo<'info>,
+    insurance_pool: &mut Account<'info, InsurancePool>,
+    slash_record: &mut Account<'info, SlashRecord>,
+) -> Result<()> {
+    let slash_amount = pending_slash.escrowed();
+    let insurance_amount = RegistryState::insurance_share(slash_amount, registry_state.insurance_slash_share_bps);
+    let escrow = pending_slash.to_account_info();
+
+    deposit_to_insurance(registry_state, insurance_pool, &escrow, insurance_amount, InsuranceSource::Slash)?;
+    **escrow.try_borrow_mut_lamports()? -= slash_amount - insurance_amount;
+    **treasury.try_borrow_mut_lamports()? += slash_amount - insurance_amount;
+
+    let slash_id = registry_state.slash_count;
+    registry_state.slash_count += 1;
+
+    slash_record.slash_id = slash_id;
+    slash_record.keeper = pending_slash.keeper_address;
+    slash_record.amount = slash_amount;
+    slash_record.insurance_amount = insurance_amount;
+    slash_record.slashed_at = Clock::get()?.unix_timestamp;
+    slash_record.claimed = false;
 
     // Severely impact reputation
     keeper.reputation_score = keeper.reputation_score.saturating_sub(2000); // -20%
     
     // If stake is below minimum, deactivate keeper
-    if keeper.stake_amount < ctx.accounts.registry_state.min_stake {
+    if keeper.stake_amount < registry_state.min_stake {
         keeper.is_active = false;
     }
 
     emit!(KeeperSlashed {
+        slash_id,
         keeper: keeper.address,
-        slash_amount: actual_slash_amount,
-        reason: reason.clone(),
+        slash_amount,
+        delegated_amount: pending_slash.delegated_amount,
+        insurance_amount,
+        reason: pending_slash.reason.clone(),
         new_stake: keeper.stake_amount,
         new_reputation: keeper.reputation_score,
     });
 
     msg!("Keeper {} slashed {} lamports for: {}", 
-         keeper.address, actual_slash_amount, reason);
+         keeper.address, slash_amount, pending_slash.reason);
 
     Ok(())
 }
 
-// Update Registry Parameters
+/// Return escrowed funds to the keeper, or to its wallet if it is no longer
+/// active. Delegated funds always return to the delegation pool.
+fn release_slash<'info>(
+    keeper: &mut Account<'info, Keeper>,
+    pending_slash: &Account<'info, PendingSlash>,
+    keeper_account: &AccountInfo<'info>,
+    resolved_by: Pubkey,
+) -> Result<()> {
+    let amount = pending_slash.escrowed();
+    let keeper_amount = amount - pending_slash.delegated_amount;
+
+    **pending_slash.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **keeper.to_account_info().try_borrow_mut_lamports()? += pending_slash.delegated_amount;
+    keeper.delegated_stake += pending_slash.delegated_amount;
+    if keeper.is_active {
+        **keeper.to_account_info().try_borrow_mut_lamports()? += keeper_amount;
+        keeper.stake_amount += pending_slash.stake_amount;
+        keeper.pending_rewards += pending_slash.rewards_amount;
+    } else {
+        **keeper_account.try_borrow_mut_lamports()? += keeper_amount;
+    }
+
+    emit!(SlashRejected {
+        keeper: keeper.address,
+        refunded_amount: amount,
+        resolved_by,
+    });
+
+    msg!("Pending slash of keeper {} rejected, {} lamports returned", keeper.address, amount);
+
+    Ok(())
+}
+
+// Propose Registry Parameters
 #[derive(Accounts)]
-pub struct UpdateRegistryParams<'info> {
+pub struct ProposeParams<'info> {
     #[account(
-        mut,
         seeds = [b"registry"],
-        bump = registry_state.bump,
-        constraint = registry_state.admin == admin.key() @ SolCronError::Unauthorized
+        bump = registry_state.bump
     )]
     pub registry_state: Account<'info, RegistryState>,
     
-    pub admin: Signer<'info>,
+    #[account(
+        init,
+        payer = proposer,
+        space = PendingParams::MAX_SIZE,
+        seeds = [b"pending_params"],
+        bump
+    )]
+    pub pending_params: Account<'info, PendingParams>,
+    
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
-pub fn update_registry_params(
-    ctx: Context<UpdateRegistryParams>,
-    base_fee: Option<u64>,
-    min_stake: Option<u64>,
-    protocol_fee_bps: Option<u16>,
-) -> Result<()> {
-    let registry_state = &mut ctx.accounts.registry_state;
+pub fn propose_params(ctx: Context<ProposeParams>, params: RegistryParams) -> Result<()> {
+    require!(!params.is_empty(), SolCronError::InvalidParameters);
+    require!(params.validate(), SolCronError::InvalidParameters);
 
-    if let Some(base_fee) = base_fee {
-        require!(base_fee > 0, SolCronError::InvalidParameters);
-        registry_state.base_fee = base_fee;
-    }
+    let registry_state = &ctx.accounts.registry_state;
+    let proposer = ctx.accounts.proposer.key();
 
-    if let Some(min_stake) = min_stake {
-        require!(min_stake > 0, SolCronError::InvalidParameters);
-        registry_state.min_stake = min_stake;
+    // Fee manager owns economics, upgrader owns the timelock itself
+    if params.changes_economics() {
+        require!(proposer == registry_state.fee_manager, SolCronError::Unauthorized);
     }
-
-    if let Some(protocol_fee_bps) = protocol_fee_bps {
-        require!(protocol_fee_bps <= 1000, SolCronError::InvalidParameters); // Max 10%
-        registry_state.protocol_fee_bps = protocol_fee_bps;
+    if params.changes_governance() {
+        require!(proposer == registry_state.upgrader, SolCronError::Unauthorized);
     }
 
+    let clock = Clock::get()?;
+    let executable_at = clock.unix_timestamp
+        .checked_add(registry_state.params_timelock)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    let pending_params = &mut ctx.accounts.pending_params;
+    pending_params.proposer = proposer;
+    pending_params.params = params.clone();
+    pending_params.proposed_at = clock.unix_timestamp;
+    pending_params.executable_at = executable_at;
+    pending_params.bump = ctx.bumps.pending_params;
+
+    emit!(ParamsProposed {
+        proposer,
+        params,
+        executable_at,
+    });
+
+    msg!("Registry parameters proposed by {}, executable at {}", proposer, executable_at);
+
+    Ok(())
+}
+
+// Execute Registry Parameters
+#[derive(Accounts)]
+pub struct ExecuteParams<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending_params"],
+        bump = pending_params.bump,
+        close = proposer
+    )]
+    pub pending_params: Account<'info, PendingParams>,
+    
+    /// CHECK: Original proposer, receives the pending account rent
+    #[account(
+        mut,
+        address = pending_params.proposer @ SolCronError::InvalidParameters
+    )]
+    pub proposer: AccountInfo<'info>,
+}
+
+pub fn execute_params(ctx: Context<ExecuteParams>) -> Result<()> {
+    let registry_state = &mut ctx.accounts.registry_state;
+    let pending_params = &ctx.accounts.pending_params;
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp >= pending_params.executable_at,
+        SolCronError::TimelockNotExpired
+    );
+
+    pending_params.params.apply(registry_state);
+
     emit!(RegistryParamsUpdated {
         base_fee: registry_state.base_fee,
         min_stake: registry_state.min_stake,
         protocol_fee_bps: registry_state.protocol_fee_bps,
+        max_executions_per_window: registry_state.max_executions_per_window,
+        rate_limit_window_slots: registry_state.rate_limit_window_slots,
+        unregister_cooldown: registry_state.unregister_cooldown,
+        params_timelock: registry_state.params_timelock,
+        slash_dispute_window: registry_state.slash_dispute_window,
+        insurance_slash_share_bps: registry_state.insurance_slash_share_bps,
+        insurance_fee_share_bps: registry_state.insurance_fee_share_bps,
+        delegation_unbonding_period: registry_state.delegation_unbonding_period,
+        keeper_tiers: registry_state.keeper_tiers.clone(),
+        job_reap_delay: registry_state.job_reap_delay,
+        reap_bounty_bps: registry_state.reap_bounty_bps,
+    });
+
+    msg!("Registry parameters proposed by {} executed", pending_params.proposer);
+
+    Ok(())
+}
+
+// Cancel Registry Parameters
+#[derive(Accounts)]
+pub struct CancelParams<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_state.bump
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    #[account(
+        mut,
+        seeds = [b"pending_params"],
+        bump = pending_params.bump,
+        close = proposer
+    )]
+    pub pending_params: Account<'info, PendingParams>,
+    
+    /// CHECK: Original proposer, receives the pending account rent
+    #[account(
+        mut,
+        address = pending_params.proposer @ SolCronError::InvalidParameters
+    )]
+    pub proposer: AccountInfo<'info>,
+    
+    #[account(
+        constraint = authority.key() == pending_params.proposer
+            || authority.key() == registry_state.admin @ SolCronError::Unauthorized
+    )]
+    pub authority: Signer<'info>,
+}
+
+pub fn cancel_params(ctx: Context<CancelParams>) -> Result<()> {
+    let pending_params = &ctx.accounts.pending_params;
+
+    emit!(ParamsCancelled {
+        proposer: pending_params.proposer,
+        cancelled_by: ctx.accounts.authority.key(),
+        params: pending_params.params.clone(),
+    });
+
+    msg!("Registry parameters proposed by {} cancelled", pending_params.proposer);
+
+    Ok(())
+}
+
+// Set Role
+#[derive(Accounts)]
+pub struct SetRole<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.admin == admin.key() @ SolCronError::Unauthorized
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    pub admin: Signer<'info>,
+}
+
+pub fn set_role(ctx: Context<SetRole>, role: RegistryRole, new_key: Pubkey) -> Result<()> {
+    let registry_state = &mut ctx.accounts.registry_state;
+    let old_key = registry_state.set_role_key(role, new_key);
+
+    emit!(RoleUpdated {
+        role,
+        old_key,
+        new_key,
+    });
+
+    msg!("Registry role {:?} transferred from {} to {}", role, old_key, new_key);
+
+    Ok(())
+}
+
+// Set Registry Paused
+#[derive(Accounts)]
+pub struct SetRegistryPaused<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.pauser == pauser.key() @ SolCronError::Unauthorized
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    pub pauser: Signer<'info>,
+}
+
+pub fn set_registry_paused(ctx: Context<SetRegistryPaused>, paused: bool) -> Result<()> {
+    let registry_state = &mut ctx.accounts.registry_state;
+    registry_state.is_paused = paused;
+
+    emit!(RegistryPauseChanged {
+        paused,
+        pauser: ctx.accounts.pauser.key(),
     });
 
-    msg!("Registry parameters updated by admin: {}", registry_state.admin);
+    msg!("Registry paused: {}", paused);
+
+    Ok(())
+}
+
+// Withdraw Protocol Revenue
+#[derive(Accounts)]
+pub struct WithdrawProtocolRevenue<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.fee_manager == fee_manager.key() @ SolCronError::Unauthorized,
+        constraint = registry_state.version == RegistryState::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    /// CHECK: Treasury account to receive the protocol revenue
+    #[account(
+        mut,
+        address = registry_state.treasury @ SolCronError::InvalidParameters
+    )]
+    pub treasury: AccountInfo<'info>,
+    
+    pub fee_manager: Signer<'info>,
+}
+
+pub fn withdraw_protocol_revenue(ctx: Context<WithdrawProtocolRevenue>) -> Result<()> {
+    let registry_info = ctx.accounts.registry_state.to_account_info();
+
+    // Everything above the rent-exempt minimum is accumulated protocol revenue
+    let rent_exempt = Rent::get()?.minimum_balance(registry_info.data_len());
+    let amount = registry_info.lamports().saturating_sub(rent_exempt);
+    require!(amount > 0, SolCronError::InsufficientBalance);
+
+    **registry_info.try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.treasury.try_borrow_mut_lamports()? += amount;
+
+    emit!(ProtocolRevenueWithdrawn {
+        amount,
+        treasury: ctx.accounts.treasury.key(),
+        fee_manager: ctx.accounts.fee_manager.key(),
+    });
+
+    msg!("Withdrew {} lamports of protocol revenue to the treasury", amount);
 
     Ok(())
 }
@@ -172,18 +707,89 @@ pub fn transfer_admin(ctx: Context<TransferAdmin>) -> Result<()> {
 // Events
 #[event]
 pub struct KeeperSlashed {
+    pub slash_id: u64,
     pub keeper: Pubkey,
     pub slash_amount: u64,
+    pub delegated_amount: u64,
+    pub insurance_amount: u64,
     pub reason: String,
     pub new_stake: u64,
     pub new_reputation: u64,
 }
 
+#[event]
+pub struct SlashProposed {
+    pub keeper: Pubkey,
+    pub slasher: Pubkey,
+    pub slash_amount: u64,
+    pub delegated_amount: u64,
+    pub reason: String,
+    pub dispute_deadline: i64,
+}
+
+#[event]
+pub struct SlashAppealed {
+    pub keeper: Pubkey,
+    pub appeal: String,
+}
+
+#[event]
+pub struct SlashRejected {
+    pub keeper: Pubkey,
+    pub refunded_amount: u64,
+    pub resolved_by: Pubkey,
+}
+
 #[event]
 pub struct RegistryParamsUpdated {
     pub base_fee: u64,
     pub min_stake: u64,
     pub protocol_fee_bps: u16,
+    pub max_executions_per_window: u32,
+    pub rate_limit_window_slots: u64,
+    pub unregister_cooldown: i64,
+    pub params_timelock: i64,
+    pub slash_dispute_window: i64,
+    pub insurance_slash_share_bps: u16,
+    pub insurance_fee_share_bps: u16,
+    pub delegation_unbonding_period: i64,
+    pub keeper_tiers: Vec<KeeperTier>,
+    pub job_reap_delay: i64,
+    pub reap_bounty_bps: u16,
+}
+
+#[event]
+pub struct ParamsProposed {
+    pub proposer: Pubkey,
+    pub params: RegistryParams,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct ParamsCancelled {
+    pub proposer: Pubkey,
+    pub cancelled_by: Pubkey,
+    pub params: RegistryParams,
+}
+
+#[event]
+pub struct RoleUpdated {
+    pub role: RegistryRole,
+    pub old_key: Pubkey,
+    pub new_key: Pubkey,
+}
+
+#[event]
+pub struct RegistryPauseChanged {
+    pub paused: bool,
+    pub pauser: Pubkey,
+}
+
+#[event]
+pub struct ProtocolRevenueWithdrawn {
+    pub amount: u64,
+    pub treasury: Pubkey,
+    pub fee_manager: Pubkey,
 }
 
 #[event]
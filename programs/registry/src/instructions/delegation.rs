@@ -0,0 +1,323 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+// Delegate Stake
+#[derive(Accounts)]
+#[instruction(keeper_pubkey: Pubkey)]
+pub struct DelegateStake<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = !registry_state.is_paused @ SolCronError::RegistryPaused
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [b"keeper", keeper_pubkey.as_ref()],
+        bump = keeper.bump,
+        constraint = keeper.is_active @ SolCronError::InvalidKeeper,
+        constraint = keeper.version == Keeper::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub keeper: Account<'info, Keeper>,
+
+    #[account(
+        init_if_needed,
+        payer = delegator,
+        space = Delegation::MAX_SIZE,
+        seeds = [b"delegation", keeper.key().as_ref(), delegator.key().as_ref()],
+        bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn delegate_stake(ctx: Context<DelegateStake>, _keeper_pubkey: Pubkey, amount: u64) -> Result<()> {
+    require!(amount > 0, SolCronError::InvalidParameters);
+
+    let keeper = &mut ctx.accounts.keeper;
+    let delegation = &mut ctx.accounts.delegation;
+    let clock = Clock::get()?;
+
+    // Shares are priced at the pool's current value so earlier delegators
+    // keep the rewards and slashes already applied
+    let shares = keeper.shares_for(amount).ok_or(SolCronError::InvalidKeeper)?;
+    require!(shares > 0, SolCronError::InvalidParameters);
+
+    let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.delegator.key(),
+        &keeper.key(),
+        amount,
+    );
+
+    anchor_lang::solana_program::program::invoke(
+        &transfer_instruction,
+        &[
+            ctx.accounts.delegator.to_account_info(),
+            keeper.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    keeper.delegated_stake = keeper.delegated_stake
+        .checked_add(amount)
+        .ok_or(SolCronError::MathOverflow)?;
+    keeper.delegator_shares = keeper.delegator_shares
+        .checked_add(shares)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    if delegation.shares == 0 {
+        delegation.keeper = keeper.key();
+        delegation.delegator = ctx.accounts.delegator.key();
+        delegation.delegated_at = clock.unix_timestamp;
+        delegation.bump = ctx.bumps.delegation;
+    }
+    delegation.shares += shares;
+
+    emit!(StakeDelegated {
+        keeper: keeper.address,
+        delegator: delegation.delegator,
+        amount,
+        shares,
+        effective_stake: keeper.effective_stake(),
+    });
+
+    msg!("{} delegated {} lamports to keeper {}", delegation.delegator, amount, keeper.address);
+
+    Ok(())
+}
+
+// Undelegate Stake
+#[derive(Accounts)]
+pub struct UndelegateStake<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_state.bump
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        address = delegation.keeper @ SolCronError::InvalidKeeper,
+        constraint = keeper.version == Keeper::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub keeper: Account<'info, Keeper>,
+
+    #[account(
+        mut,
+        seeds = [b"delegation", delegation.keeper.as_ref(), delegator.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    pub delegator: Signer<'info>,
+}
+
+/// Start unbonding `shares` of a delegation. Unbonding shares stop counting
+/// toward the keeper's effective stake but stay slashable until withdrawn.
+pub fn undelegate_stake(ctx: Context<UndelegateStake>, shares: u64) -> Result<()> {
+    require!(shares > 0, SolCronError::InvalidParameters);
+
+    let keeper = &mut ctx.accounts.keeper;
+    let delegation = &mut ctx.accounts.delegation;
+    let clock = Clock::get()?;
+
+    let bonded_shares = delegation.shares - delegation.unbonding_shares;
+    require!(shares <= bonded_shares, SolCronError::InsufficientDelegation);
+
+    // Further undelegations restart the unbonding period for all unbonding shares
+    let release_at = clock.unix_timestamp
+        .checked_add(ctx.accounts.registry_state.delegation_unbonding_period)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    delegation.unbonding_shares += shares;
+    delegation.unbonding_release_at = release_at;
+    keeper.unbonding_shares += shares;
+
+    emit!(DelegationUnbonding {
+        keeper: keeper.address,
+        delegator: delegation.delegator,
+        shares,
+        amount: keeper.delegated_value(shares),
+        release_at,
+    });
+
+    msg!("{} unbonding {} shares from keeper {} until {}",
+         delegation.delegator, shares, keeper.address, release_at);
+
+    Ok(())
+}
+
+// Withdraw Delegation
+#[derive(Accounts)]
+pub struct WithdrawDelegation<'info> {
+    #[account(
+        mut,
+        address = delegation.keeper @ SolCronError::InvalidKeeper,
+        constraint = keeper.version == Keeper::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub keeper: Account<'info, Keeper>,
+
+    #[account(
+        mut,
+        seeds = [b"delegation", delegation.keeper.as_ref(), delegator.key().as_ref()],
+        bump = delegation.bump
+    )]
+    pub delegation: Account<'info, Delegation>,
+
+    #[account(mut)]
+    pub delegator: Signer<'info>,
+}
+
+pub fn withdraw_delegation(ctx: Context<WithdrawDelegation>) -> Result<()> {
+    let keeper = &mut ctx.accounts.keeper;
+    let delegation = &mut ctx.accounts.delegation;
+    let clock = Clock::get()?;
+
+    let shares = delegation.unbonding_shares;
+    require!(shares > 0, SolCronError::InsufficientDelegation);
+    require!(
+        clock.unix_timestamp >= delegation.unbonding_release_at,
+        SolCronError::UnbondingInProgress
+    );
+
+    // Unbonding shares are redeemed at the pool's value after any slashes
+    let amount = keeper.delegated_value(shares);
+
+    **keeper.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.delegator.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    keeper.delegated_stake -= amount;
+    keeper.delegator_shares -= shares;
+    keeper.unbonding_shares -= shares;
+    delegation.shares -= shares;
+    delegation.unbonding_shares = 0;
+
+    emit!(DelegationWithdrawn {
+        keeper: keeper.address,
+        delegator: delegation.delegator,
+        shares,
+        amount,
+    });
+
+    msg!("{} withdrew {} lamports delegated to keeper {}", delegation.delegator, amount, keeper.address);
+
+    // Fully withdrawn delegations return their rent to the delegator
+    if delegation.shares == 0 {
+        delegation.close(ctx.accounts.delegator.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+// Set Commission
+#[derive(Accounts)]
+pub struct SetCommission<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_state.bump
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [b"keeper", keeper_account.key().as_ref()],
+        bump = keeper.bump,
+        constraint = keeper.address == keeper_account.key() @ SolCronError::Unauthorized,
+        constraint = keeper.version == Keeper::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub keeper: Account<'info, Keeper>,
+
+    pub keeper_account: Signer<'info>,
+}
+
+/// Set the keeper's commission. Decreases apply at once; increases are queued
+/// for the unbonding period so delegators can leave before they take effect.
+pub fn set_commission(ctx: Context<SetCommission>, commission_bps: u16) -> Result<()> {
+    require!(commission_bps <= 10000, SolCronError::InvalidParameters);
+
+    let keeper = &mut ctx.accounts.keeper;
+    let now = Clock::get()?.unix_timestamp;
+    keeper.settle_commission(now);
+
+    if commission_bps > keeper.commission_bps {
+        let effective_at = now
+            .checked_add(ctx.accounts.registry_state.delegation_unbonding_period)
+            .ok_or(SolCronError::MathOverflow)?;
+        keeper.pending_commission_bps = commission_bps;
+        keeper.commission_effective_at = effective_at;
+
+        emit!(CommissionIncreaseQueued {
+            keeper: keeper.address,
+            current_commission_bps: keeper.commission_bps,
+            new_commission_bps: commission_bps,
+            effective_at,
+        });
+
+        msg!("Keeper {} commission rises to {} bps at {}", keeper.address, commission_bps, effective_at);
+
+        return Ok(());
+    }
+
+    // A decrease also drops any queued increase
+    keeper.pending_commission_bps = 0;
+    keeper.commission_effective_at = 0;
+    let old_commission_bps = std::mem::replace(&mut keeper.commission_bps, commission_bps);
+
+    emit!(CommissionUpdated {
+        keeper: keeper.address,
+        old_commission_bps,
+        new_commission_bps: commission_bps,
+    });
+
+    msg!("Keeper {} commission set to {} bps", keeper.address, commission_bps);
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct StakeDelegated {
+    pub keeper: Pubkey,
+    pub delegator: Pubkey,
+    pub amount: u64,
+    pub shares: u64,
+    pub effective_stake: u64,
+}
+
+#[event]
+pub struct DelegationUnbonding {
+    pub keeper: Pubkey,
+    pub delegator: Pubkey,
+    pub shares: u64,
+    pub amount: u64,
+    pub release_at: i64,
+}
+
+#[event]
+pub struct DelegationWithdrawn {
+    pub keeper: Pubkey,
+    pub delegator: Pubkey,
+    pub shares: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct CommissionUpdated {
+    pub keeper: Pubkey,
+    pub old_commission_bps: u16,
+    pub new_commission_bps: u16,
+}
+
+#[event]
+pub struct CommissionIncreaseQueued {
+    pub keeper: Pubkey,
+    pub current_commission_bps: u16,
+    pub new_commission_bps: u16,
+    pub effective_at: i64,
+}
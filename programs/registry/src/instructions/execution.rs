@@ -1,6 +1,11 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{get_stack_height, Instruction, TRANSACTION_LEVEL_STACK_HEIGHT};
+use anchor_lang::solana_program::program::{get_return_data, invoke_signed};
+use solana_instructions_sysvar::load_instruction_at_checked;
 use crate::state::*;
 use crate::errors::*;
+use crate::oracle::parse_pyth_price;
+use crate::instructions::insurance::{deposit_to_insurance, InsuranceSource};
 
 // Execute Job
 #[derive(Accounts)]
@@ -9,7 +14,9 @@ pub struct ExecuteJob<'info> {
     #[account(
         mut,
         seeds = [b"registry"],
-        bump = registry_state.bump
+        bump = registry_state.bump,
+        constraint = registry_state.version == RegistryState::VERSION @ SolCronError::AccountNotMigrated,
+        constraint = !registry_state.is_paused @ SolCronError::RegistryPaused
     )]
     pub registry_state: Account<'info, RegistryState>,
     
@@ -18,7 +25,9 @@ pub struct ExecuteJob<'info> {
         seeds = [b"job", job_id.to_le_bytes().as_ref()],
         bump = automation_job.bump,
         constraint = automation_job.is_active @ SolCronError::InvalidJob,
-        constraint = automation_job.job_id == job_id @ SolCronError::InvalidJob
+        constraint = !automation_job.is_paused @ SolCronError::JobPaused,
+        constraint = automation_job.job_id == job_id @ SolCronError::InvalidJob,
+        constraint = automation_job.version == AutomationJob::VERSION @ SolCronError::AccountNotMigrated
     )]
     pub automation_job: Account<'info, AutomationJob>,
     
@@ -27,7 +36,8 @@ pub struct ExecuteJob<'info> {
         seeds = [b"keeper", keeper_account.key().as_ref()],
         bump = keeper.bump,
         constraint = keeper.is_active @ SolCronError::InvalidKeeper,
-        constraint = keeper.address == keeper_account.key() @ SolCronError::Unauthorized
+        constraint = keeper.address == keeper_account.key() @ SolCronError::Unauthorized,
+        constraint = keeper.version == Keeper::VERSION @ SolCronError::AccountNotMigrated
     )]
     pub keeper: Account<'info, Keeper>,
     
@@ -50,10 +60,42 @@ pub struct ExecuteJob<'info> {
     /// CHECK: Target program to execute
     pub target_program: AccountInfo<'info>,
     
+    /// CHECK: Registry PDA that signs CPIs into the execution engine
+    #[account(
+        seeds = [b"execution_authority"],
+        bump
+    )]
+    pub execution_authority: AccountInfo<'info>,
+    
+    /// CHECK: SolCron execution engine
+    #[account(address = crate::EXECUTION_PROGRAM_ID @ SolCronError::InvalidParameters)]
+    pub execution_program: AccountInfo<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+    
     pub system_program: Program<'info, System>,
+    
+    /// CHECK: Instructions sysvar, read to price the keeper's transaction fees
+    #[account(address = solana_sdk_ids::sysvar::instructions::ID @ SolCronError::InvalidParameters)]
+    pub instructions_sysvar: UncheckedAccount<'info>,
+    
+    /// CHECK: Account read by the job's trigger; checked against the trigger params
+    pub trigger_account: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
+    pub funding_pool: Option<Account<'info, FundingPool>>,
 }
 
-pub fn execute_job(ctx: Context<ExecuteJob>, job_id: u64) -> Result<()> {
+pub fn execute_job<'info>(
+    ctx: Context<'_, '_, '_, 'info, ExecuteJob<'info>>,
+    job_id: u64,
+    perform_data: Vec<u8>,
+) -> Result<()> {
     let automation_job = &mut ctx.accounts.automation_job;
     let keeper = &mut ctx.accounts.keeper;
     let registry_state = &mut ctx.accounts.registry_state;
@@ -66,87 +108,122 @@ pub fn execute_job(ctx: Context<ExecuteJob>, job_id: u64) -> Result<()> {
         SolCronError::InvalidParameters
     );
 
+    // Perform data comes from simulating the job's check instruction off-chain;
+    // the target must re-validate it, as with any keeper-supplied input
+    require!(
+        perform_data.len() <= AutomationJob::MAX_PERFORM_DATA_SIZE
+            && (automation_job.check_instruction.is_some() || perform_data.is_empty()),
+        SolCronError::InvalidParameters
+    );
+
+    // The job also repays the keeper's record rent and, up to its cap, the
+    // signature and priority fees of this transaction
+    let transaction_fee = transaction_fee(&ctx.accounts.instructions_sysvar)?;
+    let reimbursement = transaction_fee
+        .min(automation_job.max_fee_reimbursement)
+        .checked_add(execution_record.to_account_info().lamports())
+        .ok_or(SolCronError::MathOverflow)?;
+    let execution_fee = check_execution_eligibility(
+        registry_state,
+        automation_job,
+        ctx.accounts.funding_pool.as_ref(),
+        registry_state.keeper_tier(keeper),
+        reimbursement,
+        clock.unix_timestamp,
+    )?;
+    let execution_cost = execution_fee
+        .checked_add(reimbursement)
+        .ok_or(SolCronError::MathOverflow)?;
+
     // Check if execution is allowed based on trigger type
-    let execution_allowed = match &automation_job.trigger_type {
-        TriggerType::TimeBased => {
-            // Parse interval from trigger_params (simplified for now)
-            let min_interval = if automation_job.trigger_params.len() >= 8 {
-                i64::from_le_bytes(automation_job.trigger_params[0..8].try_into().unwrap_or([0; 8]))
-            } else {
-                60 // Default 1 minute
-            };
-            clock.unix_timestamp - automation_job.last_execution >= min_interval
-        },
-        TriggerType::Conditional => {
-            // For now, we'll implement basic conditional logic
-            // In a full implementation, this would evaluate custom conditions
-            evaluate_conditional_trigger(automation_job, &clock)?
-        },
-        TriggerType::LogTrigger => {
-            // Log-based triggers would require additional event monitoring
-            // For now, we'll allow execution if enough time has passed
-            clock.unix_timestamp - automation_job.last_execution >= 60
-        },
-        TriggerType::Hybrid => {
-            // Hybrid triggers combine multiple conditions
-            evaluate_hybrid_trigger(automation_job, &clock)?
-        }
-    };
+    let (execution_allowed, missed) = evaluate_trigger(
+        automation_job,
+        &clock,
+        ctx.accounts.trigger_account.as_ref(),
+        ctx.remaining_accounts,
+    )?;
+    emit_missed_executions(automation_job, missed);
 
     require!(execution_allowed, SolCronError::InvalidTrigger);
 
-    // Check job has sufficient balance
-    let execution_fee = calculate_execution_fee(registry_state, automation_job)?;
-    require!(automation_job.balance >= execution_fee, SolCronError::InsufficientBalance);
+    // Enforce the per-keeper execution rate limit
     require!(
-        automation_job.balance - execution_fee >= automation_job.min_balance,
-        SolCronError::InsufficientBalance
+        keeper.consume_rate_limit(
+            clock.slot,
+            registry_state.rate_limit_window_slots,
+            registry_state.max_executions_per_window,
+        ),
+        SolCronError::RateLimitExceeded
+    );
+
+    // The execution authority signs the CPI, so registry PDAs passed along
+    // would reach the target with the registry's privileges
+    let reserved = [registry_state.key(), ctx.accounts.execution_authority.key(), automation_job.key()];
+    require!(
+        ctx.remaining_accounts.iter().all(|account| !reserved.contains(account.key)),
+        SolCronError::InvalidTargetAccount
     );
 
-    // Initialize execution record
+    // Execute the target instruction via CPI. A failing target aborts the
+    // whole transaction, so past this point the execution succeeded; failures
+    // leave no on-chain trace and are tracked by keepers off-chain.
+    execute_target_instruction(
+        automation_job,
+        &ctx.accounts.target_program,
+        &ctx.accounts.execution_program,
+        &ctx.accounts.execution_authority,
+        ctx.bumps.execution_authority,
+        ctx.remaining_accounts,
+        &perform_data,
+    )?;
+
     execution_record.job_id = job_id;
     execution_record.keeper = keeper.address;
     execution_record.timestamp = clock.unix_timestamp;
-    execution_record.success = false; // Will be updated based on execution result
+    execution_record.success = true;
     execution_record.gas_used = 0; // Would be measured in actual implementation
     execution_record.fee_paid = execution_fee;
     execution_record.error_code = None;
+    execution_record.return_data = target_return_data(&automation_job.target_program);
     execution_record.bump = ctx.bumps.execution_record;
-
-    // Attempt to execute the target instruction via CPI
-    let execution_result = execute_target_instruction(
-        &automation_job,
-        &ctx.accounts.target_program,
-        ctx.remaining_accounts,
-    );
-
-    let success = execution_result.is_ok();
-    let error_code = if execution_result.is_err() {
-        Some(1u32) // Generic error code, could be more specific based on error type
-    } else {
-        None
-    };
-
-    // Update execution record
-    execution_record.success = success;
-    execution_record.error_code = error_code;
+    execution_record.version = ExecutionRecord::VERSION;
 
     // Update job state
     automation_job.last_execution = clock.unix_timestamp;
     automation_job.execution_count += 1;
-    automation_job.balance -= execution_fee;
+
+    // Debit the funding pool the job draws from, or else the job itself
+    let fee_payer = match ctx.accounts.funding_pool.as_mut().filter(|_| automation_job.funding_pool.is_some()) {
+        Some(funding_pool) => {
+            funding_pool.balance -= execution_cost;
+            funding_pool.total_spent = funding_pool.total_spent.saturating_add(execution_cost);
+            automation_job.pool_spent = automation_job.pool_spent.saturating_add(execution_cost);
+            funding_pool.to_account_info()
+        }
+        None => {
+            automation_job.balance -= execution_cost;
+            automation_job.to_account_info()
+        }
+    };
+
+    // Reimbursement goes straight to the keeper's wallet rather than rewards
+    **fee_payer.try_borrow_mut_lamports()? -= reimbursement;
+    **ctx.accounts.keeper_account.to_account_info().try_borrow_mut_lamports()? += reimbursement;
+    keeper.total_reimbursed = keeper.total_reimbursed
+        .checked_add(reimbursement)
+        .ok_or(SolCronError::MathOverflow)?;
 
     keeper.total_executions += 1;
-    if success {
-        keeper.successful_executions += 1;
-    }
+    keeper.successful_executions += 1;
 
     // Distribute fees
     distribute_execution_fees(
         registry_state,
+        automation_job,
+        &fee_payer,
         keeper,
+        &mut ctx.accounts.insurance_pool,
         execution_fee,
-        &ctx.accounts.system_program,
     )?;
 
     // Update keeper reputation and last execution time
@@ -156,9 +233,10 @@ pub fn execute_job(ctx: Context<ExecuteJob>, job_id: u64) -> Result<()> {
     // Update registry stats
     registry_state.total_executions += 1;
 
-    // Deactivate job if balance is too low
-    if automation_job.balance < automation_job.min_balance {
-        automation_job.is_active = false;
+    // Deactivate job if balance is too low; pooled jobs stay active while the
+    // pool is topped up
+    if automation_job.funding_pool.is_none() && automation_job.balance < automation_job.min_balance {
+        automation_job.deactivate(clock.unix_timestamp);
         registry_state.active_jobs -= 1;
         
         emit!(JobDeactivated {
@@ -170,24 +248,256 @@ pub fn execute_job(ctx: Context<ExecuteJob>, job_id: u64) -> Result<()> {
     emit!(JobExecuted {
         job_id: automation_job.job_id,
         keeper: keeper.address,
-        success,
+        success: true,
         fee_paid: execution_fee,
+        reimbursed: reimbursement,
         gas_used: execution_record.gas_used,
+        error_code: None,
+        return_data: execution_record.return_data.clone(),
     });
 
-    if success {
-        msg!("Job {} executed successfully by keeper {}", job_id, keeper.address);
-    } else {
-        msg!("Job {} execution failed by keeper {}, error: {:?}", job_id, keeper.address, error_code);
-    }
+    msg!("Job {} executed successfully by keeper {}", job_id, keeper.address);
 
     Ok(())
 }
 
 // Helper functions
-fn calculate_execution_fee(registry_state: &RegistryState, _job: &AutomationJob) -> Result<u64> {
-    // Simple fee calculation - in a full implementation this would be more sophisticated
-    Ok(registry_state.base_fee)
+pub(crate) fn calculate_execution_fee(registry_state: &RegistryState, job: &AutomationJob) -> Result<u64> {
+    // Base fee plus the owner's tip for the executing keeper
+    Ok(registry_state.base_fee
+        .checked_add(job.tip_lamports)
+        .ok_or(SolCronError::MathOverflow)?)
+}
+
+/// Most a job repays its keeper for one run: the fee cap plus the execution
+/// record's rent
+pub(crate) fn max_reimbursement(job: &AutomationJob) -> Result<u64> {
+    Ok(job.max_fee_reimbursement
+        .checked_add(Rent::get()?.minimum_balance(ExecutionRecord::MAX_SIZE))
+        .ok_or(SolCronError::MathOverflow)?)
+}
+
+/// Lamports charged per transaction signature
+const LAMPORTS_PER_SIGNATURE: u64 = 5_000;
+
+/// Compute units allotted per instruction when no limit is requested
+const DEFAULT_INSTRUCTION_COMPUTE_UNITS: u64 = 200_000;
+
+/// Largest compute unit limit a transaction may request
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+/// This execution's share of the current transaction's signature and priority
+/// fees, priced from its signers and ComputeBudget instructions in the
+/// instructions sysvar and split evenly between its `execute_job` instructions.
+/// Executions made through CPI are not repaid, since a single top-level
+/// instruction could bundle any number of them.
+fn transaction_fee(instructions_sysvar: &AccountInfo) -> Result<u64> {
+    if get_stack_height() > TRANSACTION_LEVEL_STACK_HEIGHT {
+        return Ok(0);
+    }
+
+    let mut signers: Vec<Pubkey> = Vec::new();
+    let mut compute_unit_limit = None;
+    let mut compute_unit_price = 0u64;
+    let mut instruction_count = 0u64;
+    let mut execution_count = 0u64;
+
+    let mut index = 0;
+    while let Ok(instruction) = load_instruction_at_checked(index, instructions_sysvar) {
+        if instruction.program_id == solana_sdk_ids::compute_budget::ID {
+            // SetComputeUnitLimit (2) carries a u32, SetComputeUnitPrice (3) a u64
+            match instruction.data.split_first() {
+                Some((2, limit)) => {
+                    if let Ok(limit) = <[u8; 4]>::try_from(limit) {
+                        compute_unit_limit = Some(u32::from_le_bytes(limit) as u64);
+                    }
+                }
+                Some((3, price)) => {
+                    if let Ok(price) = <[u8; 8]>::try_from(price) {
+                        compute_unit_price = u64::from_le_bytes(price);
+                    }
+                }
+                _ => {}
+            }
+        } else {
+            instruction_count += 1;
+        }
+
+        if instruction.program_id == crate::ID
+            && instruction.data.starts_with(crate::instruction::ExecuteJob::DISCRIMINATOR)
+        {
+            execution_count += 1;
+        }
+
+        for meta in instruction.accounts.iter().filter(|meta| meta.is_signer) {
+            if !signers.contains(&meta.pubkey) {
+                signers.push(meta.pubkey);
+            }
+        }
+        index += 1;
+    }
+
+    let signature_fee = (signers.len().max(1) as u64).saturating_mul(LAMPORTS_PER_SIGNATURE);
+
+    // Priority fee is the micro-lamport unit price times the requested limit, rounded up
+    let compute_unit_limit = compute_unit_limit
+        .unwrap_or(instruction_count.saturating_mul(DEFAULT_INSTRUCTION_COMPUTE_UNITS))
+        .min(MAX_COMPUTE_UNIT_LIMIT);
+    let priority_fee = (compute_unit_price as u128 * compute_unit_limit as u128).div_ceil(1_000_000);
+
+    let total_fee = signature_fee.saturating_add(u64::try_from(priority_fee).unwrap_or(u64::MAX));
+
+    // Each job pays its share, so bundling runs never repays the fee twice
+    Ok(total_fee / execution_count.max(1))
+}
+
+/// Check that a job, or the funding pool it draws from, can pay `execution_fee`
+/// without dropping below its minimum balance
+pub(crate) fn check_job_funding(
+    job: &AutomationJob,
+    funding_pool: Option<&Account<FundingPool>>,
+    execution_fee: u64,
+) -> Result<()> {
+    let (balance, min_balance) = match job.funding_pool {
+        Some(pool_key) => {
+            let pool = funding_pool
+                .filter(|pool| pool.key() == pool_key)
+                .ok_or(SolCronError::InvalidFundingPool)?;
+            require!(
+                job.pool_spend_cap == 0 || job.pool_spent.saturating_add(execution_fee) <= job.pool_spend_cap,
+                SolCronError::PoolSpendCapExceeded
+            );
+            (pool.balance, pool.min_balance)
+        }
+        None => (job.balance, job.min_balance),
+    };
+
+    require!(
+        balance.checked_sub(execution_fee).is_some_and(|remaining| remaining >= min_balance),
+        SolCronError::InsufficientBalance
+    );
+
+    Ok(())
+}
+
+/// Checks `execute_job` makes before evaluating a job's trigger: the job has
+/// not expired, a keeper of `keeper_tier` may run it, and its fee is within
+/// the owner's cap and payable with `reimbursement` on top. Returns the fee.
+pub(crate) fn check_execution_eligibility(
+    registry_state: &RegistryState,
+    job: &AutomationJob,
+    funding_pool: Option<&Account<FundingPool>>,
+    keeper_tier: u8,
+    reimbursement: u64,
+    now: i64,
+) -> Result<u64> {
+    require!(!job.is_expired(now), SolCronError::JobExpired);
+
+    // Jobs guarding large value only run with enough keeper stake at risk
+    require!(keeper_tier >= job.min_keeper_tier, SolCronError::KeeperTierTooLow);
+
+    let execution_fee = calculate_execution_fee(registry_state, job)?;
+    require!(
+        job.max_fee_per_execution == 0 || execution_fee <= job.max_fee_per_execution,
+        SolCronError::FeeExceedsCap
+    );
+
+    let execution_cost = execution_fee
+        .checked_add(reimbursement)
+        .ok_or(SolCronError::MathOverflow)?;
+    check_job_funding(job, funding_pool, execution_cost)?;
+
+    Ok(execution_fee)
+}
+
+/// Split an execution fee into its (protocol, keeper) shares. The protocol
+/// fee is taken from the base fee only; the job's tip goes to the keeper.
+pub(crate) fn split_execution_fee(registry_state: &RegistryState, job: &AutomationJob, total_fee: u64) -> (u64, u64) {
+    let base_fee = total_fee.saturating_sub(job.tip_lamports);
+    let protocol_fee = (base_fee as u128 * registry_state.protocol_fee_bps as u128 / 10000) as u64;
+    (protocol_fee, total_fee - protocol_fee)
+}
+
+/// Evaluate a job's trigger against the cluster clock, advancing its schedule
+/// as execution would. Returns whether the job may run and how many scheduled
+/// runs it missed, if the trigger is scheduled.
+pub(crate) fn evaluate_trigger<'info>(
+    job: &mut AutomationJob,
+    clock: &Clock,
+    trigger_account: Option<&UncheckedAccount<'info>>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<(bool, Option<u64>)> {
+    let outcome = match &job.trigger_type {
+        TriggerType::TimeBased => {
+            // Runs are anchored to created_at + k * interval; overdue runs are
+            // handled by the job's catch-up policy
+            let missed = job.advance_schedule(clock.unix_timestamp);
+            (missed.is_some(), missed)
+        },
+        TriggerType::Conditional => {
+            // For now, we'll implement basic conditional logic
+            // In a full implementation, this would evaluate custom conditions
+            (evaluate_conditional_trigger(job, clock)?, None)
+        },
+        TriggerType::LogTrigger => {
+            // Log-based triggers would require additional event monitoring
+            // For now, we'll allow execution if enough time has passed
+            (clock.unix_timestamp - job.last_execution >= 60, None)
+        },
+        TriggerType::Hybrid => {
+            // Hybrid triggers combine multiple conditions
+            (evaluate_hybrid_trigger(job, clock)?, None)
+        },
+        TriggerType::SlotInterval => {
+            // Slots cannot be skewed by validator clocks like unix_timestamp
+            let missed = job.advance_slot_schedule(clock.slot);
+            (missed.is_some(), missed)
+        },
+        TriggerType::EpochStart => {
+            let first_slot = EpochSchedule::get()?.get_first_slot_in_epoch(clock.epoch);
+            let missed = job.advance_epoch_schedule(
+                clock.epoch,
+                clock.slot.saturating_sub(first_slot),
+            );
+            (missed.is_some(), missed)
+        },
+        TriggerType::AccountChange => {
+            let watched_account = trigger_account
+                .filter(|account| Some(account.key()) == job.watched_account())
+                .ok_or(SolCronError::InvalidTriggerAccount)?;
+            let data_hash = anchor_lang::solana_program::hash::hash(&watched_account.try_borrow_data()?);
+            (job.record_data_hash(data_hash.to_bytes()), None)
+        },
+        TriggerType::PriceThreshold => {
+            let params = job.price_threshold()
+                .ok_or(SolCronError::InvalidParameters)?;
+            // The price account is passed with the target's accounts, which
+            // liquidation and limit-order instructions read anyway
+            let price_account = remaining_accounts
+                .iter()
+                .find(|account| account.key() == params.price_account)
+                .ok_or(SolCronError::InvalidTriggerAccount)?;
+            let price = parse_pyth_price(&price_account.try_borrow_data()?)
+                .ok_or(SolCronError::InvalidPriceAccount)?;
+            require!(
+                price.is_trading() && price.is_fresh(clock.unix_timestamp, params.max_staleness),
+                SolCronError::StalePrice
+            );
+            (params.comparison.is_met(price.price, params.threshold), None)
+        }
+    };
+
+    Ok(outcome)
+}
+
+fn emit_missed_executions(job: &AutomationJob, missed: Option<u64>) {
+    if let Some(missed) = missed.filter(|missed| *missed > 0) {
+        emit!(ExecutionsMissed {
+            job_id: job.job_id,
+            missed,
+            total_missed: job.missed_executions,
+        });
+    }
 }
 
 fn evaluate_conditional_trigger(_job: &AutomationJob, _clock: &Clock) -> Result<bool> {
@@ -202,51 +512,194 @@ fn evaluate_hybrid_trigger(_job: &AutomationJob, clock: &Clock) -> Result<bool>
     Ok(clock.unix_timestamp % 60 == 0) // Simple example
 }
 
-fn execute_target_instruction(
-    job: &AutomationJob,
-    target_program: &AccountInfo,
-    remaining_accounts: &[AccountInfo],
+fn execute_target_instruction<'info>(
+    job: &Account<'info, AutomationJob>,
+    target_program: &AccountInfo<'info>,
+    execution_program: &AccountInfo<'info>,
+    execution_authority: &AccountInfo<'info>,
+    authority_bump: u8,
+    remaining_accounts: &[AccountInfo<'info>],
+    perform_data: &[u8],
 ) -> Result<()> {
-    // This is a simplified version - in a full implementation, this would:
-    // 1. Parse the target instruction name and parameters
-    // 2. Build the appropriate instruction data
-    // 3. Perform CPI call to the target program
-    // 4. Handle the response and any errors
-    
     msg!("Executing instruction: {} on program: {}", 
          job.target_instruction, 
          target_program.key());
     
-    // For now, we'll simulate a successful execution
-    // In reality, this would involve complex CPI logic
     if remaining_accounts.is_empty() {
         return Err(SolCronError::InvalidParameters.into());
     }
-    
+
+    // Jobs with stored accounts pin the exact accounts the keeper must pass
+    if !job.target_accounts.is_empty() {
+        require!(
+            remaining_accounts.len() == job.target_accounts.len()
+                && remaining_accounts
+                    .iter()
+                    .zip(job.target_accounts.iter())
+                    .all(|(account, meta)| account.key() == meta.pubkey),
+            SolCronError::InvalidParameters
+        );
+    }
+
+    // The execution engine only accepts CPIs signed by the registry's
+    // execution authority and tied to a live job, so all target calls
+    // are routed through it rather than invoked directly.
+    let args = ExecuteCpiCallArgs {
+        program_id: job.target_program,
+        data: [&instruction_sighash(&job.target_instruction)[..], perform_data].concat(),
+        accounts: if job.target_accounts.is_empty() {
+            remaining_accounts
+                .iter()
+                .map(|account| CpiAccountMeta {
+                    pubkey: account.key(),
+                    is_signer: account.is_signer,
+                    is_writable: account.is_writable,
+                })
+                .collect()
+        } else {
+            job.target_accounts
+                .iter()
+                .map(|meta| CpiAccountMeta {
+                    pubkey: meta.pubkey,
+                    is_signer: meta.is_signer,
+                    is_writable: meta.is_writable,
+                })
+                .collect()
+        },
+    };
+
+    let mut data = instruction_sighash("execute_cpi_call").to_vec();
+    args.serialize(&mut data)?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(execution_authority.key(), true),
+        AccountMeta::new_readonly(job.key(), false),
+        AccountMeta::new_readonly(target_program.key(), false),
+    ];
+    accounts.extend(remaining_accounts.iter().map(|account| {
+        if account.is_writable {
+            AccountMeta::new(account.key(), account.is_signer)
+        } else {
+            AccountMeta::new_readonly(account.key(), account.is_signer)
+        }
+    }));
+
+    let instruction = Instruction {
+        program_id: crate::EXECUTION_PROGRAM_ID,
+        accounts,
+        data,
+    };
+
+    let mut account_infos = vec![
+        execution_authority.clone(),
+        job.to_account_info(),
+        target_program.clone(),
+    ];
+    account_infos.extend_from_slice(remaining_accounts);
+    account_infos.push(execution_program.clone());
+
+    invoke_signed(
+        &instruction,
+        &account_infos,
+        &[&[b"execution_authority", &[authority_bump]]],
+    )?;
+
     Ok(())
 }
 
-fn distribute_execution_fees(
-    registry_state: &RegistryState,
-    keeper: &mut Keeper,
+/// Return data set by the target during the CPI, truncated to
+/// `ExecutionRecord::MAX_RETURN_DATA`
+fn target_return_data(target_program: &Pubkey) -> Vec<u8> {
+    match get_return_data() {
+        Some((program_id, mut data)) if program_id == *target_program => {
+            data.truncate(ExecutionRecord::MAX_RETURN_DATA);
+            data
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// Anchor instruction discriminator for a global instruction name
+fn instruction_sighash(name: &str) -> [u8; 8] {
+    let preimage = format!("global:{}", name);
+    let hash = anchor_lang::solana_program::hash::hash(preimage.as_bytes());
+    let mut sighash = [0u8; 8];
+    sighash.copy_from_slice(&hash.to_bytes()[..8]);
+    sighash
+}
+
+/// Arguments of `solcron_execution::execute_cpi_call`
+#[derive(AnchorSerialize)]
+struct ExecuteCpiCallArgs {
+    program_id: Pubkey,
+    data: Vec<u8>,
+    accounts: Vec<CpiAccountMeta>,
+}
+
+/// Borsh mirror of `solcron_execution::AccountMeta`
+#[derive(AnchorSerialize)]
+struct CpiAccountMeta {
+    pubkey: Pubkey,
+    is_signer: bool,
+    is_writable: bool,
+}
+
+fn distribute_execution_fees<'info>(
+    registry_state: &mut Account<'info, RegistryState>,
+    job: &Account<'info, AutomationJob>,
+    fee_payer: &AccountInfo<'info>,
+    keeper: &mut Account<'info, Keeper>,
+    insurance_pool: &mut Account<'info, InsurancePool>,
     total_fee: u64,
-    _system_program: &Program<System>,
 ) -> Result<()> {
     // Calculate fee distribution
-    let protocol_fee = (total_fee as u128 * registry_state.protocol_fee_bps as u128 / 10000) as u64;
-    let keeper_fee = total_fee - protocol_fee;
+    let (protocol_fee, keeper_fee) = split_execution_fee(registry_state, job, total_fee);
+    keeper.settle_commission(Clock::get()?.unix_timestamp);
+    let (keeper_reward, delegator_reward) = keeper.split_rewards(keeper_fee);
 
-    // Add keeper fee to pending rewards
+    // The keeper's share moves into its account now, so claims never draw on
+    // the stake and delegated funds held there
+    **fee_payer.try_borrow_mut_lamports()? -= keeper_reward;
+    **keeper.to_account_info().try_borrow_mut_lamports()? += keeper_reward;
     keeper.pending_rewards = keeper.pending_rewards
-        .checked_add(keeper_fee)
+        .checked_add(keeper_reward)
         .ok_or(SolCronError::MathOverflow)?;
     
     keeper.total_earnings = keeper.total_earnings
-        .checked_add(keeper_fee)
+        .checked_add(keeper_reward)
         .ok_or(SolCronError::MathOverflow)?;
 
-    // Protocol fee would be transferred to treasury in a full implementation
-    msg!("Fees distributed - Keeper: {}, Protocol: {}", keeper_fee, protocol_fee);
+    // Delegator rewards move from the paying account into the delegation pool,
+    // raising the value of every outstanding share
+    if delegator_reward > 0 {
+        **fee_payer.try_borrow_mut_lamports()? -= delegator_reward;
+        **keeper.to_account_info().try_borrow_mut_lamports()? += delegator_reward;
+        keeper.delegated_stake = keeper.delegated_stake
+            .checked_add(delegator_reward)
+            .ok_or(SolCronError::MathOverflow)?;
+    }
+
+    // The insurance share of the protocol fee goes to the insurance pool and the
+    // rest is held in the registry account until the fee manager sweeps it to the
+    // treasury, so nothing is left behind in the paying account
+    let insurance_fee = RegistryState::insurance_share(protocol_fee, registry_state.insurance_fee_share_bps);
+    deposit_to_insurance(
+        registry_state,
+        insurance_pool,
+        fee_payer,
+        insurance_fee,
+        InsuranceSource::ProtocolFee,
+    )?;
+
+    let protocol_revenue = protocol_fee - insurance_fee;
+    **fee_payer.try_borrow_mut_lamports()? -= protocol_revenue;
+    **registry_state.to_account_info().try_borrow_mut_lamports()? += protocol_revenue;
+    registry_state.protocol_revenue = registry_state.protocol_revenue
+        .checked_add(protocol_revenue)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    msg!("Fees distributed - Keeper: {}, Delegators: {}, Protocol: {}, Insurance: {}",
+         keeper_reward, delegator_reward, protocol_revenue, insurance_fee);
 
     Ok(())
 }
@@ -258,7 +711,17 @@ pub struct JobExecuted {
     pub keeper: Pubkey,
     pub success: bool,
     pub fee_paid: u64,
+    pub reimbursed: u64,
     pub gas_used: u64,
+    pub error_code: Option<u32>,
+    pub return_data: Vec<u8>,
+}
+
+#[event]
+pub struct ExecutionsMissed {
+    pub job_id: u64,
+    pub missed: u64,
+    pub total_missed: u64,
 }
 
 #[event]
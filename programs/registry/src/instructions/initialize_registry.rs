@@ -48,6 +48,25 @@ pub fn initialize_registry(
     registry_state.next_job_id = 1;
     registry_state.is_paused = false;
     registry_state.bump = ctx.bumps.registry_state;
+    registry_state.version = RegistryState::VERSION;
+    registry_state.max_executions_per_window = RegistryState::DEFAULT_MAX_EXECUTIONS_PER_WINDOW;
+    registry_state.rate_limit_window_slots = RegistryState::DEFAULT_RATE_LIMIT_WINDOW_SLOTS;
+    registry_state.unregister_cooldown = RegistryState::DEFAULT_UNREGISTER_COOLDOWN;
+    registry_state.fee_manager = admin;
+    registry_state.slasher = admin;
+    registry_state.pauser = admin;
+    registry_state.upgrader = admin;
+    registry_state.params_timelock = RegistryState::DEFAULT_PARAMS_TIMELOCK;
+    registry_state.slash_dispute_window = RegistryState::DEFAULT_SLASH_DISPUTE_WINDOW;
+    registry_state.slash_count = 0;
+    registry_state.insurance_slash_share_bps = RegistryState::DEFAULT_INSURANCE_SLASH_SHARE_BPS;
+    registry_state.insurance_fee_share_bps = RegistryState::DEFAULT_INSURANCE_FEE_SHARE_BPS;
+    registry_state.insurance_deposits = 0;
+    registry_state.insurance_payouts = 0;
+    registry_state.delegation_unbonding_period = RegistryState::DEFAULT_DELEGATION_UNBONDING_PERIOD;
+    registry_state.keeper_tiers = Vec::new();
+    registry_state.job_reap_delay = RegistryState::DEFAULT_JOB_REAP_DELAY;
+    registry_state.reap_bounty_bps = RegistryState::DEFAULT_REAP_BOUNTY_BPS;
 
     msg!("SolCron Registry initialized with admin: {}", admin);
     msg!("Base fee: {} lamports, Min stake: {} lamports", base_fee, min_stake);
@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+// Initialize Insurance Pool
+#[derive(Accounts)]
+pub struct InitializeInsurancePool<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.admin == admin.key() @ SolCronError::Unauthorized
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = InsurancePool::MAX_SIZE,
+        seeds = [b"insurance_pool"],
+        bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn initialize_insurance_pool(ctx: Context<InitializeInsurancePool>) -> Result<()> {
+    let insurance_pool = &mut ctx.accounts.insurance_pool;
+    insurance_pool.balance = 0;
+    insurance_pool.bump = ctx.bumps.insurance_pool;
+
+    msg!("Insurance pool initialized at {}", insurance_pool.key());
+
+    Ok(())
+}
+
+// Approve Insurance Claim
+#[derive(Accounts)]
+#[instruction(slash_id: u64)]
+pub struct ApproveInsuranceClaim<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.admin == admin.key() @ SolCronError::Unauthorized,
+        constraint = registry_state.version == RegistryState::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance_pool"],
+        bump = insurance_pool.bump
+    )]
+    pub insurance_pool: Account<'info, InsurancePool>,
+
+    #[account(
+        seeds = [b"job", automation_job.job_id.to_le_bytes().as_ref()],
+        bump = automation_job.bump
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+
+    #[account(
+        constraint = execution_record.job_id == automation_job.job_id @ SolCronError::InvalidParameters
+    )]
+    pub execution_record: Account<'info, ExecutionRecord>,
+
+    /// Slash backing the payout, which must be of the executing keeper and
+    /// applied after the execution it compensates
+    #[account(
+        mut,
+        seeds = [b"slash_record", slash_id.to_le_bytes().as_ref()],
+        bump = slash_record.bump,
+        constraint = slash_record.keeper == execution_record.keeper @ SolCronError::InvalidKeeper,
+        constraint = execution_record.timestamp <= slash_record.slashed_at @ SolCronError::InvalidParameters,
+        constraint = !slash_record.claimed @ SolCronError::SlashAlreadyClaimed
+    )]
+    pub slash_record: Account<'info, SlashRecord>,
+
+    #[account(
+        init,
+        payer = admin,
+        space = InsuranceClaim::MAX_SIZE,
+        seeds = [b"insurance_claim", execution_record.key().as_ref()],
+        bump
+    )]
+    pub insurance_claim: Account<'info, InsuranceClaim>,
+
+    /// CHECK: Job owner receiving the payout
+    #[account(
+        mut,
+        address = automation_job.owner @ SolCronError::InvalidParameters
+    )]
+    pub owner: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub admin: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn approve_insurance_claim(
+    ctx: Context<ApproveInsuranceClaim>,
+    slash_id: u64,
+    amount: u64,
+) -> Result<()> {
+    require!(amount > 0, SolCronError::InvalidParameters);
+    // A payout never exceeds what the slash put into the pool
+    require!(amount <= ctx.accounts.slash_record.insurance_amount, SolCronError::ClaimExceedsSlash);
+
+    let registry_state = &mut ctx.accounts.registry_state;
+    let insurance_pool = &mut ctx.accounts.insurance_pool;
+    let clock = Clock::get()?;
+
+    require!(insurance_pool.balance >= amount, SolCronError::InsufficientBalance);
+
+    // Each applied slash backs a single payout
+    ctx.accounts.slash_record.claimed = true;
+
+    **insurance_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.try_borrow_mut_lamports()? += amount;
+
+    insurance_pool.balance -= amount;
+    registry_state.insurance_payouts = registry_state.insurance_payouts
+        .checked_add(amount)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    let execution_record = &ctx.accounts.execution_record;
+    let insurance_claim = &mut ctx.accounts.insurance_claim;
+    insurance_claim.execution_record = execution_record.key();
+    insurance_claim.job_id = execution_record.job_id;
+    insurance_claim.owner = ctx.accounts.owner.key();
+    insurance_claim.keeper = execution_record.keeper;
+    insurance_claim.slash_id = slash_id;
+    insurance_claim.amount = amount;
+    insurance_claim.approved_by = ctx.accounts.admin.key();
+    insurance_claim.approved_at = clock.unix_timestamp;
+    insurance_claim.bump = ctx.bumps.insurance_claim;
+
+    emit!(InsuranceClaimApproved {
+        job_id: insurance_claim.job_id,
+        owner: insurance_claim.owner,
+        execution_record: insurance_claim.execution_record,
+        keeper: insurance_claim.keeper,
+        slash_id,
+        amount,
+        pool_balance: insurance_pool.balance,
+    });
+
+    msg!("Insurance claim of {} lamports approved for job {} owner {}",
+         amount, insurance_claim.job_id, insurance_claim.owner);
+
+    Ok(())
+}
+
+/// Move lamports already held by a registry account into the insurance pool
+pub(crate) fn deposit_to_insurance<'info>(
+    registry_state: &mut RegistryState,
+    insurance_pool: &mut Account<'info, InsurancePool>,
+    from: &AccountInfo<'info>,
+    amount: u64,
+    source: InsuranceSource,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
+
+    **from.try_borrow_mut_lamports()? -= amount;
+    **insurance_pool.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    insurance_pool.balance = insurance_pool.balance
+        .checked_add(amount)
+        .ok_or(SolCronError::MathOverflow)?;
+    registry_state.insurance_deposits = registry_state.insurance_deposits
+        .checked_add(amount)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    emit!(InsuranceDeposited {
+        source,
+        amount,
+        pool_balance: insurance_pool.balance,
+    });
+
+    Ok(())
+}
+
+/// Flow that funded an insurance deposit
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum InsuranceSource {
+    Slash,
+    ProtocolFee,
+}
+
+// Events
+#[event]
+pub struct InsuranceDeposited {
+    pub source: InsuranceSource,
+    pub amount: u64,
+    pub pool_balance: u64,
+}
+
+#[event]
+pub struct InsuranceClaimApproved {
+    pub job_id: u64,
+    pub owner: Pubkey,
+    pub execution_record: Pubkey,
+    pub keeper: Pubkey,
+    pub slash_id: u64,
+    pub amount: u64,
+    pub pool_balance: u64,
+}
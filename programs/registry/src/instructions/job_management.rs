@@ -1,15 +1,16 @@
 use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Allocate, Assign, Transfer};
 use crate::state::*;
 use crate::errors::*;
 
 // Register Job
 #[derive(Accounts)]
-#[instruction(target_program: Pubkey, target_instruction: String)]
 pub struct RegisterJob<'info> {
     #[account(
         mut,
         seeds = [b"registry"],
-        bump = registry_state.bump
+        bump = registry_state.bump,
+        constraint = !registry_state.is_paused @ SolCronError::RegistryPaused
     )]
     pub registry_state: Account<'info, RegistryState>,
     
@@ -22,51 +23,39 @@ pub struct RegisterJob<'info> {
     )]
     pub automation_job: Account<'info, AutomationJob>,
     
+    /// CHECK: Created by `write_job_metadata` to fit the metadata; only
+    /// needed when registering with metadata
+    #[account(
+        mut,
+        seeds = [b"job_metadata", registry_state.next_job_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub job_metadata: Option<UncheckedAccount<'info>>,
+    
     #[account(mut)]
     pub owner: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
-pub fn register_job(
-    ctx: Context<RegisterJob>,
-    target_program: Pubkey,
-    target_instruction: String,
-    trigger_type: TriggerType,
-    trigger_params: Vec<u8>,
-    gas_limit: u64,
-    min_balance: u64,
-    initial_funding: u64,
-) -> Result<()> {
-    require!(target_instruction.len() <= 50, SolCronError::InvalidParameters);
-    require!(trigger_params.len() <= 256, SolCronError::InvalidParameters);
-    require!(gas_limit > 0 && gas_limit <= 1_400_000, SolCronError::InvalidParameters); // Max compute units
+pub fn register_job(ctx: Context<RegisterJob>, registration: JobRegistration) -> Result<()> {
+    let JobRegistration {
+        target_program,
+        target_instruction,
+        trigger_type,
+        trigger_params,
+        gas_limit,
+        min_balance,
+        initial_funding,
+        metadata,
+    } = registration;
+    validate_job_config(&target_instruction, &trigger_type, &trigger_params, gas_limit)?;
     require!(initial_funding >= min_balance, SolCronError::InsufficientBalance);
 
     let registry_state = &mut ctx.accounts.registry_state;
     let automation_job = &mut ctx.accounts.automation_job;
     let clock = Clock::get()?;
 
-    // Validate trigger type parameters
-    match &trigger_type {
-        TriggerType::TimeBased => {
-            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
-            // trigger_params should contain interval in bytes
-        },
-        TriggerType::Conditional => {
-            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
-            // trigger_params should contain condition logic
-        },
-        TriggerType::LogTrigger => {
-            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
-            // trigger_params should contain event signature
-        },
-        TriggerType::Hybrid => {
-            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
-            // trigger_params should contain hybrid configuration
-        }
-    }
-
     // Transfer initial funding from owner
     if initial_funding > 0 {
         let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
@@ -100,7 +89,42 @@ pub fn register_job(
     automation_job.last_execution = 0;
     automation_job.created_at = clock.unix_timestamp;
     automation_job.updated_at = clock.unix_timestamp;
+    automation_job.is_paused = false;
     automation_job.bump = ctx.bumps.automation_job;
+    automation_job.version = AutomationJob::VERSION;
+    automation_job.target_accounts = Vec::new();
+    automation_job.next_scheduled_time = clock.unix_timestamp;
+    automation_job.grace_period = 0;
+    automation_job.missed_executions = 0;
+    automation_job.catch_up_policy = CatchUpPolicy::RunOnce;
+    automation_job.next_scheduled_slot = clock.slot;
+    automation_job.next_epoch = clock.epoch + 1;
+    automation_job.last_data_hash = [0; 32];
+    automation_job.check_instruction = None;
+    automation_job.tip_lamports = 0;
+    automation_job.max_fee_per_execution = 0;
+    automation_job.funding_pool = None;
+    automation_job.pool_spend_cap = 0;
+    automation_job.pool_spent = 0;
+    automation_job.funding_shares = initial_funding;
+    automation_job.sponsor_shares = 0;
+    automation_job.min_keeper_tier = 0;
+    automation_job.max_fee_reimbursement = AutomationJob::DEFAULT_MAX_FEE_REIMBURSEMENT;
+    automation_job.end_at = 0;
+    automation_job.deactivated_at = 0;
+    automation_job.funding_round = 0;
+
+    if let Some(metadata) = metadata {
+        let job_metadata = ctx.accounts.job_metadata.as_ref().ok_or(SolCronError::InvalidMetadata)?;
+        write_job_metadata(
+            job_metadata,
+            &ctx.accounts.owner,
+            &ctx.accounts.system_program,
+            automation_job.job_id,
+            ctx.bumps.job_metadata.unwrap_or_default(),
+            metadata,
+        )?;
+    }
 
     // Update registry state
     registry_state.next_job_id += 1;
@@ -130,19 +154,36 @@ pub struct FundJob<'info> {
     )]
     pub automation_job: Account<'info, AutomationJob>,
     
+    /// Funder's sponsorship; the owner may omit it to fund as the owner
     #[account(
-        mut,
-        constraint = funder.key() == automation_job.owner @ SolCronError::Unauthorized
+        init_if_needed,
+        payer = funder,
+        space = Sponsorship::MAX_SIZE,
+        seeds = [b"sponsorship", automation_job.key().as_ref(), funder.key().as_ref()],
+        bump
     )]
+    pub sponsorship: Option<Account<'info, Sponsorship>>,
+    
+    #[account(mut)]
     pub funder: Signer<'info>,
     
     pub system_program: Program<'info, System>,
 }
 
+/// Fund a job from any wallet. Sponsors' shares of the job balance are
+/// tracked in their sponsorship so unspent funds can be refunded pro-rata.
 pub fn fund_job(ctx: Context<FundJob>, amount: u64) -> Result<()> {
     require!(amount > 0, SolCronError::InvalidParameters);
 
     let automation_job = &mut ctx.accounts.automation_job;
+    let clock = Clock::get()?;
+    require!(
+        ctx.accounts.sponsorship.is_some() || ctx.accounts.funder.key() == automation_job.owner,
+        SolCronError::Unauthorized
+    );
+
+    // Expired jobs only wait to be reaped, so new funds could only delay it
+    require!(!automation_job.is_expired(clock.unix_timestamp), SolCronError::JobExpired);
 
     // Transfer funds to job account
     let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
@@ -160,13 +201,31 @@ pub fn fund_job(ctx: Context<FundJob>, amount: u64) -> Result<()> {
         ],
     )?;
 
-    automation_job.balance = automation_job.balance
-        .checked_add(amount)
-        .ok_or(SolCronError::MathOverflow)?;
+    // Shares are priced at the current balance so earlier funders keep what
+    // executions have already spent from theirs
+    let shares = automation_job.add_funding(amount).ok_or(SolCronError::MathOverflow)?;
+    require!(shares > 0, SolCronError::InvalidParameters);
+
+    if let Some(sponsorship) = ctx.accounts.sponsorship.as_mut() {
+        if sponsorship.shares == 0 {
+            sponsorship.job_id = automation_job.job_id;
+            sponsorship.sponsor = ctx.accounts.funder.key();
+            sponsorship.sponsored_at = clock.unix_timestamp;
+            sponsorship.bump = ctx.bumps.sponsorship.unwrap_or_default();
+        }
+        // Shares from an earlier, drained funding round are worth nothing
+        sponsorship.shares = automation_job.sponsorship_shares(sponsorship);
+        sponsorship.funding_round = automation_job.funding_round;
+        sponsorship.shares += shares;
+        sponsorship.contributed = sponsorship.contributed.saturating_add(amount);
+        automation_job.sponsor_shares += shares;
+    }
 
     emit!(JobFunded {
         job_id: automation_job.job_id,
+        funder: ctx.accounts.funder.key(),
         amount,
+        shares,
         new_balance: automation_job.balance,
     });
 
@@ -175,6 +234,185 @@ pub fn fund_job(ctx: Context<FundJob>, amount: u64) -> Result<()> {
     Ok(())
 }
 
+// Create Funding Pool
+#[derive(Accounts)]
+#[instruction(pool_id: u64)]
+pub struct CreateFundingPool<'info> {
+    #[account(
+        init,
+        payer = owner,
+        space = FundingPool::MAX_SIZE,
+        seeds = [b"funding_pool", owner.key().as_ref(), pool_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub funding_pool: Account<'info, FundingPool>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn create_funding_pool(ctx: Context<CreateFundingPool>, pool_id: u64, min_balance: u64) -> Result<()> {
+    let funding_pool = &mut ctx.accounts.funding_pool;
+    let clock = Clock::get()?;
+
+    funding_pool.owner = ctx.accounts.owner.key();
+    funding_pool.pool_id = pool_id;
+    funding_pool.balance = 0;
+    funding_pool.min_balance = min_balance;
+    funding_pool.total_spent = 0;
+    funding_pool.created_at = clock.unix_timestamp;
+    funding_pool.bump = ctx.bumps.funding_pool;
+
+    emit!(FundingPoolCreated {
+        funding_pool: funding_pool.key(),
+        owner: funding_pool.owner,
+        pool_id,
+        min_balance,
+    });
+
+    msg!("Funding pool {} created by {}", pool_id, funding_pool.owner);
+
+    Ok(())
+}
+
+// Fund Pool
+#[derive(Accounts)]
+pub struct FundPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"funding_pool", funding_pool.owner.as_ref(), funding_pool.pool_id.to_le_bytes().as_ref()],
+        bump = funding_pool.bump
+    )]
+    pub funding_pool: Account<'info, FundingPool>,
+    
+    #[account(
+        mut,
+        constraint = funder.key() == funding_pool.owner @ SolCronError::Unauthorized
+    )]
+    pub funder: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+pub fn fund_pool(ctx: Context<FundPool>, amount: u64) -> Result<()> {
+    require!(amount > 0, SolCronError::InvalidParameters);
+
+    let funding_pool = &mut ctx.accounts.funding_pool;
+
+    // Transfer funds to pool account
+    let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
+        &ctx.accounts.funder.key(),
+        &funding_pool.key(),
+        amount,
+    );
+    
+    anchor_lang::solana_program::program::invoke(
+        &transfer_instruction,
+        &[
+            ctx.accounts.funder.to_account_info(),
+            funding_pool.to_account_info(),
+            ctx.accounts.system_program.to_account_info(),
+        ],
+    )?;
+
+    funding_pool.balance = funding_pool.balance
+        .checked_add(amount)
+        .ok_or(SolCronError::MathOverflow)?;
+
+    emit!(PoolFunded {
+        funding_pool: funding_pool.key(),
+        amount,
+        new_balance: funding_pool.balance,
+    });
+
+    msg!("Funding pool {} funded with {} lamports", funding_pool.pool_id, amount);
+
+    Ok(())
+}
+
+// Withdraw Pool
+#[derive(Accounts)]
+pub struct WithdrawPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"funding_pool", funding_pool.owner.as_ref(), funding_pool.pool_id.to_le_bytes().as_ref()],
+        bump = funding_pool.bump,
+        constraint = funding_pool.owner == owner.key() @ SolCronError::Unauthorized
+    )]
+    pub funding_pool: Account<'info, FundingPool>,
+    
+    #[account(mut)]
+    pub owner: Signer<'info>,
+}
+
+pub fn withdraw_pool(ctx: Context<WithdrawPool>, amount: u64) -> Result<()> {
+    require!(amount > 0, SolCronError::InvalidParameters);
+
+    let funding_pool = &mut ctx.accounts.funding_pool;
+    require!(funding_pool.balance >= amount, SolCronError::InsufficientBalance);
+
+    **funding_pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += amount;
+
+    funding_pool.balance -= amount;
+
+    emit!(PoolWithdrawn {
+        funding_pool: funding_pool.key(),
+        amount,
+        new_balance: funding_pool.balance,
+    });
+
+    msg!("{} lamports withdrawn from funding pool {}", amount, funding_pool.pool_id);
+
+    Ok(())
+}
+
+// Set Job Funding Pool
+#[derive(Accounts)]
+pub struct SetJobFundingPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"job", automation_job.job_id.to_le_bytes().as_ref()],
+        bump = automation_job.bump,
+        constraint = automation_job.is_active @ SolCronError::InvalidJob,
+        constraint = automation_job.owner == owner.key() @ SolCronError::Unauthorized
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+    
+    /// Pool to draw fees from; omit to pay from the job balance again
+    #[account(
+        constraint = funding_pool.owner == owner.key() @ SolCronError::Unauthorized
+    )]
+    pub funding_pool: Option<Account<'info, FundingPool>>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// Point a job at a funding pool with a spending cap, or back at its own balance.
+/// Setting the pool restarts the job's spend count.
+pub fn set_job_funding_pool(ctx: Context<SetJobFundingPool>, spend_cap: u64) -> Result<()> {
+    let automation_job = &mut ctx.accounts.automation_job;
+    let clock = Clock::get()?;
+
+    let funding_pool = ctx.accounts.funding_pool.as_ref().map(|pool| pool.key());
+    automation_job.funding_pool = funding_pool;
+    automation_job.pool_spend_cap = if funding_pool.is_some() { spend_cap } else { 0 };
+    automation_job.pool_spent = 0;
+    automation_job.updated_at = clock.unix_timestamp;
+
+    emit!(JobFundingPoolSet {
+        job_id: automation_job.job_id,
+        funding_pool,
+        spend_cap: automation_job.pool_spend_cap,
+    });
+
+    msg!("Job {} funding pool set to {:?}", automation_job.job_id, funding_pool);
+
+    Ok(())
+}
+
 // Cancel Job
 #[derive(Accounts)]
 pub struct CancelJob<'info> {
@@ -200,21 +438,26 @@ pub struct CancelJob<'info> {
     pub system_program: Program<'info, System>,
 }
 
-pub fn cancel_job(ctx: Context<CancelJob>) -> Result<()> {
+/// Cancel a job, refunding the owner's share of the balance. Sponsors are
+/// refunded as well when each sponsorship is passed followed by its sponsor's
+/// wallet as remaining accounts; any left out keep their share in the job and
+/// can withdraw it themselves.
+pub fn cancel_job<'info>(ctx: Context<'_, '_, '_, 'info, CancelJob<'info>>) -> Result<()> {
     let automation_job = &mut ctx.accounts.automation_job;
     let registry_state = &mut ctx.accounts.registry_state;
 
-    // Transfer remaining balance back to owner
-    if automation_job.balance > 0 {
-        **automation_job.to_account_info().try_borrow_mut_lamports()? -= automation_job.balance;
-        **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += automation_job.balance;
+    let refunded_amount = automation_job.funding_value(automation_job.owner_shares());
+    if refunded_amount > 0 {
+        **automation_job.to_account_info().try_borrow_mut_lamports()? -= refunded_amount;
+        **ctx.accounts.owner.to_account_info().try_borrow_mut_lamports()? += refunded_amount;
     }
-
-    let refunded_amount = automation_job.balance;
     
     // Mark job as inactive
-    automation_job.is_active = false;
-    automation_job.balance = 0;
+    automation_job.deactivate(Clock::get()?.unix_timestamp);
+    automation_job.balance -= refunded_amount;
+    automation_job.funding_shares = automation_job.sponsor_shares;
+
+    refund_sponsorships(automation_job, ctx.remaining_accounts)?;
     
     // Update registry stats
     registry_state.active_jobs -= 1;
@@ -223,6 +466,7 @@ pub fn cancel_job(ctx: Context<CancelJob>) -> Result<()> {
         job_id: automation_job.job_id,
         owner: automation_job.owner,
         refunded_amount,
+        sponsored_balance: automation_job.balance,
     });
 
     msg!("Job {} cancelled, {} lamports refunded", automation_job.job_id, refunded_amount);
@@ -230,6 +474,204 @@ pub fn cancel_job(ctx: Context<CancelJob>) -> Result<()> {
     Ok(())
 }
 
+/// Refund each sponsorship passed as a (sponsorship, sponsor wallet) pair of
+/// remaining accounts its share of the job balance, and close it
+fn refund_sponsorships<'info>(
+    automation_job: &mut Account<'info, AutomationJob>,
+    remaining_accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let job_info = automation_job.to_account_info();
+    for pair in remaining_accounts.chunks(2) {
+        let [sponsorship_info, sponsor] = pair else {
+            return err!(SolCronError::InvalidParameters);
+        };
+        require!(sponsorship_info.owner == &crate::ID, SolCronError::InvalidParameters);
+        let sponsorship = Sponsorship::try_deserialize(&mut &sponsorship_info.try_borrow_data()?[..])?;
+        require!(
+            sponsorship.job_id == automation_job.job_id && sponsorship.sponsor == sponsor.key(),
+            SolCronError::InvalidParameters
+        );
+
+        let shares = automation_job.sponsorship_shares(&sponsorship);
+        let amount = automation_job.funding_value(shares);
+        if amount > 0 {
+            **job_info.try_borrow_mut_lamports()? -= amount;
+            **sponsor.try_borrow_mut_lamports()? += amount;
+        }
+
+        automation_job.balance -= amount;
+        automation_job.funding_shares -= shares;
+        automation_job.sponsor_shares -= shares;
+
+        // Close the sponsorship, returning its rent to the sponsor
+        let sponsorship_rent = sponsorship_info.lamports();
+        **sponsorship_info.try_borrow_mut_lamports()? = 0;
+        **sponsor.try_borrow_mut_lamports()? += sponsorship_rent;
+        sponsorship_info.assign(&system_program::ID);
+        sponsorship_info.resize(0)?;
+
+        emit!(SponsorshipWithdrawn {
+            job_id: automation_job.job_id,
+            sponsor: sponsor.key(),
+            shares,
+            amount,
+        });
+    }
+
+    Ok(())
+}
+
+// Withdraw Sponsorship
+#[derive(Accounts)]
+pub struct WithdrawSponsorship<'info> {
+    #[account(
+        mut,
+        seeds = [b"job", automation_job.job_id.to_le_bytes().as_ref()],
+        bump = automation_job.bump
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+    
+    #[account(
+        mut,
+        seeds = [b"sponsorship", automation_job.key().as_ref(), sponsor.key().as_ref()],
+        bump = sponsorship.bump,
+        close = sponsor
+    )]
+    pub sponsorship: Account<'info, Sponsorship>,
+    
+    #[account(mut)]
+    pub sponsor: Signer<'info>,
+}
+
+/// Withdraw a sponsor's unspent share of the job balance, from an active or
+/// cancelled job, and close the sponsorship
+pub fn withdraw_sponsorship(ctx: Context<WithdrawSponsorship>) -> Result<()> {
+    let automation_job = &mut ctx.accounts.automation_job;
+    let shares = automation_job.sponsorship_shares(&ctx.accounts.sponsorship);
+
+    let amount = automation_job.funding_value(shares);
+    if amount > 0 {
+        **automation_job.to_account_info().try_borrow_mut_lamports()? -= amount;
+        **ctx.accounts.sponsor.to_account_info().try_borrow_mut_lamports()? += amount;
+    }
+
+    automation_job.balance -= amount;
+    automation_job.funding_shares -= shares;
+    automation_job.sponsor_shares -= shares;
+
+    emit!(SponsorshipWithdrawn {
+        job_id: automation_job.job_id,
+        sponsor: ctx.accounts.sponsor.key(),
+        shares,
+        amount,
+    });
+
+    msg!("{} withdrew {} lamports sponsoring job {}", ctx.accounts.sponsor.key(), amount, automation_job.job_id);
+
+    Ok(())
+}
+
+// Reap Job
+#[derive(Accounts)]
+pub struct ReapJob<'info> {
+    #[account(
+        mut,
+        seeds = [b"registry"],
+        bump = registry_state.bump
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+    
+    #[account(
+        mut,
+        seeds = [b"job", automation_job.job_id.to_le_bytes().as_ref()],
+        bump = automation_job.bump,
+        constraint = automation_job.version == AutomationJob::VERSION @ SolCronError::AccountNotMigrated,
+        close = owner
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+    
+    /// CHECK: Job's metadata account, closed with the job when it exists
+    #[account(
+        mut,
+        seeds = [b"job_metadata", automation_job.job_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub job_metadata: UncheckedAccount<'info>,
+    
+    /// CHECK: Job owner, receives the balance and the rent left after the bounty
+    #[account(
+        mut,
+        address = automation_job.owner @ SolCronError::Unauthorized
+    )]
+    pub owner: AccountInfo<'info>,
+    
+    #[account(mut)]
+    pub reaper: Signer<'info>,
+}
+
+/// Close an expired, or drained and long inactive, job. Anyone may call this;
+/// the caller earns a share of the job's rent and the owner gets the rest.
+/// Sponsors are refunded their share first: pass each sponsorship followed by
+/// its sponsor's wallet as remaining accounts.
+pub fn reap_job<'info>(ctx: Context<'_, '_, '_, 'info, ReapJob<'info>>) -> Result<()> {
+    let automation_job = &mut ctx.accounts.automation_job;
+    let registry_state = &mut ctx.accounts.registry_state;
+    let clock = Clock::get()?;
+
+    require!(
+        automation_job.is_reapable(clock.unix_timestamp, registry_state.job_reap_delay),
+        SolCronError::JobNotReapable
+    );
+
+    let job_info = automation_job.to_account_info();
+    refund_sponsorships(automation_job, ctx.remaining_accounts)?;
+
+    // Sponsors' funds would leave with the job account, but shares left over
+    // from a drained balance are worth nothing and do not hold up reaping
+    require!(
+        automation_job.funding_value(automation_job.sponsor_shares) == 0,
+        SolCronError::JobNotReapable
+    );
+
+    // The bounty comes out of the rent only, never the owner's balance
+    let rent = Rent::get()?
+        .minimum_balance(job_info.data_len())
+        .min(job_info.lamports().saturating_sub(automation_job.balance));
+    let bounty = (rent as u128 * registry_state.reap_bounty_bps as u128 / 10000) as u64;
+    if bounty > 0 {
+        **job_info.try_borrow_mut_lamports()? -= bounty;
+        **ctx.accounts.reaper.to_account_info().try_borrow_mut_lamports()? += bounty;
+    }
+
+    // Close the metadata account as well, returning its rent to the owner
+    if ctx.accounts.job_metadata.owner == &crate::ID {
+        let metadata_info = ctx.accounts.job_metadata.to_account_info();
+        let metadata_rent = metadata_info.lamports();
+        **metadata_info.try_borrow_mut_lamports()? = 0;
+        **ctx.accounts.owner.try_borrow_mut_lamports()? += metadata_rent;
+        metadata_info.assign(&system_program::ID);
+        metadata_info.resize(0)?;
+    }
+
+    if automation_job.is_active {
+        registry_state.active_jobs -= 1;
+    }
+
+    // The rest of the job's lamports go to the owner when the account closes
+    emit!(JobReaped {
+        job_id: automation_job.job_id,
+        owner: automation_job.owner,
+        reaper: ctx.accounts.reaper.key(),
+        bounty,
+        refunded_amount: job_info.lamports(),
+        expired: automation_job.is_expired(clock.unix_timestamp),
+    });
+
+    msg!("Job {} reaped by {} for a {} lamport bounty", automation_job.job_id, ctx.accounts.reaper.key(), bounty);
+
+    Ok(())
+}
+
 // Update Job
 #[derive(Accounts)]
 pub struct UpdateJob<'info> {
@@ -242,44 +684,493 @@ pub struct UpdateJob<'info> {
     )]
     pub automation_job: Account<'info, AutomationJob>,
     
+    /// CHECK: Created or resized by `write_job_metadata`; only needed when
+    /// updating metadata
+    #[account(
+        mut,
+        seeds = [b"job_metadata", automation_job.job_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub job_metadata: Option<UncheckedAccount<'info>>,
+    
+    #[account(mut)]
     pub owner: Signer<'info>,
+    
+    pub system_program: Program<'info, System>,
 }
 
-pub fn update_job(
-    ctx: Context<UpdateJob>,
-    gas_limit: Option<u64>,
-    min_balance: Option<u64>,
-    trigger_params: Option<Vec<u8>>,
-) -> Result<()> {
+pub fn update_job(ctx: Context<UpdateJob>, update: JobUpdate) -> Result<()> {
     let automation_job = &mut ctx.accounts.automation_job;
     let clock = Clock::get()?;
 
-    if let Some(gas_limit) = gas_limit {
-        require!(gas_limit > 0 && gas_limit <= 1_400_000, SolCronError::InvalidParameters);
-        automation_job.gas_limit = gas_limit;
+    // Validate the resulting configuration as a whole, so a new trigger type
+    // is checked against the params it will actually run with
+    validate_job_config(
+        update.target_instruction.as_deref().unwrap_or(&automation_job.target_instruction),
+        update.trigger_type.as_ref().unwrap_or(&automation_job.trigger_type),
+        update.trigger_params.as_deref().unwrap_or(&automation_job.trigger_params),
+        update.gas_limit.unwrap_or(automation_job.gas_limit),
+    )?;
+
+    if let Some(target_accounts) = &update.target_accounts {
+        require!(
+            target_accounts.len() <= AutomationJob::MAX_TARGET_ACCOUNTS,
+            SolCronError::InvalidParameters
+        );
+
+        // Target accounts are forwarded under the execution authority's
+        // signature, so they may not claim signer rights or name registry PDAs
+        let (registry_state, _) = Pubkey::find_program_address(&[b"registry"], ctx.program_id);
+        let (execution_authority, _) = Pubkey::find_program_address(&[b"execution_authority"], ctx.program_id);
+        let reserved = [registry_state, execution_authority, automation_job.key()];
+        require!(
+            target_accounts
+                .iter()
+                .all(|meta| !meta.is_signer && !reserved.contains(&meta.pubkey)),
+            SolCronError::InvalidTargetAccount
+        );
     }
 
-    if let Some(min_balance) = min_balance {
+    if let Some(min_balance) = update.min_balance {
         require!(automation_job.balance >= min_balance, SolCronError::InsufficientBalance);
-        automation_job.min_balance = min_balance;
     }
 
-    if let Some(trigger_params) = trigger_params {
-        require!(trigger_params.len() <= 256, SolCronError::InvalidParameters);
-        automation_job.trigger_params = trigger_params;
+    if let Some(grace_period) = update.grace_period {
+        require!(grace_period >= 0, SolCronError::InvalidParameters);
+    }
+
+    if let Some(check_instruction) = &update.check_instruction {
+        require!(check_instruction.len() <= 50, SolCronError::InvalidParameters);
+    }
+
+    if let Some(end_at) = update.end_at {
+        require!(end_at >= 0, SolCronError::InvalidParameters);
+    }
+
+    if let Some(min_keeper_tier) = update.min_keeper_tier {
+        require!(
+            min_keeper_tier as usize <= RegistryState::MAX_KEEPER_TIERS,
+            SolCronError::InvalidParameters
+        );
+    }
+
+    let mut changes = Vec::new();
+
+    if let Some(target_program) = update.target_program {
+        if target_program != automation_job.target_program {
+            changes.push(JobFieldChange::TargetProgram {
+                old: automation_job.target_program,
+                new: target_program,
+            });
+            automation_job.target_program = target_program;
+        }
+    }
+
+    if let Some(target_instruction) = update.target_instruction {
+        if target_instruction != automation_job.target_instruction {
+            changes.push(JobFieldChange::TargetInstruction {
+                old: std::mem::replace(&mut automation_job.target_instruction, target_instruction.clone()),
+                new: target_instruction,
+            });
+        }
+    }
+
+    if let Some(trigger_type) = update.trigger_type {
+        if trigger_type != automation_job.trigger_type {
+            changes.push(JobFieldChange::TriggerType {
+                old: std::mem::replace(&mut automation_job.trigger_type, trigger_type.clone()),
+                new: trigger_type,
+            });
+        }
+    }
+
+    if let Some(trigger_params) = update.trigger_params {
+        if trigger_params != automation_job.trigger_params {
+            changes.push(JobFieldChange::TriggerParams {
+                old: std::mem::replace(&mut automation_job.trigger_params, trigger_params.clone()),
+                new: trigger_params,
+            });
+        }
+    }
+
+    // A new trigger re-anchors the schedule at the next run from now
+    let trigger_changed = changes.iter().any(|change| matches!(
+        change,
+        JobFieldChange::TriggerType { .. } | JobFieldChange::TriggerParams { .. }
+    ));
+    if trigger_changed {
+        automation_job.next_scheduled_time = automation_job.first_run_at_or_after(clock.unix_timestamp);
+        automation_job.next_scheduled_slot = clock.slot;
+        automation_job.next_epoch = clock.epoch + 1;
+        automation_job.last_data_hash = [0; 32];
+    }
+
+    if let Some(target_accounts) = update.target_accounts {
+        if target_accounts != automation_job.target_accounts {
+            changes.push(JobFieldChange::TargetAccounts {
+                old: std::mem::replace(&mut automation_job.target_accounts, target_accounts.clone()),
+                new: target_accounts,
+            });
+        }
+    }
+
+    if let Some(gas_limit) = update.gas_limit {
+        if gas_limit != automation_job.gas_limit {
+            changes.push(JobFieldChange::GasLimit {
+                old: automation_job.gas_limit,
+                new: gas_limit,
+            });
+            automation_job.gas_limit = gas_limit;
+        }
+    }
+
+    if let Some(min_balance) = update.min_balance {
+        if min_balance != automation_job.min_balance {
+            changes.push(JobFieldChange::MinBalance {
+                old: automation_job.min_balance,
+                new: min_balance,
+            });
+            automation_job.min_balance = min_balance;
+        }
+    }
+
+    if let Some(grace_period) = update.grace_period {
+        if grace_period != automation_job.grace_period {
+            changes.push(JobFieldChange::GracePeriod {
+                old: automation_job.grace_period,
+                new: grace_period,
+            });
+            automation_job.grace_period = grace_period;
+        }
+    }
+
+    if let Some(catch_up_policy) = update.catch_up_policy {
+        if catch_up_policy != automation_job.catch_up_policy {
+            changes.push(JobFieldChange::CatchUpPolicy {
+                old: automation_job.catch_up_policy,
+                new: catch_up_policy,
+            });
+            automation_job.catch_up_policy = catch_up_policy;
+        }
+    }
+
+    if let Some(check_instruction) = update.check_instruction {
+        let check_instruction = Some(check_instruction).filter(|name| !name.is_empty());
+        if check_instruction != automation_job.check_instruction {
+            changes.push(JobFieldChange::CheckInstruction {
+                old: std::mem::replace(&mut automation_job.check_instruction, check_instruction.clone()),
+                new: check_instruction,
+            });
+        }
+    }
+
+    if let Some(tip_lamports) = update.tip_lamports {
+        if tip_lamports != automation_job.tip_lamports {
+            changes.push(JobFieldChange::TipLamports {
+                old: automation_job.tip_lamports,
+                new: tip_lamports,
+            });
+            automation_job.tip_lamports = tip_lamports;
+        }
+    }
+
+    if let Some(max_fee_per_execution) = update.max_fee_per_execution {
+        if max_fee_per_execution != automation_job.max_fee_per_execution {
+            changes.push(JobFieldChange::MaxFeePerExecution {
+                old: automation_job.max_fee_per_execution,
+                new: max_fee_per_execution,
+            });
+            automation_job.max_fee_per_execution = max_fee_per_execution;
+        }
+    }
+
+    if let Some(min_keeper_tier) = update.min_keeper_tier {
+        if min_keeper_tier != automation_job.min_keeper_tier {
+            changes.push(JobFieldChange::MinKeeperTier {
+                old: automation_job.min_keeper_tier,
+                new: min_keeper_tier,
+            });
+            automation_job.min_keeper_tier = min_keeper_tier;
+        }
+    }
+
+    if let Some(max_fee_reimbursement) = update.max_fee_reimbursement {
+        if max_fee_reimbursement != automation_job.max_fee_reimbursement {
+            changes.push(JobFieldChange::MaxFeeReimbursement {
+                old: automation_job.max_fee_reimbursement,
+                new: max_fee_reimbursement,
+            });
+            automation_job.max_fee_reimbursement = max_fee_reimbursement;
+        }
+    }
+
+    if let Some(end_at) = update.end_at {
+        if end_at != automation_job.end_at {
+            changes.push(JobFieldChange::EndAt {
+                old: automation_job.end_at,
+                new: end_at,
+            });
+            automation_job.end_at = end_at;
+        }
+    }
+
+    if let Some(metadata) = update.metadata {
+        let job_metadata = ctx.accounts.job_metadata.as_ref().ok_or(SolCronError::InvalidMetadata)?;
+        write_job_metadata(
+            job_metadata,
+            &ctx.accounts.owner,
+            &ctx.accounts.system_program,
+            automation_job.job_id,
+            ctx.bumps.job_metadata.unwrap_or_default(),
+            metadata,
+        )?;
     }
 
     automation_job.updated_at = clock.unix_timestamp;
 
+    msg!("Job {} updated, {} field(s) changed", automation_job.job_id, changes.len());
+
     emit!(JobUpdated {
         job_id: automation_job.job_id,
+        changes,
     });
 
-    msg!("Job {} updated", automation_job.job_id);
+    Ok(())
+}
+
+// Pause Job
+#[derive(Accounts)]
+pub struct PauseJob<'info> {
+    #[account(
+        mut,
+        seeds = [b"job", automation_job.job_id.to_le_bytes().as_ref()],
+        bump = automation_job.bump,
+        constraint = automation_job.is_active @ SolCronError::InvalidJob,
+        constraint = automation_job.owner == owner.key() @ SolCronError::Unauthorized
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// Stop executions while keeping the job's balance and history
+pub fn pause_job(ctx: Context<PauseJob>) -> Result<()> {
+    let automation_job = &mut ctx.accounts.automation_job;
+    let clock = Clock::get()?;
+
+    require!(!automation_job.is_paused, SolCronError::JobPaused);
+
+    automation_job.is_paused = true;
+    automation_job.updated_at = clock.unix_timestamp;
+
+    emit!(JobPaused {
+        job_id: automation_job.job_id,
+        owner: automation_job.owner,
+    });
+
+    msg!("Job {} paused", automation_job.job_id);
 
     Ok(())
 }
 
+// Resume Job
+#[derive(Accounts)]
+pub struct ResumeJob<'info> {
+    #[account(
+        mut,
+        seeds = [b"job", automation_job.job_id.to_le_bytes().as_ref()],
+        bump = automation_job.bump,
+        constraint = automation_job.is_active @ SolCronError::InvalidJob,
+        constraint = automation_job.owner == owner.key() @ SolCronError::Unauthorized
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+    
+    pub owner: Signer<'info>,
+}
+
+/// Resume a job paused by its owner. With
+/// `reset_last_execution` the schedule restarts from now rather than firing
+/// the runs that came due while paused.
+pub fn resume_job(ctx: Context<ResumeJob>, reset_last_execution: bool) -> Result<()> {
+    let automation_job = &mut ctx.accounts.automation_job;
+    let clock = Clock::get()?;
+
+    require!(automation_job.is_paused, SolCronError::InvalidJob);
+
+    automation_job.is_paused = false;
+    automation_job.updated_at = clock.unix_timestamp;
+
+    if reset_last_execution {
+        automation_job.restart_schedule(clock.unix_timestamp, clock.slot, clock.epoch);
+    }
+
+    emit!(JobResumed {
+        job_id: automation_job.job_id,
+        owner: automation_job.owner,
+        reset_last_execution,
+    });
+
+    msg!("Job {} resumed", automation_job.job_id);
+
+    Ok(())
+}
+
+/// Create or resize a job's metadata account to fit `metadata` and write it.
+/// Rent is topped up from, or refunded to, the owner.
+fn write_job_metadata<'info>(
+    job_metadata: &AccountInfo<'info>,
+    owner: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    job_id: u64,
+    bump: u8,
+    metadata: JobMetadataParams,
+) -> Result<()> {
+    require!(metadata.is_valid(), SolCronError::InvalidMetadata);
+
+    let space = JobMetadata::space(&metadata);
+    let required_lamports = Rent::get()?.minimum_balance(space);
+    let current_lamports = job_metadata.lamports();
+
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                Transfer {
+                    from: owner.clone(),
+                    to: job_metadata.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+
+    if job_metadata.owner == &crate::ID {
+        // Shrinking returns the excess rent to the owner
+        if current_lamports > required_lamports {
+            **job_metadata.try_borrow_mut_lamports()? -= current_lamports - required_lamports;
+            **owner.try_borrow_mut_lamports()? += current_lamports - required_lamports;
+        }
+        job_metadata.resize(space)?;
+    } else {
+        let job_id_bytes = job_id.to_le_bytes();
+        let signer_seeds: &[&[u8]] = &[b"job_metadata", &job_id_bytes, &[bump]];
+        system_program::allocate(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Allocate { account_to_allocate: job_metadata.clone() },
+                &[signer_seeds],
+            ),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(
+                system_program.clone(),
+                Assign { account_to_assign: job_metadata.clone() },
+                &[signer_seeds],
+            ),
+            &crate::ID,
+        )?;
+    }
+
+    let account = JobMetadata {
+        job_id,
+        name: metadata.name,
+        tags: metadata.tags,
+        metadata_uri: metadata.metadata_uri,
+        updated_at: Clock::get()?.unix_timestamp,
+        bump,
+    };
+    account.try_serialize(&mut &mut job_metadata.try_borrow_mut_data()?[..])?;
+
+    emit!(JobMetadataSet {
+        job_id,
+        name: account.name,
+        tags: account.tags,
+        metadata_uri: account.metadata_uri,
+    });
+
+    Ok(())
+}
+
+/// Configuration checks shared by `register_job` and `update_job`
+fn validate_job_config(
+    target_instruction: &str,
+    trigger_type: &TriggerType,
+    trigger_params: &[u8],
+    gas_limit: u64,
+) -> Result<()> {
+    require!(target_instruction.len() <= 50, SolCronError::InvalidParameters);
+    require!(trigger_params.len() <= 256, SolCronError::InvalidParameters);
+    require!(gas_limit > 0 && gas_limit <= 1_400_000, SolCronError::InvalidParameters); // Max compute units
+
+    // Validate trigger type parameters
+    match trigger_type {
+        TriggerType::TimeBased => {
+            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
+            // trigger_params should contain interval in bytes
+        },
+        TriggerType::Conditional => {
+            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
+            // trigger_params should contain condition logic
+        },
+        TriggerType::LogTrigger => {
+            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
+            // trigger_params should contain event signature
+        },
+        TriggerType::Hybrid => {
+            require!(!trigger_params.is_empty(), SolCronError::InvalidParameters);
+            // trigger_params should contain hybrid configuration
+        },
+        TriggerType::SlotInterval => {
+            // trigger_params should contain a non-zero slot interval (u64)
+            require!(
+                trigger_params.len() >= 8 && trigger_params[0..8] != [0u8; 8],
+                SolCronError::InvalidParameters
+            );
+        },
+        TriggerType::EpochStart => {
+            // trigger_params may contain a window in slots after the boundary (u64)
+            require!(
+                trigger_params.is_empty() || trigger_params.len() >= 8,
+                SolCronError::InvalidParameters
+            );
+        },
+        TriggerType::AccountChange => {
+            // trigger_params should contain the watched account pubkey
+            require!(trigger_params.len() >= 32, SolCronError::InvalidParameters);
+        },
+        TriggerType::PriceThreshold => {
+            // trigger_params should contain the price account, comparison,
+            // threshold and a non-zero max staleness
+            let params = PriceThresholdParams::parse(trigger_params)
+                .ok_or(SolCronError::InvalidParameters)?;
+            require!(params.max_staleness > 0, SolCronError::InvalidParameters);
+        }
+    }
+
+    Ok(())
+}
+
+/// A single field changed by `update_job`, with its previous and new value
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub enum JobFieldChange {
+    TargetProgram { old: Pubkey, new: Pubkey },
+    TargetInstruction { old: String, new: String },
+    TriggerType { old: TriggerType, new: TriggerType },
+    TriggerParams { old: Vec<u8>, new: Vec<u8> },
+    TargetAccounts { old: Vec<JobAccountMeta>, new: Vec<JobAccountMeta> },
+    GasLimit { old: u64, new: u64 },
+    MinBalance { old: u64, new: u64 },
+    GracePeriod { old: i64, new: i64 },
+    CatchUpPolicy { old: CatchUpPolicy, new: CatchUpPolicy },
+    CheckInstruction { old: Option<String>, new: Option<String> },
+    TipLamports { old: u64, new: u64 },
+    MaxFeePerExecution { old: u64, new: u64 },
+    MinKeeperTier { old: u8, new: u8 },
+    MaxFeeReimbursement { old: u64, new: u64 },
+    EndAt { old: i64, new: i64 },
+}
+
 // Events
 #[event]
 pub struct JobRegistered {
@@ -292,18 +1183,90 @@ pub struct JobRegistered {
 #[event]
 pub struct JobFunded {
     pub job_id: u64,
+    pub funder: Pubkey,
     pub amount: u64,
+    pub shares: u64,
     pub new_balance: u64,
 }
 
+#[event]
+pub struct FundingPoolCreated {
+    pub funding_pool: Pubkey,
+    pub owner: Pubkey,
+    pub pool_id: u64,
+    pub min_balance: u64,
+}
+
+#[event]
+pub struct PoolFunded {
+    pub funding_pool: Pubkey,
+    pub amount: u64,
+    pub new_balance: u64,
+}
+
+#[event]
+pub struct PoolWithdrawn {
+    pub funding_pool: Pubkey,
+    pub amount: u64,
+    pub new_balance: u64,
+}
+
+#[event]
+pub struct JobFundingPoolSet {
+    pub job_id: u64,
+    pub funding_pool: Option<Pubkey>,
+    pub spend_cap: u64,
+}
+
 #[event]
 pub struct JobCancelled {
     pub job_id: u64,
     pub owner: Pubkey,
     pub refunded_amount: u64,
+    pub sponsored_balance: u64,
+}
+
+#[event]
+pub struct SponsorshipWithdrawn {
+    pub job_id: u64,
+    pub sponsor: Pubkey,
+    pub shares: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct JobReaped {
+    pub job_id: u64,
+    pub owner: Pubkey,
+    pub reaper: Pubkey,
+    pub bounty: u64,
+    pub refunded_amount: u64,
+    pub expired: bool,
 }
 
 #[event]
 pub struct JobUpdated {
     pub job_id: u64,
-}
\ No newline at end of file
+    pub changes: Vec<JobFieldChange>,
+}
+
+#[event]
+pub struct JobMetadataSet {
+    pub job_id: u64,
+    pub name: String,
+    pub tags: Vec<String>,
+    pub metadata_uri: String,
+}
+
+#[event]
+pub struct JobPaused {
+    pub job_id: u64,
+    pub owner: Pubkey,
+}
+
+#[event]
+pub struct JobResumed {
+    pub job_id: u64,
+    pub owner: Pubkey,
+    pub reset_last_execution: bool,
+}
@@ -8,7 +8,8 @@ pub struct RegisterKeeper<'info> {
     #[account(
         mut,
         seeds = [b"registry"],
-        bump = registry_state.bump
+        bump = registry_state.bump,
+        constraint = !registry_state.is_paused @ SolCronError::RegistryPaused
     )]
     pub registry_state: Account<'info, RegistryState>,
     
@@ -62,6 +63,14 @@ pub fn register_keeper(ctx: Context<RegisterKeeper>, stake_amount: u64) -> Resul
     keeper.last_execution_time = 0;
     keeper.registered_at = clock.unix_timestamp;
     keeper.bump = ctx.bumps.keeper;
+    keeper.version = Keeper::VERSION;
+    keeper.window_start_slot = 0;
+    keeper.window_executions = 0;
+    keeper.delegated_stake = 0;
+    keeper.delegator_shares = 0;
+    keeper.unbonding_shares = 0;
+    keeper.commission_bps = Keeper::DEFAULT_COMMISSION_BPS;
+    keeper.total_reimbursed = 0;
 
     // Update registry stats
     registry_state.total_keepers += 1;
@@ -107,9 +116,9 @@ pub fn unregister_keeper(ctx: Context<UnregisterKeeper>) -> Result<()> {
     let registry_state = &mut ctx.accounts.registry_state;
     let clock = Clock::get()?;
 
-    // Check cooldown period (24 hours)
+    // Check cooldown period since the keeper's last execution
     require!(
-        clock.unix_timestamp - keeper.last_execution_time >= 86400,
+        clock.unix_timestamp - keeper.last_execution_time >= registry_state.unregister_cooldown,
         SolCronError::CooldownPeriod
     );
 
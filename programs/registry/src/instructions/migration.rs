@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{self, Transfer};
+use crate::state::*;
+use crate::errors::*;
+
+// Migrate Account
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    /// CHECK: May hold a legacy layout; owner is checked here and the
+    /// discriminator when the account is deserialized for migration
+    #[account(
+        mut,
+        owner = crate::ID @ SolCronError::InvalidParameters
+    )]
+    pub account: UncheckedAccount<'info>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn migrate_job(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<AutomationJob>(ctx)
+}
+
+pub fn migrate_keeper(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<Keeper>(ctx)
+}
+
+pub fn migrate_registry(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<RegistryState>(ctx)
+}
+
+pub fn migrate_execution_record(ctx: Context<MigrateAccount>) -> Result<()> {
+    migrate_account::<ExecutionRecord>(ctx)
+}
+
+fn migrate_account<T>(ctx: Context<MigrateAccount>) -> Result<()>
+where
+    T: AccountSerialize + AccountDeserialize + Versioned,
+{
+    let account = ctx.accounts.account.to_account_info();
+
+    // Zero everything past the stored layout, including stale bytes left by
+    // shrunk fields, so appended fields read as zero before upgrade()
+    let buffer = T::stored_data(&account.try_borrow_data()?)?;
+    let mut migrated = T::try_deserialize(&mut buffer.as_slice())?;
+
+    let from_version = migrated.version();
+    require!(from_version < T::VERSION, SolCronError::AccountAlreadyMigrated);
+    migrated.upgrade();
+
+    // Grow the account and top up rent from the payer
+    let new_len = account.data_len().max(T::SPACE);
+    let required_lamports = Rent::get()?.minimum_balance(new_len);
+    let current_lamports = account.lamports();
+    if required_lamports > current_lamports {
+        system_program::transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: account.clone(),
+                },
+            ),
+            required_lamports - current_lamports,
+        )?;
+    }
+    account.resize(new_len)?;
+
+    let mut data = account.try_borrow_mut_data()?;
+    migrated.try_serialize(&mut &mut data[..])?;
+
+    emit!(AccountMigrated {
+        account: account.key(),
+        from_version,
+        to_version: T::VERSION,
+    });
+
+    msg!("Account {} migrated from version {} to {}", account.key(), from_version, T::VERSION);
+
+    Ok(())
+}
+
+// Events
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+}
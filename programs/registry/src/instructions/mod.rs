@@ -3,9 +3,17 @@ pub mod job_management;
 pub mod keeper_management;
 pub mod execution;
 pub mod admin;
+pub mod migration;
+pub mod views;
+pub mod insurance;
+pub mod delegation;
 
 pub use initialize_registry::*;
 pub use job_management::*;
 pub use keeper_management::*;
 pub use execution::*;
-pub use admin::*;
\ No newline at end of file
+pub use admin::*;
+pub use migration::*;
+pub use views::*;
+pub use insurance::*;
+pub use delegation::*;
\ No newline at end of file
@@ -0,0 +1,150 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::execution::{
+    calculate_execution_fee, check_execution_eligibility, check_job_funding, evaluate_trigger, max_reimbursement,
+    split_execution_fee,
+};
+
+// Job View
+#[derive(Accounts)]
+#[instruction(job_id: u64)]
+pub struct JobView<'info> {
+    #[account(
+        seeds = [b"registry"],
+        bump = registry_state.bump,
+        constraint = registry_state.version == RegistryState::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub registry_state: Account<'info, RegistryState>,
+
+    #[account(
+        seeds = [b"job", job_id.to_le_bytes().as_ref()],
+        bump = automation_job.bump,
+        constraint = automation_job.job_id == job_id @ SolCronError::InvalidJob,
+        constraint = automation_job.version == AutomationJob::VERSION @ SolCronError::AccountNotMigrated
+    )]
+    pub automation_job: Account<'info, AutomationJob>,
+
+    /// CHECK: Account read by the job's trigger; checked against the trigger params
+    pub trigger_account: Option<UncheckedAccount<'info>>,
+
+    pub funding_pool: Option<Account<'info, FundingPool>>,
+
+    /// Keeper asking whether it may run the job; without one only jobs open
+    /// to any keeper tier can be due
+    pub keeper: Option<Account<'info, Keeper>>,
+}
+
+/// Fee charged for a job's next execution and how it is split
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ExecutionQuote {
+    pub job_id: u64,
+    pub execution_fee: u64,
+    pub keeper_reward: u64,
+    pub protocol_fee: u64,
+    /// Most the keeper can be repaid for transaction fees and record rent
+    pub max_reimbursement: u64,
+    /// Whether the job, or its funding pool, can pay the fee and the largest
+    /// reimbursement and stay at its minimum balance
+    pub is_funded: bool,
+}
+
+/// Whether a job can run now and when it is next scheduled
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct JobStatus {
+    pub job_id: u64,
+    pub is_active: bool,
+    pub is_paused: bool,
+    /// Active, not paused, the trigger is satisfied, and `execute_job` would
+    /// accept it: not expired, within the keeper's tier and the fee cap, and
+    /// funded for the largest reimbursement
+    pub is_due: bool,
+    /// Scheduled run time for time-based jobs
+    pub next_due_at: Option<i64>,
+    /// Scheduled run slot for slot- and epoch-based jobs
+    pub next_due_slot: Option<u64>,
+    pub execution_fee: u64,
+    pub keeper_reward: u64,
+    /// Lowest keeper tier allowed to execute the job
+    pub min_keeper_tier: u8,
+}
+
+pub fn quote_execution(ctx: Context<JobView>, job_id: u64) -> Result<ExecutionQuote> {
+    let registry_state = &ctx.accounts.registry_state;
+    let automation_job = &ctx.accounts.automation_job;
+
+    let execution_fee = calculate_execution_fee(registry_state, automation_job)?;
+    let (protocol_fee, keeper_reward) = split_execution_fee(registry_state, automation_job, execution_fee);
+    let max_reimbursement = max_reimbursement(automation_job)?;
+    let is_funded = execution_fee
+        .checked_add(max_reimbursement)
+        .is_some_and(|cost| check_job_funding(automation_job, ctx.accounts.funding_pool.as_ref(), cost).is_ok());
+
+    Ok(ExecutionQuote {
+        job_id,
+        execution_fee,
+        keeper_reward,
+        protocol_fee,
+        max_reimbursement,
+        is_funded,
+    })
+}
+
+pub fn job_status<'info>(
+    ctx: Context<'_, '_, '_, 'info, JobView<'info>>,
+    job_id: u64,
+) -> Result<JobStatus> {
+    let registry_state = &ctx.accounts.registry_state;
+    let automation_job = &ctx.accounts.automation_job;
+    let clock = Clock::get()?;
+
+    // Run the execution logic on a copy, so the view cannot drift from it
+    let mut evaluated = (**automation_job).clone();
+    let (trigger_met, _) = evaluate_trigger(
+        &mut evaluated,
+        &clock,
+        ctx.accounts.trigger_account.as_ref(),
+        ctx.remaining_accounts,
+    )?;
+
+    // A due run keeps its scheduled time; otherwise report the schedule after
+    // any runs the catch-up policy would skip
+    let scheduled = if trigger_met { &**automation_job } else { &evaluated };
+    let (next_due_at, next_due_slot) = match automation_job.trigger_type {
+        TriggerType::TimeBased => (Some(scheduled.next_scheduled_time), None),
+        TriggerType::SlotInterval => (None, Some(scheduled.next_scheduled_slot)),
+        TriggerType::EpochStart => (
+            None,
+            Some(EpochSchedule::get()?.get_first_slot_in_epoch(scheduled.next_epoch)),
+        ),
+        _ => (None, None),
+    };
+
+    let keeper_tier = ctx.accounts.keeper
+        .as_ref()
+        .map_or(0, |keeper| registry_state.keeper_tier(keeper));
+    let is_eligible = check_execution_eligibility(
+        registry_state,
+        automation_job,
+        ctx.accounts.funding_pool.as_ref(),
+        keeper_tier,
+        max_reimbursement(automation_job)?,
+        clock.unix_timestamp,
+    )
+    .is_ok();
+
+    let execution_fee = calculate_execution_fee(registry_state, automation_job)?;
+    let (_, keeper_reward) = split_execution_fee(registry_state, automation_job, execution_fee);
+
+    Ok(JobStatus {
+        job_id,
+        is_active: automation_job.is_active,
+        is_paused: automation_job.is_paused,
+        is_due: automation_job.is_active && !automation_job.is_paused && trigger_met && is_eligible,
+        next_due_at,
+        next_due_slot,
+        execution_fee,
+        keeper_reward,
+        min_keeper_tier: automation_job.min_keeper_tier,
+    })
+}
@@ -2,9 +2,13 @@ use anchor_lang::prelude::*;
 
 declare_id!("Fg6PaFpoGXkYsidMpWTK6W2BeZ7FEfcYkg476zPFsLnS");
 
+/// SolCron execution engine that performs target CPIs on behalf of the registry
+pub const EXECUTION_PROGRAM_ID: Pubkey = pubkey!("ExecNqpXiPPjs7m5wbuTCxZE8PJzgdW2cWEw23kcKJKm");
+
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod oracle;
 
 use instructions::*;
 use state::*;
@@ -25,47 +29,64 @@ pub mod solcron_registry {
         instructions::initialize_registry(ctx, admin, base_fee, min_stake, protocol_fee_bps, treasury)
     }
 
-    /// Register a new automation job
-    pub fn register_job(
-        ctx: Context<RegisterJob>,
-        target_program: Pubkey,
-        target_instruction: String,
-        trigger_type: TriggerType,
-        trigger_params: Vec<u8>,
-        gas_limit: u64,
-        min_balance: u64,
-        initial_funding: u64,
-    ) -> Result<()> {
-        instructions::register_job(
-            ctx,
-            target_program,
-            target_instruction,
-            trigger_type,
-            trigger_params,
-            gas_limit,
-            min_balance,
-            initial_funding,
-        )
-    }
-
-    /// Fund an existing job
+    /// Register a new automation job, optionally with its name, tags and metadata URI
+    pub fn register_job(ctx: Context<RegisterJob>, registration: JobRegistration) -> Result<()> {
+        instructions::register_job(ctx, registration)
+    }
+
+    /// Fund an existing job from any wallet, as the owner or a sponsor
     pub fn fund_job(ctx: Context<FundJob>, amount: u64) -> Result<()> {
         instructions::fund_job(ctx, amount)
     }
 
-    /// Cancel a job and withdraw remaining funds
-    pub fn cancel_job(ctx: Context<CancelJob>) -> Result<()> {
+    /// Create a funding pool that many of the owner's jobs can draw fees from
+    pub fn create_funding_pool(ctx: Context<CreateFundingPool>, pool_id: u64, min_balance: u64) -> Result<()> {
+        instructions::create_funding_pool(ctx, pool_id, min_balance)
+    }
+
+    /// Fund an existing funding pool
+    pub fn fund_pool(ctx: Context<FundPool>, amount: u64) -> Result<()> {
+        instructions::fund_pool(ctx, amount)
+    }
+
+    /// Withdraw unspent funds from a funding pool
+    pub fn withdraw_pool(ctx: Context<WithdrawPool>, amount: u64) -> Result<()> {
+        instructions::withdraw_pool(ctx, amount)
+    }
+
+    /// Pay a job's fees from a funding pool up to a spending cap, or from its own balance
+    pub fn set_job_funding_pool(ctx: Context<SetJobFundingPool>, spend_cap: u64) -> Result<()> {
+        instructions::set_job_funding_pool(ctx, spend_cap)
+    }
+
+    /// Cancel a job, refunding the owner and any sponsors passed along
+    pub fn cancel_job<'info>(ctx: Context<'_, '_, '_, 'info, CancelJob<'info>>) -> Result<()> {
         instructions::cancel_job(ctx)
     }
 
-    /// Update job parameters
-    pub fn update_job(
-        ctx: Context<UpdateJob>,
-        gas_limit: Option<u64>,
-        min_balance: Option<u64>,
-        trigger_params: Option<Vec<u8>>,
-    ) -> Result<()> {
-        instructions::update_job(ctx, gas_limit, min_balance, trigger_params)
+    /// Withdraw a sponsor's unspent share of a job balance
+    pub fn withdraw_sponsorship(ctx: Context<WithdrawSponsorship>) -> Result<()> {
+        instructions::withdraw_sponsorship(ctx)
+    }
+
+    /// Close an expired or long-dead job for a share of its rent
+    pub fn reap_job<'info>(ctx: Context<'_, '_, '_, 'info, ReapJob<'info>>) -> Result<()> {
+        instructions::reap_job(ctx)
+    }
+
+    /// Update job configuration
+    pub fn update_job(ctx: Context<UpdateJob>, update: JobUpdate) -> Result<()> {
+        instructions::update_job(ctx, update)
+    }
+
+    /// Pause a job's executions without cancelling it
+    pub fn pause_job(ctx: Context<PauseJob>) -> Result<()> {
+        instructions::pause_job(ctx)
+    }
+
+    /// Resume a paused job, optionally restarting its schedule from now
+    pub fn resume_job(ctx: Context<ResumeJob>, reset_last_execution: bool) -> Result<()> {
+        instructions::resume_job(ctx, reset_last_execution)
     }
 
     /// Register as a keeper
@@ -78,9 +99,26 @@ pub mod solcron_registry {
         instructions::unregister_keeper(ctx)
     }
 
-    /// Execute an automation job
-    pub fn execute_job(ctx: Context<ExecuteJob>, job_id: u64) -> Result<()> {
-        instructions::execute_job(ctx, job_id)
+    /// Execute an automation job, forwarding the perform data returned by its check instruction
+    pub fn execute_job<'info>(
+        ctx: Context<'_, '_, '_, 'info, ExecuteJob<'info>>,
+        job_id: u64,
+        perform_data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::execute_job(ctx, job_id, perform_data)
+    }
+
+    /// Quote the fee for a job's next execution (read-only, returned as return data)
+    pub fn quote_execution(ctx: Context<JobView>, job_id: u64) -> Result<ExecutionQuote> {
+        instructions::quote_execution(ctx, job_id)
+    }
+
+    /// Report whether a job is due using the execution logic (read-only, returned as return data)
+    pub fn job_status<'info>(
+        ctx: Context<'_, '_, '_, 'info, JobView<'info>>,
+        job_id: u64,
+    ) -> Result<JobStatus> {
+        instructions::job_status(ctx, job_id)
     }
 
     /// Claim accumulated keeper rewards
@@ -88,7 +126,27 @@ pub mod solcron_registry {
         instructions::claim_rewards(ctx)
     }
 
-    /// Admin function to slash a malicious keeper
+    /// Delegate stake to a keeper in exchange for shares of its delegation pool
+    pub fn delegate_stake(ctx: Context<DelegateStake>, keeper: Pubkey, amount: u64) -> Result<()> {
+        instructions::delegate_stake(ctx, keeper, amount)
+    }
+
+    /// Start unbonding delegated shares
+    pub fn undelegate_stake(ctx: Context<UndelegateStake>, shares: u64) -> Result<()> {
+        instructions::undelegate_stake(ctx, shares)
+    }
+
+    /// Withdraw unbonded delegated stake once the unbonding period has passed
+    pub fn withdraw_delegation(ctx: Context<WithdrawDelegation>) -> Result<()> {
+        instructions::withdraw_delegation(ctx)
+    }
+
+    /// Set the keeper's commission on delegator rewards
+    pub fn set_commission(ctx: Context<SetCommission>, commission_bps: u16) -> Result<()> {
+        instructions::set_commission(ctx, commission_bps)
+    }
+
+    /// Slasher function to propose slashing a keeper; funds are escrowed for a dispute window
     pub fn slash_keeper(
         ctx: Context<SlashKeeper>,
         keeper: Pubkey,
@@ -98,13 +156,82 @@ pub mod solcron_registry {
         instructions::slash_keeper(ctx, keeper, slash_amount, reason)
     }
 
-    /// Admin function to update registry parameters
-    pub fn update_registry_params(
-        ctx: Context<UpdateRegistryParams>,
-        base_fee: Option<u64>,
-        min_stake: Option<u64>,
-        protocol_fee_bps: Option<u16>,
+    /// Keeper function to appeal a pending slash within its dispute window
+    pub fn appeal_slash(ctx: Context<AppealSlash>, appeal: String) -> Result<()> {
+        instructions::appeal_slash(ctx, appeal)
+    }
+
+    /// Admin function to uphold or reject a pending slash
+    pub fn resolve_slash(ctx: Context<ResolveSlash>, uphold: bool) -> Result<()> {
+        instructions::resolve_slash(ctx, uphold)
+    }
+
+    /// Apply an unappealed slash once its dispute window has closed
+    pub fn finalize_slash(ctx: Context<FinalizeSlash>) -> Result<()> {
+        instructions::finalize_slash(ctx)
+    }
+
+    /// Admin function to create the insurance pool funded by slashes and protocol fees
+    pub fn initialize_insurance_pool(ctx: Context<InitializeInsurancePool>) -> Result<()> {
+        instructions::initialize_insurance_pool(ctx)
+    }
+
+    /// Admin function to pay a job owner from the insurance pool for a harmful execution
+    pub fn approve_insurance_claim(
+        ctx: Context<ApproveInsuranceClaim>,
+        slash_id: u64,
+        amount: u64,
     ) -> Result<()> {
-        instructions::update_registry_params(ctx, base_fee, min_stake, protocol_fee_bps)
+        instructions::approve_insurance_claim(ctx, slash_id, amount)
+    }
+
+    /// Queue a registry parameter change behind the governance timelock
+    pub fn propose_params(ctx: Context<ProposeParams>, params: RegistryParams) -> Result<()> {
+        instructions::propose_params(ctx, params)
+    }
+
+    /// Apply queued registry parameters once the timelock has expired
+    pub fn execute_params(ctx: Context<ExecuteParams>) -> Result<()> {
+        instructions::execute_params(ctx)
+    }
+
+    /// Cancel queued registry parameters
+    pub fn cancel_params(ctx: Context<CancelParams>) -> Result<()> {
+        instructions::cancel_params(ctx)
+    }
+
+    /// Admin function to assign a governance role
+    pub fn set_role(ctx: Context<SetRole>, role: RegistryRole, new_key: Pubkey) -> Result<()> {
+        instructions::set_role(ctx, role, new_key)
+    }
+
+    /// Pauser function to pause or unpause the registry
+    pub fn set_registry_paused(ctx: Context<SetRegistryPaused>, paused: bool) -> Result<()> {
+        instructions::set_registry_paused(ctx, paused)
+    }
+
+    /// Fee manager function to sweep accumulated protocol revenue to the treasury
+    pub fn withdraw_protocol_revenue(ctx: Context<WithdrawProtocolRevenue>) -> Result<()> {
+        instructions::withdraw_protocol_revenue(ctx)
+    }
+
+    /// Migrate an automation job account to the current layout
+    pub fn migrate_job(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_job(ctx)
+    }
+
+    /// Migrate a keeper account to the current layout
+    pub fn migrate_keeper(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_keeper(ctx)
+    }
+
+    /// Migrate the registry state account to the current layout
+    pub fn migrate_registry(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_registry(ctx)
+    }
+
+    /// Migrate an execution record account to the current layout
+    pub fn migrate_execution_record(ctx: Context<MigrateAccount>) -> Result<()> {
+        instructions::migrate_execution_record(ctx)
     }
 }
\ No newline at end of file
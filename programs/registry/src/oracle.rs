@@ -0,0 +1,107 @@
+//! Pyth price account parsing shared by the registry and the keeper node.
+//!
+//! Kept free of Anchor and Solana dependencies so the keeper can compile the
+//! same file against its own toolchain.
+
+/// Magic number at the start of every Pyth account
+pub const PYTH_MAGIC: u32 = 0xa1b2_c3d4;
+/// Supported Pyth account layout version
+pub const PYTH_VERSION: u32 = 2;
+/// Pyth account type tag for price accounts
+pub const PYTH_PRICE_ACCOUNT_TYPE: u32 = 3;
+/// Aggregate status for prices that are currently trading
+pub const PYTH_STATUS_TRADING: u32 = 1;
+
+// Byte offsets within the price account layout
+const MAGIC_OFFSET: usize = 0;
+const VERSION_OFFSET: usize = 4;
+const ACCOUNT_TYPE_OFFSET: usize = 8;
+const EXPO_OFFSET: usize = 20;
+const TIMESTAMP_OFFSET: usize = 96;
+const AGG_PRICE_OFFSET: usize = 208;
+const AGG_CONF_OFFSET: usize = 216;
+const AGG_STATUS_OFFSET: usize = 224;
+
+/// Minimum length covering the aggregate price
+pub const PYTH_PRICE_ACCOUNT_MIN_SIZE: usize = 240;
+
+/// Aggregate price read from a Pyth price account
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PythPrice {
+    /// Price in units of 10^expo
+    pub price: i64,
+    /// Confidence interval in units of 10^expo
+    pub conf: u64,
+    pub expo: i32,
+    /// Unix timestamp the aggregate was published at
+    pub publish_time: i64,
+    pub status: u32,
+}
+
+impl PythPrice {
+    pub fn is_trading(&self) -> bool {
+        self.status == PYTH_STATUS_TRADING
+    }
+
+    /// Whether the price was published within `max_staleness` seconds of `now`
+    pub fn is_fresh(&self, now: i64, max_staleness: u64) -> bool {
+        now.saturating_sub(self.publish_time) <= max_staleness.min(i64::MAX as u64) as i64
+    }
+}
+
+/// Direction a price must cross its threshold in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PriceComparison {
+    /// Price at or above the threshold
+    Above,
+    /// Price at or below the threshold
+    Below,
+}
+
+impl PriceComparison {
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::Above),
+            1 => Some(Self::Below),
+            _ => None,
+        }
+    }
+
+    pub fn is_met(&self, price: i64, threshold: i64) -> bool {
+        match self {
+            Self::Above => price >= threshold,
+            Self::Below => price <= threshold,
+        }
+    }
+}
+
+/// Parse the aggregate price from Pyth price account data
+pub fn parse_pyth_price(data: &[u8]) -> Option<PythPrice> {
+    if data.len() < PYTH_PRICE_ACCOUNT_MIN_SIZE
+        || read_u32(data, MAGIC_OFFSET)? != PYTH_MAGIC
+        || read_u32(data, VERSION_OFFSET)? != PYTH_VERSION
+        || read_u32(data, ACCOUNT_TYPE_OFFSET)? != PYTH_PRICE_ACCOUNT_TYPE
+    {
+        return None;
+    }
+
+    Some(PythPrice {
+        price: read_i64(data, AGG_PRICE_OFFSET)?,
+        conf: read_u64(data, AGG_CONF_OFFSET)?,
+        expo: read_u32(data, EXPO_OFFSET)? as i32,
+        publish_time: read_i64(data, TIMESTAMP_OFFSET)?,
+        status: read_u32(data, AGG_STATUS_OFFSET)?,
+    })
+}
+
+fn read_u32(data: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(data.get(offset..offset + 4)?.try_into().ok()?))
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
+
+fn read_i64(data: &[u8], offset: usize) -> Option<i64> {
+    Some(i64::from_le_bytes(data.get(offset..offset + 8)?.try_into().ok()?))
+}
@@ -1,4 +1,106 @@
 use anchor_lang::prelude::*;
+use crate::oracle::PriceComparison;
+
+/// Registry accounts with a versioned layout that can be migrated in place.
+/// Fields are only ever appended at the end. Variable-length fields may have
+/// shrunk since the account was allocated, so bytes past the stored layout
+/// can be stale; `stored_data` cuts them off and zero-extends to `SPACE`, so
+/// the appended fields read as zero, empty or the first enum variant. A
+/// legacy account written before `version` existed reads as version 0.
+pub trait Versioned {
+    /// Current layout version
+    const VERSION: u8;
+    /// Account size for the current layout
+    const SPACE: usize;
+    /// Account size before `version` was added; no larger account is legacy
+    const LEGACY_SPACE: usize;
+
+    fn version(&self) -> u8;
+
+    /// Fill defaults for fields appended after the stored version, whose zeroed
+    /// values are not meaningful, and stamp the current one
+    fn upgrade(&mut self);
+
+    /// Offset just past the fields a `version` account stores in `data`
+    fn layout_end(data: &[u8], version: u8) -> Result<usize>;
+
+    /// Layout version of the stored account data
+    fn stored_version(data: &[u8]) -> Result<u8> {
+        if data.len() <= Self::LEGACY_SPACE {
+            return Ok(0);
+        }
+        let offset = Self::layout_end(data, 0)?;
+        data.get(offset)
+            .copied()
+            .ok_or_else(|| ErrorCode::AccountDidNotDeserialize.into())
+    }
+
+    /// Stored account data cut off at the end of its own layout and
+    /// zero-extended to `SPACE`, ready to deserialize as the current layout
+    fn stored_data(data: &[u8]) -> Result<Vec<u8>> {
+        let end = Self::layout_end(data, Self::stored_version(data)?)?;
+        let mut buffer = data[..end].to_vec();
+        buffer.resize(end.max(Self::SPACE), 0);
+        Ok(buffer)
+    }
+}
+
+/// Cursor over the Borsh-encoded fields of stored account data
+pub struct StoredLayout<'a> {
+    data: &'a [u8],
+    offset: usize,
+}
+
+impl<'a> StoredLayout<'a> {
+    /// Start past the account discriminator
+    pub fn new(data: &'a [u8]) -> Self {
+        Self { data, offset: 8 }
+    }
+
+    /// Skip a fixed-size field
+    pub fn fixed(&mut self, size: usize) -> Result<&mut Self> {
+        let end = self.offset.checked_add(size)
+            .filter(|end| *end <= self.data.len())
+            .ok_or(ErrorCode::AccountDidNotDeserialize)?;
+        self.offset = end;
+        Ok(self)
+    }
+
+    /// Skip a `Vec` (or `String` with an item size of 1)
+    pub fn vec(&mut self, item_size: usize) -> Result<&mut Self> {
+        let len = self.prefix(4)?;
+        let len = u32::from_le_bytes(len.try_into().unwrap()) as usize;
+        self.fixed(len.saturating_mul(item_size))
+    }
+
+    /// Skip an `Option` of a fixed-size value
+    pub fn option(&mut self, size: usize) -> Result<&mut Self> {
+        if self.prefix(1)?[0] == 0 {
+            Ok(self)
+        } else {
+            self.fixed(size)
+        }
+    }
+
+    /// Skip an `Option` of a `Vec` or `String`
+    pub fn option_vec(&mut self, item_size: usize) -> Result<&mut Self> {
+        if self.prefix(1)?[0] == 0 {
+            Ok(self)
+        } else {
+            self.vec(item_size)
+        }
+    }
+
+    pub fn end(&self) -> usize {
+        self.offset
+    }
+
+    fn prefix(&mut self, size: usize) -> Result<&'a [u8]> {
+        let start = self.offset;
+        self.fixed(size)?;
+        Ok(&self.data[start..self.offset])
+    }
+}
 
 /// Job configuration and state
 #[account]
@@ -18,9 +120,52 @@ pub struct AutomationJob {
     pub created_at: i64,                // Creation timestamp
     pub updated_at: i64,                // Last update timestamp
     pub bump: u8,                       // PDA bump seed
+    pub version: u8,                    // Account layout version
+    pub target_accounts: Vec<JobAccountMeta>, // Stored target accounts (empty = keeper supplied)
+    pub next_scheduled_time: i64,       // Next anchored run (created_at + k * interval)
+    pub grace_period: i64,              // Seconds a run may lag its schedule (0 = full interval)
+    pub missed_executions: u64,         // Scheduled runs not executed within their grace window
+    pub catch_up_policy: CatchUpPolicy, // How overdue scheduled runs are handled
+    pub next_scheduled_slot: u64,       // Next SlotInterval run
+    pub next_epoch: u64,                // Next epoch an EpochStart job may run in
+    pub last_data_hash: [u8; 32],       // Watched account data hash at the last AccountChange run
+    pub check_instruction: Option<String>, // Target view instruction simulated before each run
+    pub tip_lamports: u64,              // Paid to the executing keeper on top of the base fee
+    pub max_fee_per_execution: u64,     // Execution fee cap (0 = no cap)
+    pub funding_pool: Option<Pubkey>,   // Pool paying fees instead of the job balance
+    pub pool_spend_cap: u64,            // Max lamports drawn from the pool (0 = no cap)
+    pub pool_spent: u64,                // Lamports drawn from the pool since it was set
+    pub funding_shares: u64,            // Shares of the balance held by the owner and sponsors
+    pub sponsor_shares: u64,            // Shares held by sponsorships (the rest are the owner's)
+    pub min_keeper_tier: u8,            // Lowest keeper tier allowed to execute (0 = any keeper)
+    pub max_fee_reimbursement: u64,     // Cap on the keeper's signature and priority fees repaid per run
+    pub end_at: i64,                    // Expiry after which anyone may reap the job (0 = never)
+    pub deactivated_at: i64,            // When the job was cancelled or deactivated (0 = active)
+    pub is_paused: bool,                // Paused by the owner
+    pub funding_round: u64,             // Bumped each time a drained balance resets the share supply
 }
 
 impl AutomationJob {
+    /// Current account layout version
+    pub const VERSION: u8 = 14;
+
+    /// Interval used when TimeBased trigger params carry none (1 minute)
+    pub const DEFAULT_TIME_INTERVAL: i64 = 60;
+
+    /// Maximum number of stored target accounts
+    pub const MAX_TARGET_ACCOUNTS: usize = 16;
+
+    /// Maximum perform data forwarded from a check instruction
+    pub const MAX_PERFORM_DATA_SIZE: usize = 512;
+
+    /// Default cap on transaction fees repaid to the keeper per run
+    pub const DEFAULT_MAX_FEE_REIMBURSEMENT: u64 = 50_000;
+
+    /// Shares per lamport of balance past which the balance counts as drained
+    /// and the next deposit starts a new funding round
+    pub const MAX_SHARES_PER_LAMPORT: u128 = 1_000;
+
+
     pub const MAX_SIZE: usize = 8 + // discriminator
         8 + // job_id
         32 + // owner
@@ -36,7 +181,331 @@ impl AutomationJob {
         8 + // last_execution
         8 + // created_at
         8 + // updated_at
-        1; // bump
+        1 + // bump
+        1 + // version
+        (4 + AutomationJob::MAX_TARGET_ACCOUNTS * JobAccountMeta::SIZE) + // target_accounts
+        8 + // next_scheduled_time
+        8 + // grace_period
+        8 + // missed_executions
+        1 + // catch_up_policy
+        8 + // next_scheduled_slot
+        8 + // next_epoch
+        32 + // last_data_hash
+        (1 + 4 + 50) + // check_instruction
+        8 + // tip_lamports
+        8 + // max_fee_per_execution
+        (1 + 32) + // funding_pool
+        8 + // pool_spend_cap
+        8 + // pool_spent
+        8 + // funding_shares
+        8 + // sponsor_shares
+        1 + // min_keeper_tier
+        8 + // max_fee_reimbursement
+        8 + // end_at
+        8 + // deactivated_at
+        1 + // is_paused
+        8; // funding_round
+
+    /// TimeBased interval in seconds, read from the first 8 bytes of trigger_params
+    pub fn time_interval(&self) -> i64 {
+        let interval = if self.trigger_params.len() >= 8 {
+            i64::from_le_bytes(self.trigger_params[0..8].try_into().unwrap_or([0; 8]))
+        } else {
+            AutomationJob::DEFAULT_TIME_INTERVAL
+        };
+        interval.max(1)
+    }
+
+    /// SlotInterval interval in slots, read from the first 8 bytes of trigger_params
+    pub fn slot_interval(&self) -> u64 {
+        read_u64_param(&self.trigger_params).unwrap_or(1).max(1)
+    }
+
+    /// EpochStart window in slots after the epoch boundary (0 = whole epoch)
+    pub fn epoch_start_window(&self) -> u64 {
+        read_u64_param(&self.trigger_params).unwrap_or(0)
+    }
+
+    /// Account watched by an AccountChange trigger, read from the first 32 bytes of trigger_params
+    pub fn watched_account(&self) -> Option<Pubkey> {
+        self.trigger_params
+            .get(0..32)
+            .and_then(|bytes| Pubkey::try_from(bytes).ok())
+    }
+
+    /// PriceThreshold configuration, if trigger_params hold a complete one
+    pub fn price_threshold(&self) -> Option<PriceThresholdParams> {
+        PriceThresholdParams::parse(&self.trigger_params)
+    }
+
+    /// Record the watched account's data hash, returning true if it changed
+    pub fn record_data_hash(&mut self, data_hash: [u8; 32]) -> bool {
+        if data_hash == self.last_data_hash {
+            return false;
+        }
+        self.last_data_hash = data_hash;
+        true
+    }
+
+    /// First anchored run `created_at + k * interval` at or after `time`
+    pub fn first_run_at_or_after(&self, time: i64) -> i64 {
+        if time <= self.created_at {
+            return self.created_at;
+        }
+        let interval = self.time_interval();
+        let elapsed = time - self.created_at;
+        let slots = elapsed / interval + i64::from(elapsed % interval != 0);
+        self.created_at.saturating_add(slots.saturating_mul(interval))
+    }
+
+    /// Advance the anchored schedule for a run at `now` according to the
+    /// catch-up policy. Returns the number of scheduled runs missed, or
+    /// `None` if the job may not run now.
+    pub fn advance_schedule(&mut self, now: i64) -> Option<u64> {
+        let due = self.next_scheduled_time;
+        if now < due {
+            return None;
+        }
+
+        let interval = self.time_interval();
+        let grace = if self.grace_period <= 0 || self.grace_period > interval {
+            interval
+        } else {
+            self.grace_period
+        };
+
+        // Runs due before the most recent one can no longer be on time
+        let overdue = (now - due) / interval;
+        let latest = due + overdue * interval;
+        let latest_on_time = now - latest <= grace;
+
+        let missed = match self.catch_up_policy {
+            CatchUpPolicy::RunOnce => {
+                self.next_scheduled_time = latest + interval;
+                overdue as u64 + u64::from(!latest_on_time)
+            },
+            CatchUpPolicy::SkipMissed => {
+                if !latest_on_time {
+                    return None;
+                }
+                self.next_scheduled_time = latest + interval;
+                overdue as u64
+            },
+            CatchUpPolicy::BackfillEach => {
+                self.next_scheduled_time = due + interval;
+                u64::from(now - due > grace)
+            },
+        };
+
+        self.missed_executions = self.missed_executions.saturating_add(missed);
+        Some(missed)
+    }
+
+    /// Restart the job's schedules as if it had just run, so a resumed job
+    /// waits a full interval instead of firing immediately
+    pub fn restart_schedule(&mut self, now: i64, slot: u64, epoch: u64) {
+        self.last_execution = now;
+        self.next_scheduled_time = self.first_run_at_or_after(now.saturating_add(self.time_interval()));
+        self.next_scheduled_slot = slot.saturating_add(self.slot_interval());
+        self.next_epoch = self.next_epoch.max(epoch + 1);
+    }
+
+    /// Advance the SlotInterval schedule for a run at `slot`. Overdue runs
+    /// are collapsed into this one and counted as missed.
+    pub fn advance_slot_schedule(&mut self, slot: u64) -> Option<u64> {
+        let due = self.next_scheduled_slot;
+        if slot < due {
+            return None;
+        }
+
+        let interval = self.slot_interval();
+        let overdue = (slot - due) / interval;
+        self.next_scheduled_slot = due.saturating_add((overdue + 1).saturating_mul(interval));
+
+        self.missed_executions = self.missed_executions.saturating_add(overdue);
+        Some(overdue)
+    }
+
+    /// Advance the EpochStart schedule for a run at `slot_index` within
+    /// `epoch`. Epochs whose window passed without a run count as missed.
+    pub fn advance_epoch_schedule(&mut self, epoch: u64, slot_index: u64) -> Option<u64> {
+        if epoch < self.next_epoch {
+            return None;
+        }
+
+        let window = self.epoch_start_window();
+        if window > 0 && slot_index > window {
+            return None;
+        }
+
+        let missed = epoch - self.next_epoch;
+        self.next_epoch = epoch + 1;
+
+        self.missed_executions = self.missed_executions.saturating_add(missed);
+        Some(missed)
+    }
+
+    /// Lamports of the balance currently backing `shares`
+    pub fn funding_value(&self, shares: u64) -> u64 {
+        if self.funding_shares == 0 {
+            return 0;
+        }
+        (shares as u128 * self.balance as u128 / self.funding_shares as u128) as u64
+    }
+
+    /// Shares minted for funding `amount` lamports, priced at the current balance
+    pub fn funding_shares_for(&self, amount: u64) -> Option<u64> {
+        if self.funding_shares == 0 {
+            return Some(amount);
+        }
+        let shares = (amount as u128 * self.funding_shares as u128).checked_div(self.balance as u128)?;
+        u64::try_from(shares).ok()
+    }
+
+    /// True once executions have spent the balance down to (almost) nothing, so
+    /// pricing new deposits against the outstanding shares would inflate them
+    pub fn is_drained(&self) -> bool {
+        self.funding_shares as u128 > self.balance as u128 * AutomationJob::MAX_SHARES_PER_LAMPORT
+    }
+
+    /// Add `amount` lamports to the balance and return the shares minted for
+    /// them. A drained balance starts a new funding round first: the leftover
+    /// shares, each worth under a thousandth of a lamport, are dropped along
+    /// with the old round's sponsorships, and the remaining dust carries over.
+    pub fn add_funding(&mut self, amount: u64) -> Option<u64> {
+        if self.is_drained() {
+            self.funding_shares = 0;
+            self.sponsor_shares = 0;
+            self.funding_round = self.funding_round.checked_add(1)?;
+        }
+
+        let shares = self.funding_shares_for(amount)?;
+        self.balance = self.balance.checked_add(amount)?;
+        self.funding_shares = self.funding_shares.checked_add(shares)?;
+        Some(shares)
+    }
+
+    /// Shares a sponsorship holds in the current funding round
+    pub fn sponsorship_shares(&self, sponsorship: &Sponsorship) -> u64 {
+        if sponsorship.funding_round == self.funding_round {
+            sponsorship.shares
+        } else {
+            0
+        }
+    }
+
+    /// Shares held by the owner rather than a sponsorship
+    pub fn owner_shares(&self) -> u64 {
+        self.funding_shares - self.sponsor_shares
+    }
+
+    /// True once the job is past its end time
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.end_at != 0 && now >= self.end_at
+    }
+
+    /// True if anyone may reap the job: it is past its end time, or it has
+    /// been inactive with no balance for at least `reap_delay` seconds
+    pub fn is_reapable(&self, now: i64, reap_delay: i64) -> bool {
+        self.is_expired(now)
+            || (!self.is_active
+                && self.balance == 0
+                && now >= self.deactivated_at.saturating_add(reap_delay))
+    }
+
+    /// Mark the job inactive from `now`
+    pub fn deactivate(&mut self, now: i64) {
+        self.is_active = false;
+        self.deactivated_at = now;
+    }
+}
+
+/// Account passed to the target instruction on every execution
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct JobAccountMeta {
+    pub pubkey: Pubkey,
+    pub is_signer: bool,
+    pub is_writable: bool,
+}
+
+impl JobAccountMeta {
+    pub const SIZE: usize = 32 + 1 + 1;
+}
+
+/// Job configuration passed to `register_job`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct JobRegistration {
+    pub target_program: Pubkey,
+    pub target_instruction: String,
+    pub trigger_type: TriggerType,
+    pub trigger_params: Vec<u8>,
+    pub gas_limit: u64,
+    pub min_balance: u64,
+    /// Lamports moved from the owner into the job balance
+    pub initial_funding: u64,
+    /// Name, tags and metadata URI, stored in the job's metadata account
+    pub metadata: Option<JobMetadataParams>,
+}
+
+/// Job configuration changes applied by `update_job`.
+/// Unset fields are left unchanged.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct JobUpdate {
+    pub target_program: Option<Pubkey>,
+    pub target_instruction: Option<String>,
+    pub trigger_type: Option<TriggerType>,
+    pub trigger_params: Option<Vec<u8>>,
+    pub target_accounts: Option<Vec<JobAccountMeta>>,
+    pub gas_limit: Option<u64>,
+    pub min_balance: Option<u64>,
+    pub grace_period: Option<i64>,
+    pub catch_up_policy: Option<CatchUpPolicy>,
+    /// New check instruction; an empty name removes the check
+    pub check_instruction: Option<String>,
+    pub tip_lamports: Option<u64>,
+    /// New fee cap; zero removes the cap
+    pub max_fee_per_execution: Option<u64>,
+    /// Replaces the job's name, tags and metadata URI
+    pub metadata: Option<JobMetadataParams>,
+    /// New minimum keeper tier; zero allows any keeper
+    pub min_keeper_tier: Option<u8>,
+    /// New cap on transaction fees repaid to the keeper; zero repays only the record rent
+    pub max_fee_reimbursement: Option<u64>,
+    /// New expiry timestamp; zero removes the expiry
+    pub end_at: Option<i64>,
+}
+
+/// Human-facing job metadata set on register or update
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq, Default)]
+pub struct JobMetadataParams {
+    pub name: String,
+    pub tags: Vec<String>,
+    pub metadata_uri: String,
+}
+
+impl JobMetadataParams {
+    pub const MAX_NAME_LEN: usize = 64;
+    pub const MAX_TAGS: usize = 8;
+    pub const MAX_TAG_LEN: usize = 32;
+    pub const MAX_URI_LEN: usize = 200;
+
+    pub fn is_valid(&self) -> bool {
+        self.name.len() <= Self::MAX_NAME_LEN
+            && self.tags.len() <= Self::MAX_TAGS
+            && self.tags.iter().all(|tag| !tag.is_empty() && tag.len() <= Self::MAX_TAG_LEN)
+            && self.metadata_uri.len() <= Self::MAX_URI_LEN
+    }
+}
+
+/// How a TimeBased job handles scheduled runs that are overdue
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CatchUpPolicy {
+    /// Run once for all overdue runs and resume the schedule from now
+    RunOnce,
+    /// Only run within a scheduled run's grace window, skipping the rest
+    SkipMissed,
+    /// Run once per overdue scheduled run, oldest first
+    BackfillEach,
 }
 
 /// Trigger type configuration
@@ -50,6 +519,45 @@ pub enum TriggerType {
     LogTrigger,
     /// Hybrid trigger combining multiple conditions
     Hybrid,
+    /// Execute every N slots
+    SlotInterval,
+    /// Execute once per epoch, shortly after the boundary
+    EpochStart,
+    /// Execute when a watched account's data changes
+    AccountChange,
+    /// Execute when an oracle price crosses a threshold
+    PriceThreshold,
+}
+
+/// PriceThreshold trigger configuration, packed into trigger_params as
+/// price account (32) | comparison (1) | threshold i64 (8) | max staleness u64 (8)
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct PriceThresholdParams {
+    /// Pyth price account to read
+    pub price_account: Pubkey,
+    pub comparison: PriceComparison,
+    /// Threshold in the price account's own exponent
+    pub threshold: i64,
+    /// Maximum age of the published price in seconds
+    pub max_staleness: u64,
+}
+
+impl PriceThresholdParams {
+    pub const SIZE: usize = 32 + 1 + 8 + 8;
+
+    pub fn parse(params: &[u8]) -> Option<Self> {
+        Some(Self {
+            price_account: Pubkey::try_from(params.get(0..32)?).ok()?,
+            comparison: PriceComparison::from_u8(*params.get(32)?)?,
+            threshold: i64::from_le_bytes(params.get(33..41)?.try_into().ok()?),
+            max_staleness: u64::from_le_bytes(params.get(41..49)?.try_into().ok()?),
+        })
+    }
+}
+
+/// Little-endian u64 from the first 8 bytes of trigger params
+fn read_u64_param(params: &[u8]) -> Option<u64> {
+    params.get(0..8)?.try_into().ok().map(u64::from_le_bytes)
 }
 
 /// Keeper registration and reputation
@@ -66,9 +574,25 @@ pub struct Keeper {
     pub last_execution_time: i64,       // Last execution timestamp
     pub registered_at: i64,             // Registration timestamp
     pub bump: u8,                       // PDA bump seed
+    pub version: u8,                    // Account layout version
+    pub window_start_slot: u64,         // Start slot of the current rate limit window
+    pub window_executions: u32,         // Executions within the current window
+    pub delegated_stake: u64,           // Lamports backing delegator shares, including unbonding
+    pub delegator_shares: u64,          // Outstanding delegator shares
+    pub unbonding_shares: u64,          // Delegator shares waiting out the unbonding period
+    pub commission_bps: u16,            // Keeper's cut of delegator rewards
+    pub total_reimbursed: u64,          // Transaction costs repaid by jobs, kept apart from earnings
+    pub pending_commission_bps: u16,    // Commission increase waiting out its delay
+    pub commission_effective_at: i64,   // When the pending commission applies (0 = none queued)
 }
 
 impl Keeper {
+    /// Current account layout version
+    pub const VERSION: u8 = 5;
+
+    /// Default commission on delegator rewards (10%)
+    pub const DEFAULT_COMMISSION_BPS: u16 = 1000;
+
     pub const MAX_SIZE: usize = 8 + // discriminator
         32 + // address
         8 + // stake_amount
@@ -80,7 +604,91 @@ impl Keeper {
         8 + // pending_rewards
         8 + // last_execution_time
         8 + // registered_at
-        1; // bump
+        1 + // bump
+        1 + // version
+        8 + // window_start_slot
+        4 + // window_executions
+        8 + // delegated_stake
+        8 + // delegator_shares
+        8 + // unbonding_shares
+        2 + // commission_bps
+        8 + // total_reimbursed
+        2 + // pending_commission_bps
+        8; // commission_effective_at
+
+    /// Own stake plus delegated stake that is not unbonding
+    pub fn effective_stake(&self) -> u64 {
+        let unbonding = self.delegated_value(self.unbonding_shares);
+        self.stake_amount
+            .saturating_add(self.delegated_stake.saturating_sub(unbonding))
+    }
+
+    /// Lamports currently backing `shares` of the delegation pool
+    pub fn delegated_value(&self, shares: u64) -> u64 {
+        if self.delegator_shares == 0 {
+            return 0;
+        }
+        (shares as u128 * self.delegated_stake as u128 / self.delegator_shares as u128) as u64
+    }
+
+    /// Shares minted for delegating `amount` lamports, or None if slashes
+    /// have emptied a pool that still has shares outstanding
+    pub fn shares_for(&self, amount: u64) -> Option<u64> {
+        if self.delegator_shares == 0 {
+            return Some(amount);
+        }
+        if self.delegated_stake == 0 {
+            return None;
+        }
+        u64::try_from(amount as u128 * self.delegator_shares as u128 / self.delegated_stake as u128).ok()
+    }
+
+    /// Portion of `amount` borne by delegators, pro-rata to the stake at risk
+    pub fn delegator_portion(&self, amount: u64) -> u64 {
+        let total_stake = self.stake_amount as u128 + self.delegated_stake as u128;
+        if total_stake == 0 {
+            return 0;
+        }
+        (amount as u128 * self.delegated_stake as u128 / total_stake) as u64
+    }
+
+    /// Apply a queued commission increase once its delay has passed
+    pub fn settle_commission(&mut self, now: i64) {
+        if self.commission_effective_at != 0 && now >= self.commission_effective_at {
+            self.commission_bps = self.pending_commission_bps;
+            self.pending_commission_bps = 0;
+            self.commission_effective_at = 0;
+        }
+    }
+
+    /// Split a keeper fee into its (keeper, delegator) shares. Delegators earn
+    /// pro-rata to their stake, less the keeper's commission.
+    pub fn split_rewards(&self, keeper_fee: u64) -> (u64, u64) {
+        let delegator_gross = self.delegator_portion(keeper_fee);
+        let commission = (delegator_gross as u128 * self.commission_bps as u128 / 10000) as u64;
+        let delegator_reward = delegator_gross - commission;
+        (keeper_fee - delegator_reward, delegator_reward)
+    }
+
+    /// Count an execution against the keeper's rate limit window.
+    /// Returns false if the keeper has used up the current window.
+    pub fn consume_rate_limit(&mut self, slot: u64, window_slots: u64, max_executions: u32) -> bool {
+        if max_executions == 0 {
+            return true;
+        }
+
+        if slot >= self.window_start_slot.saturating_add(window_slots) {
+            self.window_start_slot = slot;
+            self.window_executions = 0;
+        }
+
+        if self.window_executions >= max_executions {
+            return false;
+        }
+
+        self.window_executions += 1;
+        true
+    }
 
     /// Calculate reputation score based on performance
     pub fn calculate_reputation(&self) -> u64 {
@@ -121,9 +729,64 @@ pub struct RegistryState {
     pub next_job_id: u64,               // Next job ID counter
     pub is_paused: bool,                // Emergency pause status
     pub bump: u8,                       // PDA bump seed
+    pub version: u8,                    // Account layout version
+    pub max_executions_per_window: u32, // Per-keeper executions per window (0 disables)
+    pub rate_limit_window_slots: u64,   // Rate limit window length in slots
+    pub unregister_cooldown: i64,       // Seconds after last execution before unregistering
+    pub fee_manager: Pubkey,            // Proposes fee, stake and rate limit changes
+    pub slasher: Pubkey,                // Slashes misbehaving keepers
+    pub pauser: Pubkey,                 // Pauses and unpauses the registry
+    pub upgrader: Pubkey,               // Proposes governance timelock changes
+    pub params_timelock: i64,           // Delay in seconds before queued params apply
+    pub slash_dispute_window: i64,      // Seconds a keeper has to appeal a proposed slash
+    pub slash_count: u64,               // Slashes applied, used as slash IDs
+    pub insurance_slash_share_bps: u16, // Share of slashed funds sent to the insurance pool
+    pub insurance_fee_share_bps: u16,   // Share of protocol fees sent to the insurance pool
+    pub insurance_deposits: u64,        // Total lamports paid into the insurance pool
+    pub insurance_payouts: u64,         // Total lamports paid out to job owners
+    pub delegation_unbonding_period: i64, // Seconds undelegated stake stays slashable
+    pub keeper_tiers: Vec<KeeperTier>,  // Tiers 1..=len in ascending order (tier 0 = any keeper)
+    pub job_reap_delay: i64,            // Seconds a drained inactive job waits before it can be reaped
+    pub reap_bounty_bps: u16,           // Share of a reaped job's rent paid to the caller
 }
 
 impl RegistryState {
+    /// Current account layout version
+    pub const VERSION: u8 = 8;
+
+    /// Default delay before queued parameter changes can be executed (24 hours)
+    pub const DEFAULT_PARAMS_TIMELOCK: i64 = 86_400;
+
+    /// Default window for appealing a proposed slash (3 days)
+    pub const DEFAULT_SLASH_DISPUTE_WINDOW: i64 = 259_200;
+
+    /// Default share of slashed funds sent to the insurance pool (50%)
+    pub const DEFAULT_INSURANCE_SLASH_SHARE_BPS: u16 = 5000;
+
+    /// Default share of protocol fees sent to the insurance pool (10%)
+    pub const DEFAULT_INSURANCE_FEE_SHARE_BPS: u16 = 1000;
+
+    /// Default unbonding period for undelegated stake (7 days)
+    pub const DEFAULT_DELEGATION_UNBONDING_PERIOD: i64 = 604_800;
+
+    /// Default wait before a drained inactive job can be reaped (30 days)
+    pub const DEFAULT_JOB_REAP_DELAY: i64 = 2_592_000;
+
+    /// Default share of a reaped job's rent paid to the caller (10%)
+    pub const DEFAULT_REAP_BOUNTY_BPS: u16 = 1000;
+
+    /// Maximum number of keeper tiers above the base tier
+    pub const MAX_KEEPER_TIERS: usize = 4;
+
+    /// Default per-keeper executions allowed in one rate limit window
+    pub const DEFAULT_MAX_EXECUTIONS_PER_WINDOW: u32 = 10;
+
+    /// Default rate limit window (~1 minute of slots)
+    pub const DEFAULT_RATE_LIMIT_WINDOW_SLOTS: u64 = 150;
+
+    /// Default keeper unregister cooldown (24 hours)
+    pub const DEFAULT_UNREGISTER_COOLDOWN: i64 = 86_400;
+
     pub const MAX_SIZE: usize = 8 + // discriminator
         32 + // admin
         8 + // base_fee
@@ -139,6 +802,434 @@ impl RegistryState {
         8 + // protocol_revenue
         8 + // next_job_id
         1 + // is_paused
+        1 + // bump
+        1 + // version
+        4 + // max_executions_per_window
+        8 + // rate_limit_window_slots
+        8 + // unregister_cooldown
+        32 + // fee_manager
+        32 + // slasher
+        32 + // pauser
+        32 + // upgrader
+        8 + // params_timelock
+        8 + // slash_dispute_window
+        8 + // slash_count
+        2 + // insurance_slash_share_bps
+        2 + // insurance_fee_share_bps
+        8 + // insurance_deposits
+        8 + // insurance_payouts
+        8 + // delegation_unbonding_period
+        (4 + RegistryState::MAX_KEEPER_TIERS * KeeperTier::SIZE) + // keeper_tiers
+        8 + // job_reap_delay
+        2; // reap_bounty_bps
+
+    /// Portion of `amount` owed to the insurance pool at `share_bps`
+    pub fn insurance_share(amount: u64, share_bps: u16) -> u64 {
+        (amount as u128 * share_bps as u128 / 10000) as u64
+    }
+
+    /// Highest tier whose stake and reputation thresholds the keeper meets
+    pub fn keeper_tier(&self, keeper: &Keeper) -> u8 {
+        let stake = keeper.effective_stake();
+        self.keeper_tiers
+            .iter()
+            .take_while(|tier| stake >= tier.min_stake && keeper.reputation_score >= tier.min_reputation)
+            .count() as u8
+    }
+
+    /// Current key holding a governance role
+    pub fn role_key(&self, role: RegistryRole) -> Pubkey {
+        match role {
+            RegistryRole::FeeManager => self.fee_manager,
+            RegistryRole::Slasher => self.slasher,
+            RegistryRole::Pauser => self.pauser,
+            RegistryRole::Upgrader => self.upgrader,
+        }
+    }
+
+    /// Assign a governance role, returning the previous holder
+    pub fn set_role_key(&mut self, role: RegistryRole, key: Pubkey) -> Pubkey {
+        let slot = match role {
+            RegistryRole::FeeManager => &mut self.fee_manager,
+            RegistryRole::Slasher => &mut self.slasher,
+            RegistryRole::Pauser => &mut self.pauser,
+            RegistryRole::Upgrader => &mut self.upgrader,
+        };
+        std::mem::replace(slot, key)
+    }
+}
+
+/// Governance roles assigned by the registry admin
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RegistryRole {
+    /// Proposes fee, stake and rate limit changes
+    FeeManager,
+    /// Slashes misbehaving keepers
+    Slasher,
+    /// Pauses and unpauses the registry
+    Pauser,
+    /// Proposes governance timelock changes
+    Upgrader,
+}
+
+/// Stake and reputation a keeper needs to reach a tier
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct KeeperTier {
+    pub min_stake: u64,      // Minimum effective stake, including delegations
+    pub min_reputation: u64, // Minimum reputation score (0-10000)
+}
+
+impl KeeperTier {
+    pub const SIZE: usize = 8 + 8;
+
+    /// True if the tiers fit the registry and each is at least as strict as the one below
+    pub fn is_valid_ladder(tiers: &[KeeperTier]) -> bool {
+        tiers.len() <= RegistryState::MAX_KEEPER_TIERS
+            && tiers.iter().all(|tier| tier.min_reputation <= 10000)
+            && tiers.windows(2).all(|pair| {
+                pair[0].min_stake < pair[1].min_stake && pair[0].min_reputation <= pair[1].min_reputation
+            })
+    }
+}
+
+/// Registry parameter changes queued behind the governance timelock.
+/// Unset fields are left unchanged when the proposal executes.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq)]
+pub struct RegistryParams {
+    pub base_fee: Option<u64>,
+    pub min_stake: Option<u64>,
+    pub protocol_fee_bps: Option<u16>,
+    pub max_executions_per_window: Option<u32>,
+    pub rate_limit_window_slots: Option<u64>,
+    pub unregister_cooldown: Option<i64>,
+    pub params_timelock: Option<i64>,
+    pub slash_dispute_window: Option<i64>,
+    pub insurance_slash_share_bps: Option<u16>,
+    pub insurance_fee_share_bps: Option<u16>,
+    pub delegation_unbonding_period: Option<i64>,
+    /// Replaces the whole keeper tier ladder; an empty list removes all tiers
+    pub keeper_tiers: Option<Vec<KeeperTier>>,
+    pub job_reap_delay: Option<i64>,
+    pub reap_bounty_bps: Option<u16>,
+}
+
+impl RegistryParams {
+    pub const MAX_SIZE: usize =
+        (1 + 8) + // base_fee
+        (1 + 8) + // min_stake
+        (1 + 2) + // protocol_fee_bps
+        (1 + 4) + // max_executions_per_window
+        (1 + 8) + // rate_limit_window_slots
+        (1 + 8) + // unregister_cooldown
+        (1 + 8) + // params_timelock
+        (1 + 8) + // slash_dispute_window
+        (1 + 2) + // insurance_slash_share_bps
+        (1 + 2) + // insurance_fee_share_bps
+        (1 + 8) + // delegation_unbonding_period
+        (1 + 4 + RegistryState::MAX_KEEPER_TIERS * KeeperTier::SIZE) + // keeper_tiers
+        (1 + 8) + // job_reap_delay
+        (1 + 2); // reap_bounty_bps
+
+    /// True if the proposal touches fees, stake or rate limits
+    pub fn changes_economics(&self) -> bool {
+        self.base_fee.is_some()
+            || self.min_stake.is_some()
+            || self.protocol_fee_bps.is_some()
+            || self.max_executions_per_window.is_some()
+            || self.rate_limit_window_slots.is_some()
+            || self.unregister_cooldown.is_some()
+            || self.insurance_slash_share_bps.is_some()
+            || self.insurance_fee_share_bps.is_some()
+            || self.delegation_unbonding_period.is_some()
+            || self.keeper_tiers.is_some()
+            || self.job_reap_delay.is_some()
+            || self.reap_bounty_bps.is_some()
+    }
+
+    /// True if the proposal touches the timelock or slash dispute window
+    pub fn changes_governance(&self) -> bool {
+        self.params_timelock.is_some() || self.slash_dispute_window.is_some()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        !self.changes_economics() && !self.changes_governance()
+    }
+
+    pub fn validate(&self) -> bool {
+        !(self.base_fee.is_some_and(|fee| fee == 0)
+            || self.min_stake.is_some_and(|stake| stake == 0)
+            || self.protocol_fee_bps.is_some_and(|bps| bps > 1000) // Max 10%
+            || self.rate_limit_window_slots.is_some_and(|slots| slots == 0)
+            || self.unregister_cooldown.is_some_and(|cooldown| cooldown < 0)
+            || self.params_timelock.is_some_and(|delay| delay < 0)
+            || self.slash_dispute_window.is_some_and(|window| window < 0)
+            || self.insurance_slash_share_bps.is_some_and(|bps| bps > 10000)
+            || self.insurance_fee_share_bps.is_some_and(|bps| bps > 10000)
+            || self.delegation_unbonding_period.is_some_and(|period| period < 0)
+            || self.keeper_tiers.as_ref().is_some_and(|tiers| !KeeperTier::is_valid_ladder(tiers))
+            || self.job_reap_delay.is_some_and(|delay| delay < 0)
+            || self.reap_bounty_bps.is_some_and(|bps| bps > 10000))
+    }
+
+    pub fn apply(&self, registry: &mut RegistryState) {
+        if let Some(base_fee) = self.base_fee {
+            registry.base_fee = base_fee;
+        }
+        if let Some(min_stake) = self.min_stake {
+            registry.min_stake = min_stake;
+        }
+        if let Some(protocol_fee_bps) = self.protocol_fee_bps {
+            registry.protocol_fee_bps = protocol_fee_bps;
+        }
+        if let Some(max_executions_per_window) = self.max_executions_per_window {
+            registry.max_executions_per_window = max_executions_per_window;
+        }
+        if let Some(rate_limit_window_slots) = self.rate_limit_window_slots {
+            registry.rate_limit_window_slots = rate_limit_window_slots;
+        }
+        if let Some(unregister_cooldown) = self.unregister_cooldown {
+            registry.unregister_cooldown = unregister_cooldown;
+        }
+        if let Some(params_timelock) = self.params_timelock {
+            registry.params_timelock = params_timelock;
+        }
+        if let Some(slash_dispute_window) = self.slash_dispute_window {
+            registry.slash_dispute_window = slash_dispute_window;
+        }
+        if let Some(insurance_slash_share_bps) = self.insurance_slash_share_bps {
+            registry.insurance_slash_share_bps = insurance_slash_share_bps;
+        }
+        if let Some(insurance_fee_share_bps) = self.insurance_fee_share_bps {
+            registry.insurance_fee_share_bps = insurance_fee_share_bps;
+        }
+        if let Some(delegation_unbonding_period) = self.delegation_unbonding_period {
+            registry.delegation_unbonding_period = delegation_unbonding_period;
+        }
+        if let Some(keeper_tiers) = &self.keeper_tiers {
+            registry.keeper_tiers = keeper_tiers.clone();
+        }
+        if let Some(job_reap_delay) = self.job_reap_delay {
+            registry.job_reap_delay = job_reap_delay;
+        }
+        if let Some(reap_bounty_bps) = self.reap_bounty_bps {
+            registry.reap_bounty_bps = reap_bounty_bps;
+        }
+    }
+}
+
+/// Parameter change waiting for its timelock to expire
+#[account]
+pub struct PendingParams {
+    pub proposer: Pubkey,               // Role holder that queued the change
+    pub params: RegistryParams,         // Queued parameter changes
+    pub proposed_at: i64,               // Proposal timestamp
+    pub executable_at: i64,             // Earliest execution timestamp
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl PendingParams {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 + // proposer
+        RegistryParams::MAX_SIZE + // params
+        8 + // proposed_at
+        8 + // executable_at
+        1; // bump
+}
+
+/// Slash proposed by the slasher, escrowed until its dispute window closes
+#[account]
+pub struct PendingSlash {
+    pub keeper: Pubkey,                 // Keeper PDA being slashed
+    pub keeper_address: Pubkey,         // Keeper's wallet, which may appeal
+    pub slasher: Pubkey,                // Role holder that proposed the slash
+    pub stake_amount: u64,              // Escrowed from the keeper's stake
+    pub rewards_amount: u64,            // Escrowed from the keeper's pending rewards
+    pub delegated_amount: u64,          // Escrowed pro-rata from delegated stake
+    pub reason: String,                 // Slasher's reason
+    pub proposed_at: i64,               // Proposal timestamp
+    pub dispute_deadline: i64,          // Last moment the keeper may appeal
+    pub appeal: Option<String>,         // Keeper's appeal, if submitted
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl PendingSlash {
+    /// Maximum length of the slash reason and the appeal
+    pub const MAX_REASON_LEN: usize = 200;
+
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 + // keeper
+        32 + // keeper_address
+        32 + // slasher
+        8 + // stake_amount
+        8 + // rewards_amount
+        8 + // delegated_amount
+        (4 + PendingSlash::MAX_REASON_LEN) + // reason
+        8 + // proposed_at
+        8 + // dispute_deadline
+        (1 + 4 + PendingSlash::MAX_REASON_LEN) + // appeal
+        1; // bump
+
+    /// Total lamports held in escrow
+    pub fn escrowed(&self) -> u64 {
+        self.stake_amount + self.rewards_amount + self.delegated_amount
+    }
+}
+
+/// Applied slash, which can back at most one insurance claim
+#[account]
+pub struct SlashRecord {
+    pub slash_id: u64,                  // Sequential slash ID
+    pub keeper: Pubkey,                 // Slashed keeper's wallet
+    pub amount: u64,                    // Lamports slashed
+    pub insurance_amount: u64,          // Share sent to the insurance pool
+    pub slashed_at: i64,                // When the slash was applied
+    pub claimed: bool,                  // Whether an insurance claim has used it
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl SlashRecord {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        8 + // slash_id
+        32 + // keeper
+        8 + // amount
+        8 + // insurance_amount
+        8 + // slashed_at
+        1 + // claimed
+        1; // bump
+}
+
+/// Lamports shared by many jobs of one owner, debited when those jobs execute
+#[account]
+pub struct FundingPool {
+    pub owner: Pubkey,                  // Pool owner, also the owner of its jobs
+    pub pool_id: u64,                   // Owner-chosen pool identifier
+    pub balance: u64,                   // Lamports available for execution fees
+    pub min_balance: u64,               // Balance executions may not draw below
+    pub total_spent: u64,               // Lamports paid out in execution fees
+    pub created_at: i64,                // Creation timestamp
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl FundingPool {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 + // owner
+        8 + // pool_id
+        8 + // balance
+        8 + // min_balance
+        8 + // total_spent
+        8 + // created_at
+        1; // bump
+}
+
+/// Job name, tags and metadata URI, kept out of the job account and sized
+/// to their contents
+#[account]
+pub struct JobMetadata {
+    pub job_id: u64,                    // Described job
+    pub name: String,                   // Display name
+    pub tags: Vec<String>,              // Labels jobs can be filtered by
+    pub metadata_uri: String,           // Off-chain metadata document
+    pub updated_at: i64,                // Last update timestamp
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl JobMetadata {
+    /// Account size needed to hold `metadata`
+    pub fn space(metadata: &JobMetadataParams) -> usize {
+        8 + // discriminator
+        8 + // job_id
+        (4 + metadata.name.len()) + // name
+        (4 + metadata.tags.iter().map(|tag| 4 + tag.len()).sum::<usize>()) + // tags
+        (4 + metadata.metadata_uri.len()) + // metadata_uri
+        8 + // updated_at
+        1 // bump
+    }
+}
+
+/// A third party's contribution to a job, held as shares of the job balance
+#[account]
+pub struct Sponsorship {
+    pub job_id: u64,                    // Sponsored job
+    pub sponsor: Pubkey,                // Sponsor's wallet
+    pub shares: u64,                    // Shares of the job balance held
+    pub contributed: u64,               // Lamports contributed in total
+    pub sponsored_at: i64,              // First contribution timestamp
+    pub bump: u8,                       // PDA bump seed
+    pub funding_round: u64,             // Job funding round the shares belong to
+}
+
+impl Sponsorship {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        8 + // job_id
+        32 + // sponsor
+        8 + // shares
+        8 + // contributed
+        8 + // sponsored_at
+        1 + // bump
+        8; // funding_round
+}
+
+/// Stake delegated to a keeper, held as shares of the keeper's delegation pool
+#[account]
+pub struct Delegation {
+    pub keeper: Pubkey,                 // Keeper PDA delegated to
+    pub delegator: Pubkey,              // Delegator's wallet
+    pub shares: u64,                    // Pool shares held, including unbonding
+    pub unbonding_shares: u64,          // Shares waiting out the unbonding period
+    pub unbonding_release_at: i64,      // When unbonding shares can be withdrawn
+    pub delegated_at: i64,              // First delegation timestamp
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl Delegation {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 + // keeper
+        32 + // delegator
+        8 + // shares
+        8 + // unbonding_shares
+        8 + // unbonding_release_at
+        8 + // delegated_at
+        1; // bump
+}
+
+/// Pool compensating job owners harmed by slashed keepers, funded by
+/// shares of slashes and protocol fees
+#[account]
+pub struct InsurancePool {
+    pub balance: u64,                   // Lamports available for payouts
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl InsurancePool {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        8 + // balance
+        1; // bump
+}
+
+/// Insurance payout approved for a job owner, one per execution record
+#[account]
+pub struct InsuranceClaim {
+    pub execution_record: Pubkey,       // Execution the owner was harmed by
+    pub job_id: u64,                    // Job the execution belongs to
+    pub owner: Pubkey,                  // Job owner that was paid
+    pub keeper: Pubkey,                 // Keeper that performed the execution
+    pub slash_id: u64,                  // Slash the claim is backed by
+    pub amount: u64,                    // Lamports paid out
+    pub approved_by: Pubkey,            // Admin that approved the payout
+    pub approved_at: i64,               // Approval timestamp
+    pub bump: u8,                       // PDA bump seed
+}
+
+impl InsuranceClaim {
+    pub const MAX_SIZE: usize = 8 + // discriminator
+        32 + // execution_record
+        8 + // job_id
+        32 + // owner
+        32 + // keeper
+        8 + // slash_id
+        8 + // amount
+        32 + // approved_by
+        8 + // approved_at
         1; // bump
 }
 
@@ -153,9 +1244,17 @@ pub struct ExecutionRecord {
     pub fee_paid: u64,                  // Fee paid to keeper
     pub error_code: Option<u32>,        // Error code if failed
     pub bump: u8,                       // PDA bump seed
+    pub version: u8,                    // Account layout version
+    pub return_data: Vec<u8>,           // Target return data, truncated to MAX_RETURN_DATA
 }
 
 impl ExecutionRecord {
+    /// Current account layout version
+    pub const VERSION: u8 = 2;
+
+    /// Maximum target return data bytes kept per execution
+    pub const MAX_RETURN_DATA: usize = 128;
+
     pub const MAX_SIZE: usize = 8 + // discriminator
         8 + // job_id
         32 + // keeper
@@ -164,5 +1263,329 @@ impl ExecutionRecord {
         8 + // gas_used
         8 + // fee_paid
         (1 + 4) + // error_code (Option<u32>)
-        1; // bump
-}
\ No newline at end of file
+        1 + // bump
+        1 + // version
+        (4 + ExecutionRecord::MAX_RETURN_DATA); // return_data
+}
+
+impl Versioned for AutomationJob {
+    const VERSION: u8 = AutomationJob::VERSION;
+    const SPACE: usize = AutomationJob::MAX_SIZE;
+    const LEGACY_SPACE: usize = 461;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 3 {
+            // Anchor the schedule at the first run the old interval check would allow
+            let resume_at = if self.last_execution == 0 {
+                self.created_at
+            } else {
+                self.last_execution.saturating_add(self.time_interval())
+            };
+            self.next_scheduled_time = self.first_run_at_or_after(resume_at);
+            self.grace_period = 0;
+            self.missed_executions = 0;
+            self.catch_up_policy = CatchUpPolicy::RunOnce;
+        }
+        if self.version < 9 {
+            // The owner funded everything before sponsorships existed
+            self.funding_shares = self.balance;
+            self.sponsor_shares = 0;
+        }
+        if self.version < 11 {
+            self.max_fee_reimbursement = AutomationJob::DEFAULT_MAX_FEE_REIMBURSEMENT;
+        }
+        if self.version < 12 && !self.is_active {
+            // Last change before the upgrade is the best record of deactivation
+            self.deactivated_at = self.updated_at;
+        }
+        if self.version < 13 {
+            self.is_paused = false;
+        }
+        if self.version < 14 {
+            self.funding_round = 0;
+        }
+        self.version = AutomationJob::VERSION;
+    }
+
+    fn layout_end(data: &[u8], version: u8) -> Result<usize> {
+        let mut layout = StoredLayout::new(data);
+        layout
+            .fixed(8 + 32 + 32)? // job_id, owner, target_program
+            .vec(1)? // target_instruction
+            .fixed(1)? // trigger_type
+            .vec(1)? // trigger_params
+            .fixed(8 * 3 + 1 + 8 * 4 + 1)?; // gas_limit through bump
+        if version >= 1 {
+            layout.fixed(1)?; // version
+        }
+        if version >= 2 {
+            layout.vec(JobAccountMeta::SIZE)?; // target_accounts
+        }
+        if version >= 3 {
+            layout.fixed(8 * 3 + 1)?; // next_scheduled_time through catch_up_policy
+        }
+        if version >= 4 {
+            layout.fixed(8 + 8)?; // next_scheduled_slot, next_epoch
+        }
+        if version >= 5 {
+            layout.fixed(32)?; // last_data_hash
+        }
+        if version >= 6 {
+            layout.option_vec(1)?; // check_instruction
+        }
+        if version >= 7 {
+            layout.fixed(8 + 8)?; // tip_lamports, max_fee_per_execution
+        }
+        if version >= 8 {
+            layout.option(32)?.fixed(8 + 8)?; // funding_pool, pool_spend_cap, pool_spent
+        }
+        if version >= 9 {
+            layout.fixed(8 + 8)?; // funding_shares, sponsor_shares
+        }
+        if version >= 10 {
+            layout.fixed(1)?; // min_keeper_tier
+        }
+        if version >= 11 {
+            layout.fixed(8)?; // max_fee_reimbursement
+        }
+        if version >= 12 {
+            layout.fixed(8 + 8)?; // end_at, deactivated_at
+        }
+        if version >= 13 {
+            layout.fixed(1)?; // is_paused
+        }
+        if version >= 14 {
+            layout.fixed(8)?; // funding_round
+        }
+        Ok(layout.end())
+    }
+}
+
+impl Versioned for Keeper {
+    const VERSION: u8 = Keeper::VERSION;
+    const SPACE: usize = Keeper::MAX_SIZE;
+    const LEGACY_SPACE: usize = 8 + 32 + 8 + 8 + 1 + 8 * 6 + 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 3 {
+            self.commission_bps = Keeper::DEFAULT_COMMISSION_BPS;
+        }
+        self.version = Keeper::VERSION;
+    }
+
+    fn layout_end(data: &[u8], version: u8) -> Result<usize> {
+        let mut layout = StoredLayout::new(data);
+        layout.fixed(32 + 8 + 8 + 1 + 8 * 6 + 1)?; // address through bump
+        if version >= 1 {
+            layout.fixed(1)?; // version
+        }
+        if version >= 2 {
+            layout.fixed(8 + 4)?; // window_start_slot, window_executions
+        }
+        if version >= 3 {
+            layout.fixed(8 * 3 + 2)?; // delegated_stake through commission_bps
+        }
+        if version >= 4 {
+            layout.fixed(8)?; // total_reimbursed
+        }
+        if version >= 5 {
+            layout.fixed(2 + 8)?; // pending_commission_bps, commission_effective_at
+        }
+        Ok(layout.end())
+    }
+}
+
+impl Versioned for RegistryState {
+    const VERSION: u8 = RegistryState::VERSION;
+    const SPACE: usize = RegistryState::MAX_SIZE;
+    const LEGACY_SPACE: usize = 8 + 32 + 8 + 8 + 2 + 32 + 8 * 8 + 1 + 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        if self.version < 2 {
+            self.max_executions_per_window = RegistryState::DEFAULT_MAX_EXECUTIONS_PER_WINDOW;
+            self.rate_limit_window_slots = RegistryState::DEFAULT_RATE_LIMIT_WINDOW_SLOTS;
+            self.unregister_cooldown = RegistryState::DEFAULT_UNREGISTER_COOLDOWN;
+        }
+        if self.version < 3 {
+            self.fee_manager = self.admin;
+            self.slasher = self.admin;
+            self.pauser = self.admin;
+            self.upgrader = self.admin;
+            self.params_timelock = RegistryState::DEFAULT_PARAMS_TIMELOCK;
+        }
+        if self.version < 4 {
+            self.slash_dispute_window = RegistryState::DEFAULT_SLASH_DISPUTE_WINDOW;
+        }
+        if self.version < 5 {
+            self.insurance_slash_share_bps = RegistryState::DEFAULT_INSURANCE_SLASH_SHARE_BPS;
+            self.insurance_fee_share_bps = RegistryState::DEFAULT_INSURANCE_FEE_SHARE_BPS;
+        }
+        if self.version < 6 {
+            self.delegation_unbonding_period = RegistryState::DEFAULT_DELEGATION_UNBONDING_PERIOD;
+        }
+        if self.version < 8 {
+            self.job_reap_delay = RegistryState::DEFAULT_JOB_REAP_DELAY;
+            self.reap_bounty_bps = RegistryState::DEFAULT_REAP_BOUNTY_BPS;
+        }
+        self.version = RegistryState::VERSION;
+    }
+
+    fn layout_end(data: &[u8], version: u8) -> Result<usize> {
+        let mut layout = StoredLayout::new(data);
+        layout.fixed(32 + 8 + 8 + 2 + 32 + 8 * 8 + 1 + 1)?; // admin through bump
+        if version >= 1 {
+            layout.fixed(1)?; // version
+        }
+        if version >= 2 {
+            layout.fixed(4 + 8 + 8)?; // max_executions_per_window through unregister_cooldown
+        }
+        if version >= 3 {
+            layout.fixed(32 * 4 + 8)?; // fee_manager through params_timelock
+        }
+        if version >= 4 {
+            layout.fixed(8)?; // slash_dispute_window
+        }
+        if version >= 5 {
+            layout.fixed(8 + 2 + 2 + 8 + 8)?; // slash_count through insurance_payouts
+        }
+        if version >= 6 {
+            layout.fixed(8)?; // delegation_unbonding_period
+        }
+        if version >= 7 {
+            layout.vec(KeeperTier::SIZE)?; // keeper_tiers
+        }
+        if version >= 8 {
+            layout.fixed(8 + 2)?; // job_reap_delay, reap_bounty_bps
+        }
+        Ok(layout.end())
+    }
+}
+
+impl Versioned for ExecutionRecord {
+    const VERSION: u8 = ExecutionRecord::VERSION;
+    const SPACE: usize = ExecutionRecord::MAX_SIZE;
+    const LEGACY_SPACE: usize = 8 + 8 + 32 + 8 + 1 + 8 + 8 + (1 + 4) + 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+
+    fn upgrade(&mut self) {
+        self.version = ExecutionRecord::VERSION;
+    }
+
+    fn layout_end(data: &[u8], version: u8) -> Result<usize> {
+        let mut layout = StoredLayout::new(data);
+        layout
+            .fixed(8 + 32 + 8 + 1 + 8 + 8)? // job_id through fee_paid
+            .option(4)? // error_code
+            .fixed(1)?; // bump
+        if version >= 1 {
+            layout.fixed(1)?; // version
+        }
+        if version >= 2 {
+            layout.vec(1)?; // return_data
+        }
+        Ok(layout.end())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn empty_job() -> AutomationJob {
+        let data = vec![0u8; AutomationJob::MAX_SIZE - 8];
+        AutomationJob::deserialize(&mut &data[..]).unwrap()
+    }
+
+    #[test]
+    fn drain_and_refill_cycles_keep_shares_bounded() {
+        let mut job = empty_job();
+        let deposit = 1_000_000_000;
+
+        for cycle in 0..10_000u64 {
+            let shares = job.add_funding(deposit).unwrap();
+            assert!(job.funding_shares <= deposit + job.balance);
+            assert_eq!(job.funding_value(job.funding_shares), job.balance);
+            assert!(shares > 0);
+
+            // Executions spend the balance down to dust, or to nothing at all
+            job.balance = if cycle % 2 == 0 { 5_000 } else { 0 };
+        }
+
+        // Every refill after the first found the balance drained
+        assert_eq!(job.funding_round, 9_999);
+    }
+
+    #[test]
+    fn stale_sponsorships_do_not_claim_new_funding() {
+        let mut job = empty_job();
+        let shares = job.add_funding(1_000_000).unwrap();
+        job.sponsor_shares = shares;
+        let sponsorship = Sponsorship {
+            job_id: 0,
+            sponsor: Pubkey::default(),
+            shares,
+            contributed: 1_000_000,
+            sponsored_at: 0,
+            bump: 0,
+            funding_round: job.funding_round,
+        };
+
+        job.balance = 0;
+        job.add_funding(1_000_000).unwrap();
+
+        assert_eq!(job.sponsorship_shares(&sponsorship), 0);
+        assert_eq!(job.sponsor_shares, 0);
+        assert_eq!(job.funding_value(job.owner_shares()), 1_000_000);
+    }
+
+    #[test]
+    fn stale_bytes_past_a_legacy_layout_read_as_zero() {
+        let mut job = empty_job();
+        job.target_instruction = "run".to_string();
+        job.trigger_params = vec![1; 8];
+        job.bump = 255;
+        let mut current = Vec::new();
+        job.try_serialize(&mut current).unwrap();
+
+        // A legacy job whose trigger params shrank, leaving old bytes behind
+        let legacy_end = AutomationJob::layout_end(&current, 0).unwrap();
+        let mut legacy = current[..legacy_end].to_vec();
+        legacy.resize(AutomationJob::LEGACY_SPACE, 0xAA);
+
+        assert_eq!(AutomationJob::stored_version(&legacy).unwrap(), 0);
+        let data = AutomationJob::stored_data(&legacy).unwrap();
+        let migrated = AutomationJob::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(migrated.version, 0);
+        assert_eq!(migrated.bump, 255);
+        assert_eq!(migrated.trigger_params, vec![1; 8]);
+        assert!(migrated.target_accounts.is_empty());
+        assert_eq!(migrated.check_instruction, None);
+        assert_eq!(migrated.funding_pool, None);
+
+        // A current job keeps everything it stores
+        job.version = AutomationJob::VERSION;
+        job.check_instruction = Some("check".to_string());
+        let mut current = Vec::new();
+        job.try_serialize(&mut current).unwrap();
+        current.resize(AutomationJob::SPACE, 0xAA);
+        assert_eq!(AutomationJob::stored_version(&current).unwrap(), AutomationJob::VERSION);
+        let data = AutomationJob::stored_data(&current).unwrap();
+        let stored = AutomationJob::try_deserialize(&mut data.as_slice()).unwrap();
+        assert_eq!(stored.check_instruction, Some("check".to_string()));
+    }
+}
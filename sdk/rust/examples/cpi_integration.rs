@@ -44,6 +44,7 @@ pub mod my_defi_program {
             })).map_err(|_| ErrorCode::SerializationFailed)?,
             gas_limit: 200_000,
             min_balance: 1_000_000, // 0.001 SOL
+            metadata: None,
         };
 
         // Use PDA as the job owner so the program can manage it
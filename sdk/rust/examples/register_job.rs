@@ -4,7 +4,7 @@
 //! fund it, and manage its lifecycle using the SolCron Rust SDK.
 
 use solcron_sdk::{
-    SolCronClient, JobParams, TriggerType, Utils,
+    SolCronClient, JobParams, JobMetadataParams, TriggerType, Utils,
     error::SolCronResult,
 };
 use solana_sdk::{
@@ -68,6 +68,11 @@ async fn main() -> SolCronResult<()> {
         }))?,
         gas_limit: 200_000,
         min_balance: Utils::sol_to_lamports(0.001), // 0.001 SOL minimum
+        metadata: Some(JobMetadataParams {
+            name: "Hourly rewards harvest".to_string(),
+            tags: vec!["defi".to_string(), "harvest".to_string()],
+            metadata_uri: String::new(),
+        }),
     };
 
     // Validate parameters
@@ -213,6 +218,7 @@ fn demonstrate_trigger_types() -> SolCronResult<Vec<JobParams>> {
             }))?,
             gas_limit: 300_000,
             min_balance: Utils::sol_to_lamports(0.005),
+            metadata: None,
         },
         
         // Conditional trigger - execute when price changes
@@ -229,6 +235,7 @@ fn demonstrate_trigger_types() -> SolCronResult<Vec<JobParams>> {
             }))?,
             gas_limit: 500_000,
             min_balance: Utils::sol_to_lamports(0.01),
+            metadata: None,
         },
         
         // Log-based trigger - execute on specific events
@@ -245,6 +252,7 @@ fn demonstrate_trigger_types() -> SolCronResult<Vec<JobParams>> {
             }))?,
             gas_limit: 250_000,
             min_balance: Utils::sol_to_lamports(0.003),
+            metadata: None,
         },
     ];
 
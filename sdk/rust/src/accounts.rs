@@ -33,6 +33,48 @@ impl Accounts {
         })
     }
 
+    /// Derive a job metadata PDA
+    /// 
+    /// # Arguments
+    /// * `job_id` - The described job identifier
+    pub fn job_metadata(job_id: u64) -> SolCronResult<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &[b"job_metadata", &job_id.to_le_bytes()],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: format!("Failed to derive job metadata PDA for job_id: {}", job_id),
+        })
+    }
+
+    /// Derive a job sponsorship PDA
+    /// 
+    /// # Arguments
+    /// * `job_id` - The sponsored job identifier
+    /// * `sponsor` - The sponsor's public key
+    pub fn sponsorship(job_id: u64, sponsor: &Pubkey) -> SolCronResult<(Pubkey, u8)> {
+        let (automation_job, _) = Self::automation_job(job_id)?;
+        Pubkey::try_find_program_address(
+            &[b"sponsorship", automation_job.as_ref(), sponsor.as_ref()],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: format!("Failed to derive sponsorship PDA for job {} sponsor {}", job_id, sponsor),
+        })
+    }
+
+    /// Derive a funding pool PDA
+    /// 
+    /// # Arguments
+    /// * `owner` - The pool owner's public key
+    /// * `pool_id` - Owner-chosen pool identifier
+    pub fn funding_pool(owner: &Pubkey, pool_id: u64) -> SolCronResult<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &[b"funding_pool", owner.as_ref(), &pool_id.to_le_bytes()],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: format!("Failed to derive funding pool PDA for owner {} pool {}", owner, pool_id),
+        })
+    }
+
     /// Derive a keeper account PDA
     /// 
     /// # Arguments
@@ -48,6 +90,21 @@ impl Accounts {
         })
     }
 
+    /// Derive a delegation account PDA
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - The keeper's public key
+    /// * `delegator` - The delegator's public key
+    pub fn delegation(keeper_address: &Pubkey, delegator: &Pubkey) -> SolCronResult<(Pubkey, u8)> {
+        let (keeper, _) = Self::keeper(keeper_address)?;
+        Pubkey::try_find_program_address(
+            &[b"delegation", keeper.as_ref(), delegator.as_ref()],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: format!("Failed to derive delegation PDA for delegator: {}", delegator),
+        })
+    }
+
     /// Derive an execution record account PDA
     /// 
     /// # Arguments
@@ -70,11 +127,71 @@ impl Accounts {
         })
     }
 
-    /// Derive the execution authority PDA for the execution engine
+    /// Derive the pending registry parameters PDA
+    pub fn pending_params() -> SolCronResult<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &[b"pending_params"],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: "Failed to derive pending params PDA".to_string(),
+        })
+    }
+
+    /// Derive the pending slash account PDA for a keeper
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - The keeper's wallet address
+    pub fn pending_slash(keeper_address: &Pubkey) -> SolCronResult<(Pubkey, u8)> {
+        let (keeper, _) = Self::keeper(keeper_address)?;
+        Pubkey::try_find_program_address(
+            &[b"pending_slash", keeper.as_ref()],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: format!("Failed to derive pending slash PDA for keeper: {}", keeper_address),
+        })
+    }
+
+    /// Derive the insurance pool PDA
+    pub fn insurance_pool() -> SolCronResult<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &[b"insurance_pool"],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: "Failed to derive insurance pool PDA".to_string(),
+        })
+    }
+
+    /// Derive the record PDA of an applied slash
+    /// 
+    /// # Arguments
+    /// * `slash_id` - Sequential slash ID (the registry's slash count when applied)
+    pub fn slash_record(slash_id: u64) -> SolCronResult<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &[b"slash_record", &slash_id.to_le_bytes()],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: format!("Failed to derive slash record PDA for slash: {}", slash_id),
+        })
+    }
+
+    /// Derive the insurance claim PDA for an execution record
+    /// 
+    /// # Arguments
+    /// * `execution_record` - The execution record the claim compensates
+    pub fn insurance_claim(execution_record: &Pubkey) -> SolCronResult<(Pubkey, u8)> {
+        Pubkey::try_find_program_address(
+            &[b"insurance_claim", execution_record.as_ref()],
+            &REGISTRY_PROGRAM_ID,
+        ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
+            reason: format!("Failed to derive insurance claim PDA for execution: {}", execution_record),
+        })
+    }
+
+    /// Derive the registry PDA that signs execution engine CPIs
     pub fn execution_authority() -> SolCronResult<(Pubkey, u8)> {
         Pubkey::try_find_program_address(
             &[b"execution_authority"],
-            &crate::EXECUTION_PROGRAM_ID,
+            &REGISTRY_PROGRAM_ID,
         ).ok_or_else(|| crate::error::SolCronError::PDADerivationError {
             reason: "Failed to derive execution authority PDA".to_string(),
         })
@@ -93,10 +210,12 @@ impl Accounts {
     ) -> SolCronResult<JobRegistrationAccounts> {
         let (registry_state, _) = Self::registry_state()?;
         let (automation_job, _) = Self::automation_job(job_id)?;
+        let (job_metadata, _) = Self::job_metadata(job_id)?;
 
         Ok(JobRegistrationAccounts {
             registry_state,
             automation_job,
+            job_metadata,
             owner: *owner,
             system_program: solana_program::system_program::ID,
         })
@@ -140,6 +259,8 @@ impl Accounts {
         let (automation_job, _) = Self::automation_job(job_id)?;
         let (keeper, _) = Self::keeper(keeper_address)?;
         let (execution_record, _) = Self::execution_record(job_id, execution_count)?;
+        let (execution_authority, _) = Self::execution_authority()?;
+        let (insurance_pool, _) = Self::insurance_pool()?;
 
         Ok(JobExecutionAccounts {
             registry_state,
@@ -148,7 +269,13 @@ impl Accounts {
             execution_record,
             keeper_account: *keeper_address,
             target_program: *target_program,
+            execution_authority,
+            execution_program: crate::EXECUTION_PROGRAM_ID,
+            insurance_pool,
             system_program: solana_program::system_program::ID,
+            instructions_sysvar: solana_program::sysvar::instructions::ID,
+            trigger_account: None,
+            funding_pool: None,
         })
     }
 
@@ -178,6 +305,7 @@ impl Accounts {
 pub struct JobRegistrationAccounts {
     pub registry_state: Pubkey,
     pub automation_job: Pubkey,
+    pub job_metadata: Pubkey,
     pub owner: Pubkey,
     pub system_program: Pubkey,
 }
@@ -200,7 +328,13 @@ pub struct JobExecutionAccounts {
     pub execution_record: Pubkey,
     pub keeper_account: Pubkey,
     pub target_program: Pubkey,
+    pub execution_authority: Pubkey,
+    pub execution_program: Pubkey,
+    pub insurance_pool: Pubkey,
     pub system_program: Pubkey,
+    pub instructions_sysvar: Pubkey,
+    pub trigger_account: Option<Pubkey>,
+    pub funding_pool: Option<Pubkey>,
 }
 
 /// Account addresses required for reward claiming
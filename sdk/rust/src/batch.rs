@@ -325,6 +325,12 @@ impl BatchOperations {
         for (_, job) in jobs_result.successful {
             report.total_jobs += 1;
             report.total_balance += job.balance;
+
+            if let Some(metadata) = self.client.get_job_metadata(job.job_id).await? {
+                if !metadata.name.is_empty() {
+                    report.job_names.insert(job.job_id, metadata.name);
+                }
+            }
             
             // Analyze job health
             if job.balance < job.min_balance * 2 {
@@ -444,6 +450,8 @@ pub struct BatchAnalysisReport {
     pub inactive_jobs: Vec<u64>,
     pub execution_stats: HashMap<u64, JobExecutionStats>,
     pub recommendations: Vec<OptimizationRecommendation>,
+    /// Names of analyzed jobs that have metadata
+    pub job_names: HashMap<u64, String>,
 }
 
 impl BatchAnalysisReport {
@@ -455,6 +463,7 @@ impl BatchAnalysisReport {
             inactive_jobs: Vec::new(),
             execution_stats: HashMap::new(),
             recommendations: Vec::new(),
+            job_names: HashMap::new(),
         }
     }
     
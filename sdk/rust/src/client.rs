@@ -7,9 +7,12 @@
 #[cfg(feature = "client")]
 use {
     anchor_client::{Client, Cluster, Program},
-    solana_client::rpc_client::RpcClient,
+    anchor_lang::AnchorDeserialize,
+    base64::Engine,
+    solana_client::{rpc_client::RpcClient, rpc_config::RpcSimulateTransactionConfig},
     solana_sdk::{
         commitment_config::CommitmentConfig,
+        instruction::{AccountMeta, Instruction},
         pubkey::Pubkey,
         signature::{Keypair, Signature},
         signer::Signer,
@@ -139,6 +142,7 @@ impl SolCronClient {
     ///         trigger_params: vec![],
     ///         gas_limit: 200_000,
     ///         min_balance: 1_000_000,
+    ///         metadata: None,
     ///     };
     ///     
     ///     let job_id = client.register_job(&job_params, 100_000_000, &owner).await?;
@@ -166,17 +170,12 @@ impl SolCronClient {
             .accounts(crate::accounts::RegisterJob {
                 registry_state: accounts.registry_state,
                 automation_job: accounts.automation_job,
+                job_metadata: job_params.metadata.as_ref().map(|_| accounts.job_metadata),
                 owner: accounts.owner,
                 system_program: accounts.system_program,
             })
             .args(crate::instruction::RegisterJob {
-                target_program: job_params.target_program,
-                target_instruction: job_params.target_instruction.clone(),
-                trigger_type: job_params.trigger_type.clone(),
-                trigger_params: job_params.trigger_params.clone(),
-                gas_limit: job_params.gas_limit,
-                min_balance: job_params.min_balance,
-                initial_funding,
+                registration: job_params.registration(initial_funding),
             })
             .signer(owner)
             .send()
@@ -206,12 +205,44 @@ impl SolCronClient {
             .map_err(|e| SolCronError::JobNotFound { job_id })
     }
 
+    /// Get a job's name, tags and metadata URI, if any were set
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    pub async fn get_job_metadata(&self, job_id: u64) -> SolCronResult<Option<JobMetadata>> {
+        let (metadata_address, _) = Accounts::job_metadata(job_id)?;
+
+        Ok(self.program
+            .account::<JobMetadata>(metadata_address)
+            .await
+            .ok())
+    }
+
+    /// Get the metadata of every job carrying `tag`
+    /// 
+    /// # Arguments
+    /// * `tag` - Tag to filter by
+    pub async fn get_jobs_by_tag(&self, tag: &str) -> SolCronResult<Vec<JobMetadata>> {
+        let accounts = self.program
+            .accounts::<JobMetadata>(vec![])
+            .await
+            .map_err(|e| SolCronError::NetworkError {
+                reason: format!("Failed to fetch job metadata: {}", e),
+            })?;
+
+        Ok(accounts
+            .into_iter()
+            .map(|(_, metadata)| metadata)
+            .filter(|metadata| metadata.has_tag(tag))
+            .collect())
+    }
+
     /// Fund an existing job
     /// 
     /// # Arguments
     /// * `job_id` - Job identifier
     /// * `amount` - Additional funding amount in lamports
-    /// * `funder` - Account providing the funding
+    /// * `funder` - Job owner keypair
     pub async fn fund_job(
         &self,
         job_id: u64,
@@ -224,6 +255,7 @@ impl SolCronClient {
             .request()
             .accounts(crate::accounts::FundJob {
                 automation_job: job_address,
+                sponsorship: None,
                 funder: funder.pubkey(),
                 system_program: solana_sdk::system_program::ID,
             })
@@ -238,35 +270,64 @@ impl SolCronClient {
         self.confirm_transaction(tx).await
     }
 
-    /// Update job parameters
+    /// Sponsor a job from any wallet; the unspent share can be withdrawn later
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `amount` - Sponsorship amount in lamports
+    /// * `sponsor` - Sponsor keypair
+    pub async fn sponsor_job(
+        &self,
+        job_id: u64,
+        amount: u64,
+        sponsor: &Keypair,
+    ) -> SolCronResult<Signature> {
+        let (job_address, _) = Accounts::automation_job(job_id)?;
+        let (sponsorship, _) = Accounts::sponsorship(job_id, &sponsor.pubkey())?;
+
+        let tx = self.program
+            .request()
+            .accounts(crate::accounts::FundJob {
+                automation_job: job_address,
+                sponsorship: Some(sponsorship),
+                funder: sponsor.pubkey(),
+                system_program: solana_sdk::system_program::ID,
+            })
+            .args(crate::instruction::FundJob { amount })
+            .signer(sponsor)
+            .send()
+            .await
+            .map_err(|e| SolCronError::TransactionExecutionError {
+                reason: format!("Failed to sponsor job: {}", e),
+            })?;
+
+        self.confirm_transaction(tx).await
+    }
+
+    /// Update job configuration
     /// 
     /// # Arguments
     /// * `job_id` - Job identifier
-    /// * `gas_limit` - New gas limit (optional)
-    /// * `min_balance` - New minimum balance (optional)
-    /// * `trigger_params` - New trigger parameters (optional)
+    /// * `update` - Fields to change; unset fields are left unchanged
     /// * `owner` - Job owner keypair
     pub async fn update_job(
         &self,
         job_id: u64,
-        gas_limit: Option<u64>,
-        min_balance: Option<u64>,
-        trigger_params: Option<Vec<u8>>,
+        update: JobUpdate,
         owner: &Keypair,
     ) -> SolCronResult<Signature> {
         let (job_address, _) = Accounts::automation_job(job_id)?;
+        let (metadata_address, _) = Accounts::job_metadata(job_id)?;
 
         let tx = self.program
             .request()
             .accounts(crate::accounts::UpdateJob {
                 automation_job: job_address,
+                job_metadata: update.metadata.as_ref().map(|_| metadata_address),
                 owner: owner.pubkey(),
+                system_program: solana_sdk::system_program::ID,
             })
-            .args(crate::instruction::UpdateJob {
-                gas_limit,
-                min_balance,
-                trigger_params,
-            })
+            .args(crate::instruction::UpdateJob { update })
             .signer(owner)
             .send()
             .await
@@ -277,7 +338,63 @@ impl SolCronClient {
         self.confirm_transaction(tx).await
     }
 
-    /// Cancel a job and refund remaining balance
+    /// Pause a job's executions, keeping its balance and history
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `owner` - Job owner keypair
+    pub async fn pause_job(&self, job_id: u64, owner: &Keypair) -> SolCronResult<Signature> {
+        let (job_address, _) = Accounts::automation_job(job_id)?;
+
+        let tx = self.program
+            .request()
+            .accounts(crate::accounts::PauseJob {
+                automation_job: job_address,
+                owner: owner.pubkey(),
+            })
+            .args(crate::instruction::PauseJob)
+            .signer(owner)
+            .send()
+            .await
+            .map_err(|e| SolCronError::TransactionExecutionError {
+                reason: format!("Failed to pause job: {}", e),
+            })?;
+
+        self.confirm_transaction(tx).await
+    }
+
+    /// Resume a job paused by its owner
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `owner` - Job owner keypair
+    /// * `reset_last_execution` - Restart the schedule from now instead of running overdue executions
+    pub async fn resume_job(
+        &self,
+        job_id: u64,
+        owner: &Keypair,
+        reset_last_execution: bool,
+    ) -> SolCronResult<Signature> {
+        let (job_address, _) = Accounts::automation_job(job_id)?;
+
+        let tx = self.program
+            .request()
+            .accounts(crate::accounts::ResumeJob {
+                automation_job: job_address,
+                owner: owner.pubkey(),
+            })
+            .args(crate::instruction::ResumeJob { reset_last_execution })
+            .signer(owner)
+            .send()
+            .await
+            .map_err(|e| SolCronError::TransactionExecutionError {
+                reason: format!("Failed to resume job: {}", e),
+            })?;
+
+        self.confirm_transaction(tx).await
+    }
+
+    /// Cancel a job and refund the owner's share of the remaining balance
     /// 
     /// # Arguments
     /// * `job_id` - Job identifier
@@ -286,6 +403,9 @@ impl SolCronClient {
         let (registry_state, _) = Accounts::registry_state()?;
         let (job_address, _) = Accounts::automation_job(job_id)?;
 
+        // Refund every sponsor along with the owner
+        let remaining_accounts = self.sponsorship_accounts(job_id).await?;
+
         let tx = self.program
             .request()
             .accounts(crate::accounts::CancelJob {
@@ -294,6 +414,7 @@ impl SolCronClient {
                 owner: owner.pubkey(),
                 system_program: solana_sdk::system_program::ID,
             })
+            .accounts(remaining_accounts)
             .args(crate::instruction::CancelJob)
             .signer(owner)
             .send()
@@ -305,6 +426,85 @@ impl SolCronClient {
         self.confirm_transaction(tx).await
     }
 
+    /// Withdraw a sponsor's unspent share of a job balance
+    /// 
+    /// # Arguments
+    /// * `job_id` - Sponsored job identifier
+    /// * `sponsor` - Sponsor keypair
+    pub async fn withdraw_sponsorship(&self, job_id: u64, sponsor: &Keypair) -> SolCronResult<Signature> {
+        let (job_address, _) = Accounts::automation_job(job_id)?;
+        let (sponsorship, _) = Accounts::sponsorship(job_id, &sponsor.pubkey())?;
+
+        let tx = self.program
+            .request()
+            .accounts(crate::accounts::WithdrawSponsorship {
+                automation_job: job_address,
+                sponsorship,
+                sponsor: sponsor.pubkey(),
+            })
+            .args(crate::instruction::WithdrawSponsorship)
+            .signer(sponsor)
+            .send()
+            .await
+            .map_err(|e| SolCronError::TransactionExecutionError {
+                reason: format!("Failed to withdraw sponsorship: {}", e),
+            })?;
+
+        self.confirm_transaction(tx).await
+    }
+
+    /// Reap an expired, or drained and long inactive, job for a share of its rent
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `reaper` - Caller keypair, which receives the bounty
+    pub async fn reap_job(&self, job_id: u64, reaper: &Keypair) -> SolCronResult<Signature> {
+        let job = self.get_job(job_id).await?;
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (job_address, _) = Accounts::automation_job(job_id)?;
+        let (job_metadata, _) = Accounts::job_metadata(job_id)?;
+
+        // Sponsors are refunded by the reap, so every sponsorship is passed along
+        let remaining_accounts = self.sponsorship_accounts(job_id).await?;
+
+        let tx = self.program
+            .request()
+            .accounts(crate::accounts::ReapJob {
+                registry_state,
+                automation_job: job_address,
+                job_metadata,
+                owner: job.owner,
+                reaper: reaper.pubkey(),
+            })
+            .accounts(remaining_accounts)
+            .args(crate::instruction::ReapJob)
+            .signer(reaper)
+            .send()
+            .await
+            .map_err(|e| SolCronError::TransactionExecutionError {
+                reason: format!("Failed to reap job: {}", e),
+            })?;
+
+        self.confirm_transaction(tx).await
+    }
+
+    /// Each of a job's sponsorships followed by its sponsor's wallet, as passed
+    /// to the instructions that refund sponsors
+    async fn sponsorship_accounts(&self, job_id: u64) -> SolCronResult<Vec<AccountMeta>> {
+        let sponsorships = self.program
+            .accounts::<Sponsorship>(vec![])
+            .await
+            .map_err(|e| SolCronError::NetworkError {
+                reason: format!("Failed to fetch sponsorships: {}", e),
+            })?;
+        let mut accounts = Vec::new();
+        for (address, sponsorship) in sponsorships.into_iter().filter(|(_, s)| s.job_id == job_id) {
+            accounts.push(AccountMeta::new(address, false));
+            accounts.push(AccountMeta::new(sponsorship.sponsor, false));
+        }
+        Ok(accounts)
+    }
+
     /// Register as a keeper
     /// 
     /// # Arguments
@@ -382,16 +582,33 @@ impl SolCronClient {
     /// * `job_id` - Job identifier
     /// * `keeper` - Keeper keypair
     pub async fn execute_job(&self, job_id: u64, keeper: &Keypair) -> SolCronResult<ExecutionResult> {
+        self.execute_job_with_perform_data(job_id, Vec::new(), keeper).await
+    }
+
+    /// Execute a job with the perform data returned by its check instruction
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `perform_data` - Data appended to the target instruction
+    /// * `keeper` - Keeper keypair
+    pub async fn execute_job_with_perform_data(
+        &self,
+        job_id: u64,
+        perform_data: Vec<u8>,
+        keeper: &Keypair,
+    ) -> SolCronResult<ExecutionResult> {
         // Get job info to determine execution count and target program
         let job = self.get_job(job_id).await?;
         let execution_count = job.execution_count;
 
-        let accounts = Accounts::job_execution_accounts(
+        let mut accounts = Accounts::job_execution_accounts(
             job_id,
             &keeper.pubkey(),
             &job.target_program,
             execution_count,
         )?;
+        accounts.trigger_account = job.trigger_account();
+        accounts.funding_pool = job.funding_pool;
 
         let tx = self.program
             .request()
@@ -402,9 +619,15 @@ impl SolCronClient {
                 execution_record: accounts.execution_record,
                 keeper_account: accounts.keeper_account,
                 target_program: accounts.target_program,
+                execution_authority: accounts.execution_authority,
+                execution_program: accounts.execution_program,
+                insurance_pool: accounts.insurance_pool,
                 system_program: accounts.system_program,
+                instructions_sysvar: accounts.instructions_sysvar,
+                trigger_account: accounts.trigger_account,
+                funding_pool: accounts.funding_pool,
             })
-            .args(crate::instruction::ExecuteJob { job_id })
+            .args(crate::instruction::ExecuteJob { job_id, perform_data })
             .signer(keeper)
             .send()
             .await
@@ -415,6 +638,13 @@ impl SolCronClient {
 
         let signature = self.confirm_transaction(tx).await?;
 
+        // The registry keeps the target's bounded return data on the execution record
+        let return_data = self.program
+            .account::<ExecutionRecord>(accounts.execution_record)
+            .await
+            .map(|record| record.return_data)
+            .unwrap_or_default();
+
         // Return execution result
         // Note: In a real implementation, you might parse transaction logs
         // to get the actual execution details
@@ -425,9 +655,51 @@ impl SolCronClient {
             fee_charged: 0, // Would be extracted from logs
             execution_time: chrono::Utc::now().timestamp() as u64,
             error: None,
+            return_data,
         })
     }
 
+    /// Get whether a job is due and when it next runs
+    /// 
+    /// Simulates the registry's `job_status` view, so the answer comes from the
+    /// same logic `execute_job` enforces.
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `keeper_address` - Keeper that would execute the job, for jobs requiring a keeper tier
+    pub async fn job_status(&self, job_id: u64, keeper_address: Option<Pubkey>) -> SolCronResult<JobStatus> {
+        let job = self.get_job(job_id).await?;
+
+        // Price thresholds read their price account from the remaining accounts
+        let remaining_accounts = match &job.trigger_type {
+            TriggerType::PriceThreshold { price_account, .. } => {
+                vec![AccountMeta::new_readonly(*price_account, false)]
+            }
+            _ => Vec::new(),
+        };
+
+        let instruction = Instructions::job_status(
+            job_id,
+            job.trigger_account(),
+            job.funding_pool,
+            keeper_address,
+            remaining_accounts,
+        )?;
+        self.simulate_view(instruction).await
+    }
+
+    /// Quote a job's next execution fee and keeper reward
+    /// 
+    /// Simulates the registry's `quote_execution` view.
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    pub async fn quote_execution(&self, job_id: u64) -> SolCronResult<ExecutionQuote> {
+        let job = self.get_job(job_id).await?;
+        let instruction = Instructions::quote_execution(job_id, job.funding_pool)?;
+        self.simulate_view(instruction).await
+    }
+
     /// Get job statistics
     pub async fn get_job_stats(&self, job_id: u64) -> SolCronResult<JobStats> {
         let job = self.get_job(job_id).await?;
@@ -455,6 +727,7 @@ impl SolCronClient {
             failed_executions: keeper.failed_executions,
             success_rate: keeper.success_rate() / 100.0,
             total_earnings: keeper.total_earnings,
+            total_reimbursed: keeper.total_reimbursed,
             reputation_score: keeper.reputation_score,
             stake_amount: keeper.stake_amount,
             is_active: keeper.is_active,
@@ -495,6 +768,48 @@ impl SolCronClient {
         Ok(vec![])
     }
 
+    /// Simulate a read-only registry instruction and decode its return data
+    async fn simulate_view<T: AnchorDeserialize>(&self, instruction: Instruction) -> SolCronResult<T> {
+        let payer = self.program.payer();
+        let transaction = Transaction::new_with_payer(&[instruction], Some(&payer));
+
+        let simulation = self.rpc_client
+            .simulate_transaction_with_config(
+                &transaction,
+                RpcSimulateTransactionConfig {
+                    sig_verify: false,
+                    replace_recent_blockhash: true,
+                    commitment: Some(self.commitment),
+                    ..Default::default()
+                },
+            )
+            .map_err(|e| SolCronError::TransactionExecutionError {
+                reason: format!("Failed to simulate view: {}", e),
+            })?
+            .value;
+
+        if let Some(err) = simulation.err {
+            return Err(SolCronError::TransactionExecutionError {
+                reason: format!("View simulation failed: {:?}", err),
+            });
+        }
+
+        let return_data = simulation.return_data
+            .filter(|return_data| return_data.program_id == crate::REGISTRY_PROGRAM_ID.to_string())
+            .ok_or_else(|| SolCronError::DeserializationError {
+                reason: "View returned no data".to_string(),
+            })?;
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(&return_data.data.0)
+            .map_err(|e| SolCronError::DeserializationError {
+                reason: format!("Invalid view return data: {}", e),
+            })?;
+
+        T::try_from_slice(&bytes).map_err(|e| SolCronError::DeserializationError {
+            reason: format!("Failed to decode view return data: {}", e),
+        })
+    }
+
     /// Wait for transaction confirmation with retry logic
     async fn confirm_transaction(&self, signature: Signature) -> SolCronResult<Signature> {
         const MAX_RETRIES: usize = 30;
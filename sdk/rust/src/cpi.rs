@@ -49,6 +49,7 @@ impl CPI {
     ///         trigger_params: vec![],
     ///         gas_limit: 200_000,
     ///         min_balance: 1_000_000,
+    ///         metadata: None,
     ///     };
     ///     
     ///     CPI::register_job(
@@ -85,13 +86,10 @@ impl CPI {
 
         // Build instruction data
         let data = RegisterJobData {
-            target_program: job_params.target_program,
-            target_instruction: job_params.target_instruction,
-            trigger_type: job_params.trigger_type,
-            trigger_params: job_params.trigger_params,
-            gas_limit: job_params.gas_limit,
-            min_balance: job_params.min_balance,
-            initial_funding,
+            registration: JobRegistration {
+                metadata: None, // The metadata account isn't passed; set it with the client
+                ..job_params.registration(initial_funding)
+            },
         };
 
         let instruction_data = InstructionData::RegisterJob(data).try_to_vec()?;
@@ -101,6 +99,7 @@ impl CPI {
             accounts: vec![
                 AccountMeta::new(*registry_state_info.key, false),
                 AccountMeta::new(*job_info.key, false),
+                AccountMeta::new_readonly(*program_info.key, false), // No metadata
                 AccountMeta::new(*owner_info.key, true),
                 AccountMeta::new_readonly(*system_program_info.key, false),
             ],
@@ -129,7 +128,7 @@ impl CPI {
     /// # Arguments
     /// * `program_info` - SolCron registry program account
     /// * `job_info` - Job account to fund
-    /// * `funder_info` - Account providing the funding
+    /// * `funder_info` - Job owner providing the funding
     /// * `system_program_info` - System program account
     /// * `amount` - Amount to fund (lamports)
     /// * `signer_seeds` - Optional seeds for PDA signing
@@ -155,6 +154,7 @@ impl CPI {
             program_id: REGISTRY_PROGRAM_ID,
             accounts: vec![
                 AccountMeta::new(*job_info.key, false),
+                AccountMeta::new_readonly(*program_info.key, false), // No sponsorship
                 AccountMeta::new(*funder_info.key, true),
                 AccountMeta::new_readonly(*system_program_info.key, false),
             ],
@@ -177,23 +177,22 @@ impl CPI {
         Ok(())
     }
 
-    /// Update job parameters via CPI
+    /// Update job configuration via CPI
     /// 
     /// # Arguments
     /// * `program_info` - SolCron registry program account
     /// * `job_info` - Job account to update
     /// * `owner_info` - Job owner account
-    /// * `gas_limit` - New gas limit (optional)
-    /// * `min_balance` - New minimum balance (optional)
-    /// * `trigger_params` - New trigger parameters (optional)
+    /// * `system_program_info` - System program account
+    /// * `update` - Fields to change; unset fields are left unchanged. Metadata
+    ///   can't be changed via CPI as its account isn't passed.
     /// * `signer_seeds` - Optional seeds for PDA signing
     pub fn update_job<'info>(
         program_info: &AccountInfo<'info>,
         job_info: &AccountInfo<'info>,
         owner_info: &AccountInfo<'info>,
-        gas_limit: Option<u64>,
-        min_balance: Option<u64>,
-        trigger_params: Option<Vec<u8>>,
+        system_program_info: &AccountInfo<'info>,
+        update: JobUpdate,
         signer_seeds: Option<&[&[&[u8]]]>,
     ) -> SolCronResult<()> {
         if *program_info.key != REGISTRY_PROGRAM_ID {
@@ -203,18 +202,16 @@ impl CPI {
             });
         }
 
-        let data = UpdateJobData {
-            gas_limit,
-            min_balance,
-            trigger_params,
-        };
+        let data = UpdateJobData { update };
         let instruction_data = InstructionData::UpdateJob(data).try_to_vec()?;
 
         let instruction = Instruction {
             program_id: REGISTRY_PROGRAM_ID,
             accounts: vec![
                 AccountMeta::new(*job_info.key, false),
-                AccountMeta::new_readonly(*owner_info.key, true),
+                AccountMeta::new_readonly(*program_info.key, false), // No metadata
+                AccountMeta::new(*owner_info.key, true),
+                AccountMeta::new_readonly(*system_program_info.key, false),
             ],
             data: instruction_data,
         };
@@ -223,6 +220,7 @@ impl CPI {
             program_info.clone(),
             job_info.clone(),
             owner_info.clone(),
+            system_program_info.clone(),
         ];
 
         if let Some(seeds) = signer_seeds {
@@ -442,11 +440,9 @@ impl CPIValidation {
 
         // Check trigger conditions
         match &job.trigger_type {
-            TriggerType::TimeBased { interval } => {
-                if job.last_execution == 0 {
-                    return Ok(true); // First execution
-                }
-                Ok(current_time >= job.last_execution + interval)
+            TriggerType::TimeBased { .. } => {
+                // Anchored schedule; SkipMissed jobs may still reject runs past their grace window
+                Ok(current_time >= job.next_scheduled_time)
             }
             TriggerType::Conditional { .. } => {
                 // For conditional triggers, the caller needs to implement
@@ -462,11 +458,26 @@ impl CPIValidation {
                 // For hybrid triggers, more complex logic is needed
                 Ok(true)
             }
+            TriggerType::SlotInterval { .. } | TriggerType::EpochStart { .. } => {
+                // Slot and epoch triggers are checked against the cluster clock,
+                // which the caller needs to compare with next_scheduled_slot/next_epoch
+                Ok(true)
+            }
+            TriggerType::AccountChange { .. } => {
+                // The caller needs to compare the watched account's data hash
+                // with last_data_hash
+                Ok(true)
+            }
+            TriggerType::PriceThreshold { .. } => {
+                // The caller needs to read the price account and compare
+                // its aggregate price with the threshold
+                Ok(true)
+            }
         }
     }
 }
 
 // Re-export commonly used CPI types
-pub use crate::types::{TriggerType, JobParams, TriggerCondition};
+pub use crate::types::{TriggerType, JobParams, TriggerCondition, JobUpdate, JobAccountMeta, PriceComparison};
 pub use crate::accounts::{Accounts, JobRegistrationAccounts, KeeperRegistrationAccounts};
 pub use crate::instructions::{RegisterJobData, FundJobData, UpdateJobData, RegisterKeeperData};
\ No newline at end of file
@@ -211,42 +211,99 @@ impl From<ProgramError> for SolCronError {
 /// Specialized result type for SolCron operations
 pub type SolCronResult<T> = Result<T, SolCronError>;
 
-/// Error codes that match the on-chain program errors
+/// Offset Anchor applies to custom program error codes
+pub const ANCHOR_ERROR_CODE_OFFSET: u32 = 6000;
+
+/// Error codes that match the on-chain registry program errors
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 #[repr(u32)]
 pub enum SolCronErrorCode {
-    /// Invalid instruction
-    InvalidInstruction = 0,
-    /// Insufficient balance
-    InsufficientBalance = 1,
+    /// Unauthorized
+    Unauthorized = 6000,
     /// Invalid job
-    InvalidJob = 2,
-    /// Job not active
-    JobNotActive = 3,
-    /// Keeper not found
-    KeeperNotFound = 4,
+    InvalidJob = 6001,
+    /// Invalid keeper
+    InvalidKeeper = 6002,
+    /// Insufficient balance
+    InsufficientBalance = 6003,
     /// Insufficient stake
-    InsufficientStake = 5,
+    InsufficientStake = 6004,
     /// Invalid trigger
-    InvalidTrigger = 6,
+    InvalidTrigger = 6005,
+    /// Gas limit exceeded
+    GasLimitExceeded = 6006,
     /// Execution failed
-    ExecutionFailed = 7,
-    /// Unauthorized
-    Unauthorized = 8,
-    /// Invalid program ID
-    InvalidProgramId = 9,
-    /// Account already exists
-    AccountAlreadyExists = 10,
+    ExecutionFailed = 6007,
+    /// Invalid parameters
+    InvalidParameters = 6008,
+    /// Job already exists
+    JobAlreadyExists = 6009,
+    /// Keeper already registered
+    KeeperAlreadyRegistered = 6010,
+    /// Cooldown period active
+    CooldownPeriod = 6011,
+    /// Rate limit exceeded
+    RateLimitExceeded = 6012,
+    /// Invalid fee
+    InvalidFee = 6013,
+    /// Slashing failed
+    SlashingFailed = 6014,
     /// No rewards to claim
-    NoRewardsToClaim = 11,
+    NoRewardsToClaim = 6015,
+    /// Execution too early
+    ExecutionTooEarly = 6016,
+    /// Target program error
+    TargetProgramError = 6017,
     /// Math overflow
-    MathOverflow = 12,
-    /// Invalid fee rate
-    InvalidFeeRate = 13,
-    /// Keeper already registered
-    KeeperAlreadyRegistered = 14,
-    /// Registry not initialized
-    RegistryNotInitialized = 15,
+    MathOverflow = 6018,
+    /// Invalid instruction data
+    InvalidInstructionData = 6019,
+    /// Job paused after consecutive failures
+    JobPaused = 6020,
+    /// Account must be migrated to the current layout
+    AccountNotMigrated = 6021,
+    /// Account already uses the current layout
+    AccountAlreadyMigrated = 6022,
+    /// Registry is paused
+    RegistryPaused = 6023,
+    /// Parameter change timelock has not expired
+    TimelockNotExpired = 6024,
+    /// Trigger account missing or does not match the job's trigger
+    InvalidTriggerAccount = 6025,
+    /// Price account is not a Pyth price account
+    InvalidPriceAccount = 6026,
+    /// Price is not trading or older than the max staleness
+    StalePrice = 6027,
+    /// Execution fee is above the job's max fee per execution
+    FeeExceedsCap = 6028,
+    /// Slash cannot be finalized until its dispute window closes
+    DisputeWindowOpen = 6029,
+    /// Slash can no longer be appealed
+    DisputeWindowClosed = 6030,
+    /// Appealed slash must be resolved by the admin
+    SlashUnderAppeal = 6031,
+    /// Delegation cannot be withdrawn until the unbonding period ends
+    UnbondingInProgress = 6032,
+    /// Not enough bonded or unbonding delegation shares
+    InsufficientDelegation = 6033,
+    /// Funding pool missing or not the pool the job draws from
+    InvalidFundingPool = 6034,
+    /// Job has used up its funding pool allowance
+    PoolSpendCapExceeded = 6035,
+    /// Metadata too long or its account missing
+    InvalidMetadata = 6036,
+    /// Job requires a higher keeper tier
+    KeeperTierTooLow = 6037,
+    /// Job is past its end time
+    JobExpired = 6038,
+    /// Job is neither expired nor drained and inactive long enough, or has sponsors
+    JobNotReapable = 6039,
+    /// Target accounts cannot be signers or registry PDAs
+    InvalidTargetAccount = 6040,
+    /// The slash has already backed an insurance payout
+    SlashAlreadyClaimed = 6041,
+    /// The payout is larger than the slash's insurance share
+    ClaimExceedsSlash = 6042,
 }
 
 impl SolCronErrorCode {
@@ -254,48 +311,112 @@ impl SolCronErrorCode {
         self as u32
     }
 
+    /// Map an `ExecutionRecord.error_code` or transaction custom error code
     pub fn from_u32(code: u32) -> Option<Self> {
         match code {
-            0 => Some(Self::InvalidInstruction),
-            1 => Some(Self::InsufficientBalance),
-            2 => Some(Self::InvalidJob),
-            3 => Some(Self::JobNotActive),
-            4 => Some(Self::KeeperNotFound),
-            5 => Some(Self::InsufficientStake),
-            6 => Some(Self::InvalidTrigger),
-            7 => Some(Self::ExecutionFailed),
-            8 => Some(Self::Unauthorized),
-            9 => Some(Self::InvalidProgramId),
-            10 => Some(Self::AccountAlreadyExists),
-            11 => Some(Self::NoRewardsToClaim),
-            12 => Some(Self::MathOverflow),
-            13 => Some(Self::InvalidFeeRate),
-            14 => Some(Self::KeeperAlreadyRegistered),
-            15 => Some(Self::RegistryNotInitialized),
+            6000 => Some(Self::Unauthorized),
+            6001 => Some(Self::InvalidJob),
+            6002 => Some(Self::InvalidKeeper),
+            6003 => Some(Self::InsufficientBalance),
+            6004 => Some(Self::InsufficientStake),
+            6005 => Some(Self::InvalidTrigger),
+            6006 => Some(Self::GasLimitExceeded),
+            6007 => Some(Self::ExecutionFailed),
+            6008 => Some(Self::InvalidParameters),
+            6009 => Some(Self::JobAlreadyExists),
+            6010 => Some(Self::KeeperAlreadyRegistered),
+            6011 => Some(Self::CooldownPeriod),
+            6012 => Some(Self::RateLimitExceeded),
+            6013 => Some(Self::InvalidFee),
+            6014 => Some(Self::SlashingFailed),
+            6015 => Some(Self::NoRewardsToClaim),
+            6016 => Some(Self::ExecutionTooEarly),
+            6017 => Some(Self::TargetProgramError),
+            6018 => Some(Self::MathOverflow),
+            6019 => Some(Self::InvalidInstructionData),
+            6020 => Some(Self::JobPaused),
+            6021 => Some(Self::AccountNotMigrated),
+            6022 => Some(Self::AccountAlreadyMigrated),
+            6023 => Some(Self::RegistryPaused),
+            6024 => Some(Self::TimelockNotExpired),
+            6025 => Some(Self::InvalidTriggerAccount),
+            6026 => Some(Self::InvalidPriceAccount),
+            6027 => Some(Self::StalePrice),
+            6028 => Some(Self::FeeExceedsCap),
+            6029 => Some(Self::DisputeWindowOpen),
+            6030 => Some(Self::DisputeWindowClosed),
+            6031 => Some(Self::SlashUnderAppeal),
+            6032 => Some(Self::UnbondingInProgress),
+            6033 => Some(Self::InsufficientDelegation),
+            6034 => Some(Self::InvalidFundingPool),
+            6035 => Some(Self::PoolSpendCapExceeded),
+            6036 => Some(Self::InvalidMetadata),
+            6037 => Some(Self::KeeperTierTooLow),
+            6038 => Some(Self::JobExpired),
+            6039 => Some(Self::JobNotReapable),
+            6040 => Some(Self::InvalidTargetAccount),
+            6041 => Some(Self::SlashAlreadyClaimed),
+            6042 => Some(Self::ClaimExceedsSlash),
             _ => None,
         }
     }
+
+    /// Describe any recorded error code, including errors raised by target programs
+    pub fn describe(code: u32) -> String {
+        match Self::from_u32(code) {
+            Some(known) => known.to_string(),
+            None if code >= ANCHOR_ERROR_CODE_OFFSET => format!("Target program custom error {}", code),
+            None => format!("Builtin program error {}", code),
+        }
+    }
 }
 
 impl std::fmt::Display for SolCronErrorCode {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let message = match self {
-            Self::InvalidInstruction => "Invalid instruction",
-            Self::InsufficientBalance => "Insufficient balance",
+            Self::Unauthorized => "Unauthorized",
             Self::InvalidJob => "Invalid job",
-            Self::JobNotActive => "Job not active",
-            Self::KeeperNotFound => "Keeper not found",
+            Self::InvalidKeeper => "Invalid keeper",
+            Self::InsufficientBalance => "Insufficient balance",
             Self::InsufficientStake => "Insufficient stake",
             Self::InvalidTrigger => "Invalid trigger",
+            Self::GasLimitExceeded => "Gas limit exceeded",
             Self::ExecutionFailed => "Execution failed",
-            Self::Unauthorized => "Unauthorized",
-            Self::InvalidProgramId => "Invalid program ID",
-            Self::AccountAlreadyExists => "Account already exists",
+            Self::InvalidParameters => "Invalid parameters",
+            Self::JobAlreadyExists => "Job already exists",
+            Self::KeeperAlreadyRegistered => "Keeper already registered",
+            Self::CooldownPeriod => "Cooldown period active",
+            Self::RateLimitExceeded => "Rate limit exceeded",
+            Self::InvalidFee => "Invalid fee",
+            Self::SlashingFailed => "Slashing failed",
             Self::NoRewardsToClaim => "No rewards to claim",
+            Self::ExecutionTooEarly => "Execution too early",
+            Self::TargetProgramError => "Target program error",
             Self::MathOverflow => "Math overflow",
-            Self::InvalidFeeRate => "Invalid fee rate",
-            Self::KeeperAlreadyRegistered => "Keeper already registered",
-            Self::RegistryNotInitialized => "Registry not initialized",
+            Self::InvalidInstructionData => "Invalid instruction data",
+            Self::JobPaused => "Job paused after consecutive failures",
+            Self::AccountNotMigrated => "Account not migrated",
+            Self::AccountAlreadyMigrated => "Account already migrated",
+            Self::RegistryPaused => "Registry paused",
+            Self::TimelockNotExpired => "Timelock not expired",
+            Self::InvalidTriggerAccount => "Invalid trigger account",
+            Self::InvalidPriceAccount => "Invalid price account",
+            Self::StalePrice => "Stale price",
+            Self::FeeExceedsCap => "Fee exceeds cap",
+            Self::DisputeWindowOpen => "Dispute window open",
+            Self::DisputeWindowClosed => "Dispute window closed",
+            Self::SlashUnderAppeal => "Slash under appeal",
+            Self::UnbondingInProgress => "Unbonding in progress",
+            Self::InsufficientDelegation => "Insufficient delegation",
+            Self::InvalidFundingPool => "Invalid funding pool",
+            Self::PoolSpendCapExceeded => "Pool spend cap exceeded",
+            Self::InvalidMetadata => "Invalid metadata",
+            Self::KeeperTierTooLow => "Keeper tier too low",
+            Self::JobExpired => "Job expired",
+            Self::JobNotReapable => "Job not reapable",
+            Self::InvalidTargetAccount => "Invalid target account",
+            Self::SlashAlreadyClaimed => "Slash already claimed",
+            Self::ClaimExceedsSlash => "Claim exceeds slash",
         };
         write!(f, "{}", message)
     }
@@ -349,13 +470,13 @@ impl SolCronError {
         match self {
             Self::InsufficientBalance { .. } => Some(SolCronErrorCode::InsufficientBalance),
             Self::JobNotFound { .. } => Some(SolCronErrorCode::InvalidJob),
-            Self::JobNotActive { .. } => Some(SolCronErrorCode::JobNotActive),
-            Self::KeeperNotFound { .. } => Some(SolCronErrorCode::KeeperNotFound),
+            Self::JobNotActive { .. } => Some(SolCronErrorCode::InvalidJob),
+            Self::KeeperNotFound { .. } => Some(SolCronErrorCode::InvalidKeeper),
             Self::InsufficientStake { .. } => Some(SolCronErrorCode::InsufficientStake),
             Self::InvalidTrigger { .. } => Some(SolCronErrorCode::InvalidTrigger),
             Self::ExecutionFailed { .. } => Some(SolCronErrorCode::ExecutionFailed),
             Self::Unauthorized { .. } => Some(SolCronErrorCode::Unauthorized),
-            Self::InvalidProgramId { .. } => Some(SolCronErrorCode::InvalidProgramId),
+            Self::InvalidProgramId { .. } => Some(SolCronErrorCode::TargetProgramError),
             _ => None,
         }
     }
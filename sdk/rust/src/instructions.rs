@@ -73,18 +73,13 @@ impl Instructions {
         let accounts = vec![
             AccountMeta::new(accounts_info.registry_state, false),
             AccountMeta::new(accounts_info.automation_job, false),
+            job_metadata_meta(job_params.metadata.is_some(), accounts_info.job_metadata),
             AccountMeta::new(accounts_info.owner, true),
             AccountMeta::new_readonly(accounts_info.system_program, false),
         ];
 
         let data = RegisterJobData {
-            target_program: job_params.target_program,
-            target_instruction: job_params.target_instruction,
-            trigger_type: job_params.trigger_type,
-            trigger_params: job_params.trigger_params,
-            gas_limit: job_params.gas_limit,
-            min_balance: job_params.min_balance,
-            initial_funding,
+            registration: job_params.registration(initial_funding),
         };
 
         Ok(Instruction {
@@ -99,7 +94,7 @@ impl Instructions {
     /// # Arguments
     /// * `job_id` - Job identifier
     /// * `amount` - Funding amount (lamports)
-    /// * `funder` - Account funding the job
+    /// * `funder` - Job owner funding the job
     pub fn fund_job(
         job_id: u64,
         amount: u64,
@@ -109,6 +104,7 @@ impl Instructions {
 
         let accounts = vec![
             AccountMeta::new(automation_job, false),
+            AccountMeta::new_readonly(REGISTRY_PROGRAM_ID, false), // No sponsorship
             AccountMeta::new(funder, true),
             AccountMeta::new_readonly(system_program::ID, false),
         ];
@@ -122,33 +118,158 @@ impl Instructions {
         })
     }
 
-    /// Create an instruction to update job parameters
+    /// Create an instruction to sponsor a job, recording the sponsor's share of its balance
     /// 
     /// # Arguments
     /// * `job_id` - Job identifier
-    /// * `gas_limit` - New gas limit (optional)
-    /// * `min_balance` - New minimum balance (optional)
-    /// * `trigger_params` - New trigger parameters (optional)
+    /// * `amount` - Funding amount (lamports)
+    /// * `sponsor` - Any wallet funding the job
+    pub fn sponsor_job(
+        job_id: u64,
+        amount: u64,
+        sponsor: Pubkey,
+    ) -> SolCronResult<Instruction> {
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+        let (sponsorship, _) = Accounts::sponsorship(job_id, &sponsor)?;
+
+        let accounts = vec![
+            AccountMeta::new(automation_job, false),
+            AccountMeta::new(sponsorship, false),
+            AccountMeta::new(sponsor, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::FundJob(FundJobData { amount }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to create a funding pool
+    /// 
+    /// # Arguments
+    /// * `owner` - Pool owner, pays for the pool account
+    /// * `pool_id` - Owner-chosen pool identifier
+    /// * `min_balance` - Balance executions may not draw below (lamports)
+    pub fn create_funding_pool(
+        owner: Pubkey,
+        pool_id: u64,
+        min_balance: u64,
+    ) -> SolCronResult<Instruction> {
+        let (funding_pool, _) = Accounts::funding_pool(&owner, pool_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(funding_pool, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        let data = CreateFundingPoolData { pool_id, min_balance };
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::CreateFundingPool(data).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to fund a funding pool
+    /// 
+    /// # Arguments
+    /// * `owner` - Pool owner, who funds the pool
+    /// * `pool_id` - Pool identifier
+    /// * `amount` - Amount to add (lamports)
+    pub fn fund_pool(owner: Pubkey, pool_id: u64, amount: u64) -> SolCronResult<Instruction> {
+        let (funding_pool, _) = Accounts::funding_pool(&owner, pool_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(funding_pool, false),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::FundPool(FundJobData { amount }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to withdraw from a funding pool
+    /// 
+    /// # Arguments
+    /// * `owner` - Pool owner
+    /// * `pool_id` - Pool identifier
+    /// * `amount` - Amount to withdraw (lamports)
+    pub fn withdraw_pool(owner: Pubkey, pool_id: u64, amount: u64) -> SolCronResult<Instruction> {
+        let (funding_pool, _) = Accounts::funding_pool(&owner, pool_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(funding_pool, false),
+            AccountMeta::new(owner, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::WithdrawPool(FundJobData { amount }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to pay a job's fees from a funding pool
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `funding_pool` - Pool to draw from, or None to pay from the job balance
+    /// * `spend_cap` - Max lamports the job may draw from the pool (0 = no cap)
+    /// * `owner` - Owner of the job and the pool
+    pub fn set_job_funding_pool(
+        job_id: u64,
+        funding_pool: Option<Pubkey>,
+        spend_cap: u64,
+        owner: Pubkey,
+    ) -> SolCronResult<Instruction> {
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(automation_job, false),
+            // Optional accounts are passed as the program ID when absent
+            AccountMeta::new_readonly(funding_pool.unwrap_or(REGISTRY_PROGRAM_ID), false),
+            AccountMeta::new_readonly(owner, true),
+        ];
+
+        let data = SetJobFundingPoolData { spend_cap };
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::SetJobFundingPool(data).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to update job configuration
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `update` - Fields to change; unset fields are left unchanged
     /// * `owner` - Job owner
     pub fn update_job(
         job_id: u64,
-        gas_limit: Option<u64>,
-        min_balance: Option<u64>,
-        trigger_params: Option<Vec<u8>>,
+        update: JobUpdate,
         owner: Pubkey,
     ) -> SolCronResult<Instruction> {
         let (automation_job, _) = Accounts::automation_job(job_id)?;
+        let (job_metadata, _) = Accounts::job_metadata(job_id)?;
 
         let accounts = vec![
             AccountMeta::new(automation_job, false),
-            AccountMeta::new_readonly(owner, true),
+            job_metadata_meta(update.metadata.is_some(), job_metadata),
+            AccountMeta::new(owner, true),
+            AccountMeta::new_readonly(system_program::ID, false),
         ];
 
-        let data = UpdateJobData {
-            gas_limit,
-            min_balance,
-            trigger_params,
-        };
+        let data = UpdateJobData { update };
 
         Ok(Instruction {
             program_id: REGISTRY_PROGRAM_ID,
@@ -157,22 +278,74 @@ impl Instructions {
         })
     }
 
+    /// Create an instruction to pause a job's executions without cancelling it
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `owner` - Job owner
+    pub fn pause_job(job_id: u64, owner: Pubkey) -> SolCronResult<Instruction> {
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(automation_job, false),
+            AccountMeta::new_readonly(owner, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::PauseJob.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to resume a job paused by its owner
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `owner` - Job owner
+    /// * `reset_last_execution` - Restart the schedule from now instead of running overdue executions
+    pub fn resume_job(job_id: u64, owner: Pubkey, reset_last_execution: bool) -> SolCronResult<Instruction> {
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(automation_job, false),
+            AccountMeta::new_readonly(owner, true),
+        ];
+
+        let data = ResumeJobData { reset_last_execution };
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::ResumeJob(data).try_to_vec()?,
+        })
+    }
+
     /// Create an instruction to cancel a job
     /// 
     /// # Arguments
     /// * `job_id` - Job identifier
     /// * `owner` - Job owner
-    pub fn cancel_job(job_id: u64, owner: Pubkey) -> SolCronResult<Instruction> {
+    /// * `sponsors` - Wallets of sponsors to refund along with the owner; the
+    ///   rest can withdraw their share later
+    pub fn cancel_job(job_id: u64, owner: Pubkey, sponsors: &[Pubkey]) -> SolCronResult<Instruction> {
         let (registry_state, _) = Accounts::registry_state()?;
         let (automation_job, _) = Accounts::automation_job(job_id)?;
 
-        let accounts = vec![
+        let mut accounts = vec![
             AccountMeta::new(registry_state, false),
             AccountMeta::new(automation_job, false),
             AccountMeta::new(owner, true),
             AccountMeta::new_readonly(system_program::ID, false),
         ];
 
+        // Each sponsorship is followed by its sponsor's wallet
+        for sponsor in sponsors {
+            let (sponsorship, _) = Accounts::sponsorship(job_id, sponsor)?;
+            accounts.push(AccountMeta::new(sponsorship, false));
+            accounts.push(AccountMeta::new(*sponsor, false));
+        }
+
         Ok(Instruction {
             program_id: REGISTRY_PROGRAM_ID,
             accounts,
@@ -180,6 +353,68 @@ impl Instructions {
         })
     }
 
+    /// Create an instruction to withdraw a sponsor's unspent share of a job balance
+    /// 
+    /// # Arguments
+    /// * `job_id` - Sponsored job identifier
+    /// * `sponsor` - Sponsor's wallet
+    pub fn withdraw_sponsorship(job_id: u64, sponsor: Pubkey) -> SolCronResult<Instruction> {
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+        let (sponsorship, _) = Accounts::sponsorship(job_id, &sponsor)?;
+
+        let accounts = vec![
+            AccountMeta::new(automation_job, false),
+            AccountMeta::new(sponsorship, false),
+            AccountMeta::new(sponsor, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::WithdrawSponsorship.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to reap an expired, or drained and long inactive, job
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `owner` - Job owner, who receives the balance and the rent left after the bounty
+    /// * `reaper` - Caller collecting the rent bounty
+    /// * `sponsors` - Wallets of the job's sponsors, refunded before the job closes
+    pub fn reap_job(
+        job_id: u64,
+        owner: Pubkey,
+        reaper: Pubkey,
+        sponsors: &[Pubkey],
+    ) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+        let (job_metadata, _) = Accounts::job_metadata(job_id)?;
+
+        let mut accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new(automation_job, false),
+            // Closed only if the job has metadata
+            AccountMeta::new(job_metadata, false),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(reaper, true),
+        ];
+
+        // Each sponsorship is followed by its sponsor's wallet
+        for sponsor in sponsors {
+            let (sponsorship, _) = Accounts::sponsorship(job_id, sponsor)?;
+            accounts.push(AccountMeta::new(sponsorship, false));
+            accounts.push(AccountMeta::new(*sponsor, false));
+        }
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::ReapJob.try_to_vec()?,
+        })
+    }
+
     /// Create an instruction to register a keeper
     /// 
     /// # Arguments
@@ -236,11 +471,17 @@ impl Instructions {
     /// * `keeper_address` - Executing keeper
     /// * `target_program` - Target program to execute
     /// * `execution_count` - Current execution count
+    /// * `trigger_account` - Account read by the job's trigger, if any
+    /// * `funding_pool` - Funding pool the job draws fees from, if any
+    /// * `perform_data` - Data returned by the job's check instruction, appended to the target call
     pub fn execute_job(
         job_id: u64,
         keeper_address: Pubkey,
         target_program: Pubkey,
         execution_count: u64,
+        trigger_account: Option<Pubkey>,
+        funding_pool: Option<Pubkey>,
+        perform_data: Vec<u8>,
     ) -> SolCronResult<Instruction> {
         let accounts_info = Accounts::job_execution_accounts(
             job_id,
@@ -256,10 +497,20 @@ impl Instructions {
             AccountMeta::new(accounts_info.execution_record, false),
             AccountMeta::new_readonly(accounts_info.keeper_account, true),
             AccountMeta::new_readonly(accounts_info.target_program, false),
+            AccountMeta::new_readonly(accounts_info.execution_authority, false),
+            AccountMeta::new_readonly(accounts_info.execution_program, false),
+            AccountMeta::new(accounts_info.insurance_pool, false),
             AccountMeta::new_readonly(accounts_info.system_program, false),
+            AccountMeta::new_readonly(accounts_info.instructions_sysvar, false),
+            // Optional accounts are passed as the program ID when absent
+            AccountMeta::new_readonly(trigger_account.unwrap_or(REGISTRY_PROGRAM_ID), false),
+            match funding_pool {
+                Some(funding_pool) => AccountMeta::new(funding_pool, false),
+                None => AccountMeta::new_readonly(REGISTRY_PROGRAM_ID, false),
+            },
         ];
 
-        let data = ExecuteJobData { job_id };
+        let data = ExecuteJobData { job_id, perform_data };
 
         Ok(Instruction {
             program_id: REGISTRY_PROGRAM_ID,
@@ -268,6 +519,70 @@ impl Instructions {
         })
     }
 
+    /// Create a read-only instruction reporting whether a job is due
+    /// 
+    /// Meant to be simulated; the program returns a `JobStatus` as return data.
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `trigger_account` - Account read by the job's trigger, if any
+    /// * `funding_pool` - Funding pool the job draws fees from, if any
+    /// * `keeper_address` - Keeper whose tier is checked; without one only jobs open to any tier can be due
+    /// * `remaining_accounts` - Extra accounts the trigger reads, such as a price account
+    pub fn job_status(
+        job_id: u64,
+        trigger_account: Option<Pubkey>,
+        funding_pool: Option<Pubkey>,
+        keeper_address: Option<Pubkey>,
+        remaining_accounts: Vec<AccountMeta>,
+    ) -> SolCronResult<Instruction> {
+        let mut accounts = Self::job_view_accounts(job_id, trigger_account, funding_pool, keeper_address)?;
+        accounts.extend(remaining_accounts);
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::JobStatus(JobViewData { job_id }).try_to_vec()?,
+        })
+    }
+
+    /// Create a read-only instruction quoting a job's next execution fee
+    /// 
+    /// Meant to be simulated; the program returns an `ExecutionQuote` as return data.
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `funding_pool` - Funding pool the job draws fees from, if any
+    pub fn quote_execution(job_id: u64, funding_pool: Option<Pubkey>) -> SolCronResult<Instruction> {
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts: Self::job_view_accounts(job_id, None, funding_pool, None)?,
+            data: InstructionData::QuoteExecution(JobViewData { job_id }).try_to_vec()?,
+        })
+    }
+
+    fn job_view_accounts(
+        job_id: u64,
+        trigger_account: Option<Pubkey>,
+        funding_pool: Option<Pubkey>,
+        keeper_address: Option<Pubkey>,
+    ) -> SolCronResult<Vec<AccountMeta>> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+        let keeper = keeper_address
+            .map(|keeper_address| Accounts::keeper(&keeper_address).map(|(keeper, _)| keeper))
+            .transpose()?;
+
+        Ok(vec![
+            AccountMeta::new_readonly(registry_state, false),
+            AccountMeta::new_readonly(automation_job, false),
+            // Optional accounts are passed as the program ID when absent
+            AccountMeta::new_readonly(trigger_account.unwrap_or(REGISTRY_PROGRAM_ID), false),
+            AccountMeta::new_readonly(funding_pool.unwrap_or(REGISTRY_PROGRAM_ID), false),
+            AccountMeta::new_readonly(keeper.unwrap_or(REGISTRY_PROGRAM_ID), false),
+        ])
+    }
+
     /// Create an instruction to claim keeper rewards
     /// 
     /// # Arguments
@@ -288,17 +603,255 @@ impl Instructions {
         })
     }
 
-    /// Create an instruction to update registry parameters (admin only)
+    /// Create an instruction to delegate stake to a keeper
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Keeper's wallet
+    /// * `delegator` - Delegator's wallet
+    /// * `amount` - Amount to delegate (lamports)
+    pub fn delegate_stake(
+        keeper_address: Pubkey,
+        delegator: Pubkey,
+        amount: u64,
+    ) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (keeper, _) = Accounts::keeper(&keeper_address)?;
+        let (delegation, _) = Accounts::delegation(&keeper_address, &delegator)?;
+
+        let accounts = vec![
+            AccountMeta::new_readonly(registry_state, false),
+            AccountMeta::new(keeper, false),
+            AccountMeta::new(delegation, false),
+            AccountMeta::new(delegator, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        let data = DelegateStakeData { keeper_address, amount };
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::DelegateStake(data).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to start unbonding delegated shares
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Keeper's wallet
+    /// * `delegator` - Delegator's wallet
+    /// * `shares` - Shares to unbond
+    pub fn undelegate_stake(
+        keeper_address: Pubkey,
+        delegator: Pubkey,
+        shares: u64,
+    ) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (keeper, _) = Accounts::keeper(&keeper_address)?;
+        let (delegation, _) = Accounts::delegation(&keeper_address, &delegator)?;
+
+        let accounts = vec![
+            AccountMeta::new_readonly(registry_state, false),
+            AccountMeta::new(keeper, false),
+            AccountMeta::new(delegation, false),
+            AccountMeta::new_readonly(delegator, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::UndelegateStake(UndelegateStakeData { shares }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to withdraw delegated stake after unbonding
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Keeper's wallet
+    /// * `delegator` - Delegator's wallet
+    pub fn withdraw_delegation(keeper_address: Pubkey, delegator: Pubkey) -> SolCronResult<Instruction> {
+        let (keeper, _) = Accounts::keeper(&keeper_address)?;
+        let (delegation, _) = Accounts::delegation(&keeper_address, &delegator)?;
+
+        let accounts = vec![
+            AccountMeta::new(keeper, false),
+            AccountMeta::new(delegation, false),
+            AccountMeta::new(delegator, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::WithdrawDelegation.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to set a keeper's commission on delegator rewards
+    /// 
+    /// Increases only apply after the registry's delegation unbonding period.
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Keeper's wallet
+    /// * `commission_bps` - Commission in basis points (0-10000)
+    pub fn set_commission(keeper_address: Pubkey, commission_bps: u16) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (keeper, _) = Accounts::keeper(&keeper_address)?;
+
+        let accounts = vec![
+            AccountMeta::new_readonly(registry_state, false),
+            AccountMeta::new(keeper, false),
+            AccountMeta::new_readonly(keeper_address, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::SetCommission(SetCommissionData { commission_bps }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to migrate a job account to the current layout
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job identifier
+    /// * `payer` - Pays any additional rent (anyone may migrate)
+    pub fn migrate_job(job_id: u64, payer: Pubkey) -> SolCronResult<Instruction> {
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+        Self::migrate_account(automation_job, payer, InstructionData::MigrateJob)
+    }
+
+    /// Create an instruction to migrate a keeper account to the current layout
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Keeper's public key
+    /// * `payer` - Pays any additional rent (anyone may migrate)
+    pub fn migrate_keeper(keeper_address: Pubkey, payer: Pubkey) -> SolCronResult<Instruction> {
+        let (keeper, _) = Accounts::keeper(&keeper_address)?;
+        Self::migrate_account(keeper, payer, InstructionData::MigrateKeeper)
+    }
+
+    /// Create an instruction to migrate the registry state to the current layout
+    /// 
+    /// # Arguments
+    /// * `payer` - Pays any additional rent (anyone may migrate)
+    pub fn migrate_registry(payer: Pubkey) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        Self::migrate_account(registry_state, payer, InstructionData::MigrateRegistry)
+    }
+
+    /// Create an instruction to migrate an execution record to the current layout
     /// 
     /// # Arguments
-    /// * `base_fee` - New base fee (optional)
-    /// * `min_stake` - New minimum stake (optional)
-    /// * `protocol_fee_bps` - New protocol fee rate (optional)
+    /// * `job_id` - Job identifier
+    /// * `execution_count` - Execution index of the record
+    /// * `payer` - Pays any additional rent (anyone may migrate)
+    pub fn migrate_execution_record(
+        job_id: u64,
+        execution_count: u64,
+        payer: Pubkey,
+    ) -> SolCronResult<Instruction> {
+        let (execution_record, _) = Accounts::execution_record(job_id, execution_count)?;
+        Self::migrate_account(execution_record, payer, InstructionData::MigrateExecutionRecord)
+    }
+
+    fn migrate_account(
+        account: Pubkey,
+        payer: Pubkey,
+        data: InstructionData,
+    ) -> SolCronResult<Instruction> {
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts: vec![
+                AccountMeta::new(account, false),
+                AccountMeta::new(payer, true),
+                AccountMeta::new_readonly(system_program::ID, false),
+            ],
+            data: data.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to queue registry parameter changes
+    ///
+    /// Fee, stake and rate limit changes require the fee manager; timelock
+    /// changes require the upgrader.
+    ///
+    /// # Arguments
+    /// * `params` - Parameter changes to queue
+    /// * `proposer` - Role holder proposing the change
+    pub fn propose_params(
+        params: RegistryParams,
+        proposer: Pubkey,
+    ) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (pending_params, _) = Accounts::pending_params()?;
+
+        let accounts = vec![
+            AccountMeta::new_readonly(registry_state, false),
+            AccountMeta::new(pending_params, false),
+            AccountMeta::new(proposer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::ProposeParams(ProposeParamsData { params }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to apply queued parameters once the timelock expires
+    ///
+    /// # Arguments
+    /// * `proposer` - Original proposer, receives the pending account rent
+    pub fn execute_params(proposer: Pubkey) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (pending_params, _) = Accounts::pending_params()?;
+
+        let accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new(pending_params, false),
+            AccountMeta::new(proposer, false),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::ExecuteParams.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to cancel queued parameters (proposer or admin)
+    ///
+    /// # Arguments
+    /// * `proposer` - Original proposer, receives the pending account rent
+    /// * `authority` - Proposer or registry admin
+    pub fn cancel_params(proposer: Pubkey, authority: Pubkey) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (pending_params, _) = Accounts::pending_params()?;
+
+        let accounts = vec![
+            AccountMeta::new_readonly(registry_state, false),
+            AccountMeta::new(pending_params, false),
+            AccountMeta::new(proposer, false),
+            AccountMeta::new_readonly(authority, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::CancelParams.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to assign a governance role (admin only)
+    ///
+    /// # Arguments
+    /// * `role` - Role to assign
+    /// * `new_key` - New role holder
     /// * `admin` - Registry admin
-    pub fn update_registry_params(
-        base_fee: Option<u64>,
-        min_stake: Option<u64>,
-        protocol_fee_bps: Option<u16>,
+    pub fn set_role(
+        role: RegistryRole,
+        new_key: Pubkey,
         admin: Pubkey,
     ) -> SolCronResult<Instruction> {
         let admin_accounts = AdminAccounts::new(&admin)?;
@@ -308,42 +861,78 @@ impl Instructions {
             AccountMeta::new_readonly(admin_accounts.admin, true),
         ];
 
-        let data = UpdateRegistryParamsData {
-            base_fee,
-            min_stake,
-            protocol_fee_bps,
-        };
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::SetRole(SetRoleData { role, new_key }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to pause or unpause the registry (pauser only)
+    ///
+    /// # Arguments
+    /// * `paused` - Whether registrations and executions are suspended
+    /// * `pauser` - Registry pauser
+    pub fn set_registry_paused(paused: bool, pauser: Pubkey) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+
+        let accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new_readonly(pauser, true),
+        ];
 
         Ok(Instruction {
             program_id: REGISTRY_PROGRAM_ID,
             accounts,
-            data: InstructionData::UpdateRegistryParams(data).try_to_vec()?,
+            data: InstructionData::SetRegistryPaused(SetRegistryPausedData { paused }).try_to_vec()?,
         })
     }
 
-    /// Create an instruction to slash a keeper (admin only)
+    /// Create an instruction to sweep accumulated protocol revenue to the treasury (fee manager only)
+    ///
+    /// # Arguments
+    /// * `treasury` - Registry treasury
+    /// * `fee_manager` - Registry fee manager
+    pub fn withdraw_protocol_revenue(treasury: Pubkey, fee_manager: Pubkey) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+
+        let accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new_readonly(fee_manager, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::WithdrawProtocolRevenue.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to propose slashing a keeper (slasher only)
+    /// 
+    /// The slashed funds are escrowed until the dispute window closes.
     /// 
     /// # Arguments
     /// * `keeper_address` - Keeper to slash
     /// * `slash_amount` - Amount to slash (lamports)
     /// * `reason` - Reason for slashing
-    /// * `admin` - Registry admin
-    /// * `treasury` - Treasury to receive slashed funds
+    /// * `slasher` - Registry slasher, pays for the pending slash account
     pub fn slash_keeper(
         keeper_address: Pubkey,
         slash_amount: u64,
         reason: String,
-        admin: Pubkey,
-        treasury: Pubkey,
+        slasher: Pubkey,
     ) -> SolCronResult<Instruction> {
         let (registry_state, _) = Accounts::registry_state()?;
         let (keeper, _) = Accounts::keeper(&keeper_address)?;
+        let (pending_slash, _) = Accounts::pending_slash(&keeper_address)?;
 
         let accounts = vec![
-            AccountMeta::new(registry_state, false),
+            AccountMeta::new_readonly(registry_state, false),
             AccountMeta::new(keeper, false),
-            AccountMeta::new_readonly(admin, true),
-            AccountMeta::new(treasury, false),
+            AccountMeta::new(pending_slash, false),
+            AccountMeta::new(slasher, true),
             AccountMeta::new_readonly(system_program::ID, false),
         ];
 
@@ -359,6 +948,190 @@ impl Instructions {
             data: InstructionData::SlashKeeper(data).try_to_vec()?,
         })
     }
+
+    /// Create an instruction to create the insurance pool (admin only)
+    /// 
+    /// # Arguments
+    /// * `admin` - Registry admin, pays for the pool account
+    pub fn initialize_insurance_pool(admin: Pubkey) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (insurance_pool, _) = Accounts::insurance_pool()?;
+
+        let accounts = vec![
+            AccountMeta::new_readonly(registry_state, false),
+            AccountMeta::new(insurance_pool, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::InitializeInsurancePool.try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to pay a job owner from the insurance pool (admin only)
+    /// 
+    /// # Arguments
+    /// * `job_id` - Job harmed by the execution
+    /// * `execution_count` - Execution count of the harmful execution
+    /// * `owner` - Job owner receiving the payout
+    /// * `slash_id` - Slash backing the claim
+    /// * `amount` - Payout (lamports)
+    /// * `admin` - Registry admin, pays for the claim account
+    pub fn approve_insurance_claim(
+        job_id: u64,
+        execution_count: u64,
+        owner: Pubkey,
+        slash_id: u64,
+        amount: u64,
+        admin: Pubkey,
+    ) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (insurance_pool, _) = Accounts::insurance_pool()?;
+        let (automation_job, _) = Accounts::automation_job(job_id)?;
+        let (execution_record, _) = Accounts::execution_record(job_id, execution_count)?;
+        let (insurance_claim, _) = Accounts::insurance_claim(&execution_record)?;
+        let (slash_record, _) = Accounts::slash_record(slash_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new(insurance_pool, false),
+            AccountMeta::new_readonly(automation_job, false),
+            AccountMeta::new_readonly(execution_record, false),
+            AccountMeta::new(slash_record, false),
+            AccountMeta::new(insurance_claim, false),
+            AccountMeta::new(owner, false),
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        let data = ApproveInsuranceClaimData { slash_id, amount };
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::ApproveInsuranceClaim(data).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to appeal a pending slash (slashed keeper only)
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Slashed keeper's wallet
+    /// * `appeal` - Grounds for the appeal
+    pub fn appeal_slash(keeper_address: Pubkey, appeal: String) -> SolCronResult<Instruction> {
+        let (pending_slash, _) = Accounts::pending_slash(&keeper_address)?;
+
+        let accounts = vec![
+            AccountMeta::new(pending_slash, false),
+            AccountMeta::new_readonly(keeper_address, true),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::AppealSlash(AppealSlashData { appeal }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to uphold or reject a pending slash (admin only)
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Slashed keeper's wallet
+    /// * `uphold` - Send the escrow to the treasury if true, return it to the keeper if false
+    /// * `slash_id` - Registry's current slash count, which numbers the slash record when upholding
+    /// * `slasher` - Slasher that proposed the slash, receives the account rent
+    /// * `treasury` - Treasury to receive the non-insurance share of upheld slashes
+    /// * `admin` - Registry admin
+    pub fn resolve_slash(
+        keeper_address: Pubkey,
+        uphold: bool,
+        slash_id: u64,
+        slasher: Pubkey,
+        treasury: Pubkey,
+        admin: Pubkey,
+    ) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (keeper, _) = Accounts::keeper(&keeper_address)?;
+        let (pending_slash, _) = Accounts::pending_slash(&keeper_address)?;
+        let (insurance_pool, _) = Accounts::insurance_pool()?;
+        let slash_record = if uphold {
+            AccountMeta::new(Accounts::slash_record(slash_id)?.0, false)
+        } else {
+            AccountMeta::new_readonly(REGISTRY_PROGRAM_ID, false)
+        };
+
+        let accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new(keeper, false),
+            AccountMeta::new(pending_slash, false),
+            AccountMeta::new(slasher, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(insurance_pool, false),
+            AccountMeta::new(keeper_address, false),
+            slash_record,
+            AccountMeta::new(admin, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::ResolveSlash(ResolveSlashData { uphold }).try_to_vec()?,
+        })
+    }
+
+    /// Create an instruction to apply an unappealed slash after its dispute window
+    /// 
+    /// # Arguments
+    /// * `keeper_address` - Slashed keeper's wallet
+    /// * `slasher` - Slasher that proposed the slash, receives the account rent
+    /// * `treasury` - Treasury to receive the non-insurance share of the slash
+    /// * `slash_id` - Registry's current slash count, which numbers the slash record
+    /// * `payer` - Pays the slash record rent
+    pub fn finalize_slash(
+        keeper_address: Pubkey,
+        slasher: Pubkey,
+        treasury: Pubkey,
+        slash_id: u64,
+        payer: Pubkey,
+    ) -> SolCronResult<Instruction> {
+        let (registry_state, _) = Accounts::registry_state()?;
+        let (keeper, _) = Accounts::keeper(&keeper_address)?;
+        let (pending_slash, _) = Accounts::pending_slash(&keeper_address)?;
+        let (insurance_pool, _) = Accounts::insurance_pool()?;
+        let (slash_record, _) = Accounts::slash_record(slash_id)?;
+
+        let accounts = vec![
+            AccountMeta::new(registry_state, false),
+            AccountMeta::new(keeper, false),
+            AccountMeta::new(pending_slash, false),
+            AccountMeta::new(slasher, false),
+            AccountMeta::new(treasury, false),
+            AccountMeta::new(insurance_pool, false),
+            AccountMeta::new(slash_record, false),
+            AccountMeta::new(payer, true),
+            AccountMeta::new_readonly(system_program::ID, false),
+        ];
+
+        Ok(Instruction {
+            program_id: REGISTRY_PROGRAM_ID,
+            accounts,
+            data: InstructionData::FinalizeSlash.try_to_vec()?,
+        })
+    }
+}
+
+/// Metadata account meta, or the registry program ID standing in for the
+/// optional account when no metadata is being written
+fn job_metadata_meta(has_metadata: bool, job_metadata: Pubkey) -> AccountMeta {
+    if has_metadata {
+        AccountMeta::new(job_metadata, false)
+    } else {
+        AccountMeta::new_readonly(REGISTRY_PROGRAM_ID, false)
+    }
 }
 
 /// Instruction data structures
@@ -373,8 +1146,36 @@ pub enum InstructionData {
     UnregisterKeeper,
     ExecuteJob(ExecuteJobData),
     ClaimRewards,
-    UpdateRegistryParams(UpdateRegistryParamsData),
     SlashKeeper(SlashKeeperData),
+    ResumeJob(ResumeJobData),
+    MigrateJob,
+    MigrateKeeper,
+    MigrateRegistry,
+    MigrateExecutionRecord,
+    ProposeParams(ProposeParamsData),
+    ExecuteParams,
+    CancelParams,
+    SetRole(SetRoleData),
+    SetRegistryPaused(SetRegistryPausedData),
+    QuoteExecution(JobViewData),
+    JobStatus(JobViewData),
+    AppealSlash(AppealSlashData),
+    ResolveSlash(ResolveSlashData),
+    FinalizeSlash,
+    InitializeInsurancePool,
+    ApproveInsuranceClaim(ApproveInsuranceClaimData),
+    DelegateStake(DelegateStakeData),
+    UndelegateStake(UndelegateStakeData),
+    WithdrawDelegation,
+    SetCommission(SetCommissionData),
+    CreateFundingPool(CreateFundingPoolData),
+    FundPool(FundJobData),
+    WithdrawPool(FundJobData),
+    SetJobFundingPool(SetJobFundingPoolData),
+    WithdrawSponsorship,
+    PauseJob,
+    ReapJob,
+    WithdrawProtocolRevenue,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -388,13 +1189,7 @@ pub struct InitializeRegistryData {
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct RegisterJobData {
-    pub target_program: Pubkey,
-    pub target_instruction: String,
-    pub trigger_type: TriggerType,
-    pub trigger_params: Vec<u8>,
-    pub gas_limit: u64,
-    pub min_balance: u64,
-    pub initial_funding: u64,
+    pub registration: JobRegistration,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -402,11 +1197,20 @@ pub struct FundJobData {
     pub amount: u64,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct CreateFundingPoolData {
+    pub pool_id: u64,
+    pub min_balance: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetJobFundingPoolData {
+    pub spend_cap: u64,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct UpdateJobData {
-    pub gas_limit: Option<u64>,
-    pub min_balance: Option<u64>,
-    pub trigger_params: Option<Vec<u8>>,
+    pub update: JobUpdate,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -417,13 +1221,12 @@ pub struct RegisterKeeperData {
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct ExecuteJobData {
     pub job_id: u64,
+    pub perform_data: Vec<u8>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
-pub struct UpdateRegistryParamsData {
-    pub base_fee: Option<u64>,
-    pub min_stake: Option<u64>,
-    pub protocol_fee_bps: Option<u16>,
+pub struct JobViewData {
+    pub job_id: u64,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
@@ -433,6 +1236,59 @@ pub struct SlashKeeperData {
     pub reason: String,
 }
 
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct AppealSlashData {
+    pub appeal: String,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResolveSlashData {
+    pub uphold: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct DelegateStakeData {
+    pub keeper_address: Pubkey,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UndelegateStakeData {
+    pub shares: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetCommissionData {
+    pub commission_bps: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ResumeJobData {
+    pub reset_last_execution: bool,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ApproveInsuranceClaimData {
+    pub slash_id: u64,
+    pub amount: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct ProposeParamsData {
+    pub params: RegistryParams,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRoleData {
+    pub role: RegistryRole,
+    pub new_key: Pubkey,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct SetRegistryPausedData {
+    pub paused: bool,
+}
+
 impl InstructionData {
     /// Serialize instruction data
     pub fn try_to_vec(&self) -> SolCronResult<Vec<u8>> {
@@ -129,6 +129,7 @@ pub use client::SolCronClient;
 //!                 trigger_type: cpi::TriggerType::TimeBased { interval },
 //!                 gas_limit,
 //!                 min_balance: 1_000_000,
+//!                 metadata: None,
 //!             },
 //!             50_000_000, // Initial funding
 //!         )?;
@@ -308,6 +308,10 @@ impl Monitor {
                 TriggerType::Conditional { .. } => "Conditional", 
                 TriggerType::LogBased { .. } => "LogBased",
                 TriggerType::Hybrid { .. } => "Hybrid",
+                TriggerType::SlotInterval { .. } => "SlotInterval",
+                TriggerType::EpochStart { .. } => "EpochStart",
+                TriggerType::AccountChange { .. } => "AccountChange",
+                TriggerType::PriceThreshold { .. } => "PriceThreshold",
             };
             *trigger_distribution.entry(trigger_name.to_string()).or_insert(0) += 1;
         }
@@ -5,7 +5,7 @@
 
 use crate::{
     client::SolCronClient,
-    types::{AutomationJob, Keeper, TriggerType, RegistryState},
+    types::{AutomationJob, CatchUpPolicy, Keeper, TriggerType, RegistryState},
     error::{SolCronError, SolCronResult},
     utils::{Utils, TimeUtils},
     batch::{BatchOperations, BatchJobParams, BatchConfig},
@@ -260,6 +260,11 @@ impl Simulator {
                 pending_rewards: 0,
                 last_execution_time: Utils::current_timestamp() - rng.gen_range(0..3600),
                 registered_at: Utils::current_timestamp() - rng.gen_range(0..86400 * 30),
+                delegated_stake: 0,
+                delegator_shares: 0,
+                unbonding_shares: 0,
+                commission_bps: 1000,
+                total_reimbursed: 0,
             };
             
             keepers.insert(address, keeper);
@@ -303,6 +308,28 @@ impl Simulator {
                 execution_count: 0,
                 last_execution: 0,
                 created_at: Utils::current_timestamp() - rng.gen_range(0..86400),
+                is_paused: false,
+                target_accounts: vec![],
+                next_scheduled_time: 0,
+                grace_period: 0,
+                missed_executions: 0,
+                catch_up_policy: CatchUpPolicy::RunOnce,
+                next_scheduled_slot: 0,
+                next_epoch: 0,
+                last_data_hash: [0; 32],
+                check_instruction: None,
+                tip_lamports: 0,
+                max_fee_per_execution: 0,
+                funding_pool: None,
+                pool_spend_cap: 0,
+                pool_spent: 0,
+                funding_shares: 0,
+                sponsor_shares: 0,
+                min_keeper_tier: 0,
+                max_fee_reimbursement: 0,
+                end_at: 0,
+                deactivated_at: 0,
+                funding_round: 0,
             };
             
             jobs.insert(job_id, job);
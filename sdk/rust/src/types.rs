@@ -28,6 +28,41 @@ pub enum TriggerType {
         /// Logic operator: "AND" or "OR"
         operator: String,
     },
+    /// Slot-based trigger that executes every N slots
+    SlotInterval {
+        /// Interval in slots between executions
+        interval: u64,
+    },
+    /// Epoch-based trigger that executes once per epoch after the boundary
+    EpochStart {
+        /// Slots after the boundary the run may happen in (0 = whole epoch)
+        window: u64,
+    },
+    /// Account-change trigger that executes when a watched account's data changes
+    AccountChange {
+        /// Account whose data is watched
+        account: Pubkey,
+    },
+    /// Price trigger that executes when a Pyth price crosses a threshold
+    PriceThreshold {
+        /// Pyth price account, passed with the target accounts on execution
+        price_account: Pubkey,
+        /// Direction the price must cross the threshold in
+        comparison: PriceComparison,
+        /// Threshold in the price account's own exponent
+        threshold: i64,
+        /// Maximum age of the published price in seconds
+        max_staleness: u64,
+    },
+}
+
+/// Direction a price-threshold trigger compares in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub enum PriceComparison {
+    /// Price at or above the threshold
+    Above,
+    /// Price at or below the threshold
+    Below,
 }
 
 /// Individual trigger condition for hybrid triggers
@@ -52,6 +87,57 @@ pub struct JobParams {
     pub gas_limit: u64,
     /// Minimum balance to maintain in the job account
     pub min_balance: u64,
+    /// Optional name, tags and metadata URI stored alongside the job
+    #[serde(default)]
+    pub metadata: Option<JobMetadataParams>,
+}
+
+impl JobParams {
+    /// `register_job` arguments for these parameters and the initial funding
+    pub fn registration(&self, initial_funding: u64) -> JobRegistration {
+        JobRegistration {
+            target_program: self.target_program,
+            target_instruction: self.target_instruction.clone(),
+            trigger_type: self.trigger_type.clone(),
+            trigger_params: self.trigger_params.clone(),
+            gas_limit: self.gas_limit,
+            min_balance: self.min_balance,
+            initial_funding,
+            metadata: self.metadata.clone(),
+        }
+    }
+}
+
+/// Job configuration passed to `register_job`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct JobRegistration {
+    /// Program to call
+    pub target_program: Pubkey,
+    /// Instruction name to call on the target program
+    pub target_instruction: String,
+    /// Trigger configuration
+    pub trigger_type: TriggerType,
+    /// Serialized trigger parameters
+    pub trigger_params: Vec<u8>,
+    /// Maximum compute units per execution
+    pub gas_limit: u64,
+    /// Minimum balance to maintain (lamports)
+    pub min_balance: u64,
+    /// Lamports moved from the owner into the job balance
+    pub initial_funding: u64,
+    /// Name, tags and metadata URI, stored in the job's metadata account
+    pub metadata: Option<JobMetadataParams>,
+}
+
+/// Human-facing job metadata set on register or update
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct JobMetadataParams {
+    /// Display name (max 64 bytes)
+    pub name: String,
+    /// Labels jobs can be filtered by (max 8, 32 bytes each)
+    pub tags: Vec<String>,
+    /// Off-chain metadata document (max 200 bytes)
+    pub metadata_uri: String,
 }
 
 /// Automation job account state
@@ -83,6 +169,110 @@ pub struct AutomationJob {
     pub last_execution: u64,
     /// Job creation timestamp
     pub created_at: u64,
+    /// Accounts passed to the target instruction (empty if keeper supplied)
+    pub target_accounts: Vec<JobAccountMeta>,
+    /// Next anchored run (`created_at + k * interval`)
+    pub next_scheduled_time: u64,
+    /// Seconds a run may lag its schedule before it counts as missed (0 = full interval)
+    pub grace_period: i64,
+    /// Scheduled runs not executed within their grace window
+    pub missed_executions: u64,
+    /// How overdue scheduled runs are handled
+    pub catch_up_policy: CatchUpPolicy,
+    /// Next slot a slot-interval job may run at
+    pub next_scheduled_slot: u64,
+    /// Next epoch an epoch-start job may run in
+    pub next_epoch: u64,
+    /// Watched account data hash at the last account-change execution
+    pub last_data_hash: [u8; 32],
+    /// Target view instruction simulated before each run, returning Borsh `(bool, Vec<u8>)`
+    pub check_instruction: Option<String>,
+    /// Lamports paid to the executing keeper on top of the base fee
+    pub tip_lamports: u64,
+    /// Execution fee cap in lamports (0 = no cap)
+    pub max_fee_per_execution: u64,
+    /// Funding pool paying fees instead of the job balance
+    pub funding_pool: Option<Pubkey>,
+    /// Max lamports drawn from the funding pool (0 = no cap)
+    pub pool_spend_cap: u64,
+    /// Lamports drawn from the funding pool since it was set
+    pub pool_spent: u64,
+    /// Shares of the balance held by the owner and sponsors
+    pub funding_shares: u64,
+    /// Shares held by sponsorships (the rest are the owner's)
+    pub sponsor_shares: u64,
+    /// Lowest keeper tier allowed to execute (0 = any keeper)
+    pub min_keeper_tier: u8,
+    /// Cap on the keeper's signature and priority fees repaid per run (lamports)
+    pub max_fee_reimbursement: u64,
+    /// Expiry after which anyone may reap the job (0 = never)
+    pub end_at: i64,
+    /// When the job was cancelled or deactivated (0 = active)
+    pub deactivated_at: i64,
+    /// Whether the job was paused by its owner
+    pub is_paused: bool,
+    /// Bumped each time a drained balance resets the share supply
+    pub funding_round: u64,
+}
+
+/// How a time-based job handles scheduled runs that are overdue
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub enum CatchUpPolicy {
+    /// Run once for all overdue runs and resume the schedule from now
+    #[default]
+    RunOnce,
+    /// Only run within a scheduled run's grace window, skipping the rest
+    SkipMissed,
+    /// Run once per overdue scheduled run, oldest first
+    BackfillEach,
+}
+
+/// Account passed to a job's target instruction on every execution
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct JobAccountMeta {
+    /// Account address
+    pub pubkey: Pubkey,
+    /// Whether the account signs the target instruction
+    pub is_signer: bool,
+    /// Whether the account is writable
+    pub is_writable: bool,
+}
+
+/// Job configuration changes for `update_job`; unset fields are left unchanged
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct JobUpdate {
+    /// New target program
+    pub target_program: Option<Pubkey>,
+    /// New target instruction name
+    pub target_instruction: Option<String>,
+    /// New trigger type
+    pub trigger_type: Option<TriggerType>,
+    /// New serialized trigger parameters
+    pub trigger_params: Option<Vec<u8>>,
+    /// New stored target accounts (empty lets keepers supply them)
+    pub target_accounts: Option<Vec<JobAccountMeta>>,
+    /// New gas limit
+    pub gas_limit: Option<u64>,
+    /// New minimum balance (lamports)
+    pub min_balance: Option<u64>,
+    /// New grace period in seconds (0 = full interval)
+    pub grace_period: Option<i64>,
+    /// New catch-up policy for overdue runs
+    pub catch_up_policy: Option<CatchUpPolicy>,
+    /// New check instruction; an empty name removes the check
+    pub check_instruction: Option<String>,
+    /// New keeper tip (lamports)
+    pub tip_lamports: Option<u64>,
+    /// New fee cap (lamports); zero removes the cap
+    pub max_fee_per_execution: Option<u64>,
+    /// New name, tags and metadata URI, replacing the current ones
+    pub metadata: Option<JobMetadataParams>,
+    /// New minimum keeper tier; zero allows any keeper
+    pub min_keeper_tier: Option<u8>,
+    /// New cap on transaction fees repaid to the keeper (lamports); zero repays only the record rent
+    pub max_fee_reimbursement: Option<u64>,
+    /// New expiry timestamp; zero removes the expiry
+    pub end_at: Option<i64>,
 }
 
 /// Keeper account state
@@ -106,6 +296,20 @@ pub struct Keeper {
     pub total_earnings: u64,
     /// Registration timestamp
     pub registered_at: u64,
+    /// Lamports backing delegator shares, including unbonding
+    pub delegated_stake: u64,
+    /// Outstanding delegator shares
+    pub delegator_shares: u64,
+    /// Delegator shares waiting out the unbonding period
+    pub unbonding_shares: u64,
+    /// Keeper's cut of delegator rewards (basis points)
+    pub commission_bps: u16,
+    /// Transaction costs repaid by jobs, kept apart from earnings
+    pub total_reimbursed: u64,
+    /// Commission increase waiting out its delay (basis points)
+    pub pending_commission_bps: u16,
+    /// When the pending commission applies (0 = none queued)
+    pub commission_effective_at: i64,
 }
 
 /// Registry state account
@@ -137,9 +341,274 @@ pub struct RegistryState {
     pub total_fees_collected: u64,
     /// Registry creation timestamp
     pub created_at: u64,
+    /// Executions a keeper may perform per rate limit window (0 disables)
+    pub max_executions_per_window: u32,
+    /// Rate limit window length in slots
+    pub rate_limit_window_slots: u64,
+    /// Seconds after a keeper's last execution before it may unregister
+    pub unregister_cooldown: i64,
+    /// Key allowed to propose fee, stake and rate limit changes
+    pub fee_manager: Pubkey,
+    /// Key allowed to slash keepers
+    pub slasher: Pubkey,
+    /// Key allowed to pause the registry
+    pub pauser: Pubkey,
+    /// Key allowed to change the parameter timelock
+    pub upgrader: Pubkey,
+    /// Delay in seconds before proposed parameters can be executed
+    pub params_timelock: i64,
+    /// Seconds a keeper has to appeal a proposed slash
+    pub slash_dispute_window: i64,
+    /// Slashes applied, used as slash IDs
+    pub slash_count: u64,
+    /// Share of slashed funds sent to the insurance pool (basis points)
+    pub insurance_slash_share_bps: u16,
+    /// Share of protocol fees sent to the insurance pool (basis points)
+    pub insurance_fee_share_bps: u16,
+    /// Total lamports paid into the insurance pool
+    pub insurance_deposits: u64,
+    /// Total lamports paid out to job owners
+    pub insurance_payouts: u64,
+    /// Seconds undelegated stake stays slashable before withdrawal
+    pub delegation_unbonding_period: i64,
+    /// Keeper tiers 1..=len in ascending order (tier 0 is any keeper)
+    pub keeper_tiers: Vec<KeeperTier>,
+    /// Seconds a drained inactive job waits before it can be reaped
+    pub job_reap_delay: i64,
+    /// Share of a reaped job's rent paid to the caller (basis points)
+    pub reap_bounty_bps: u16,
+}
+
+/// Stake and reputation a keeper needs to reach a tier
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct KeeperTier {
+    /// Minimum effective stake, including delegations (lamports)
+    pub min_stake: u64,
+    /// Minimum reputation score (0-10000)
+    pub min_reputation: u64,
 }
 
 /// Execution record for tracking job runs
+/// Governance roles held in the registry
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub enum RegistryRole {
+    /// Proposes fee, stake and rate limit changes
+    FeeManager,
+    /// Slashes misbehaving keepers
+    Slasher,
+    /// Pauses and unpauses the registry
+    Pauser,
+    /// Changes the parameter timelock
+    Upgrader,
+}
+
+/// Registry parameter changes queued behind the timelock
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct RegistryParams {
+    /// New base fee (lamports)
+    pub base_fee: Option<u64>,
+    /// New minimum keeper stake (lamports)
+    pub min_stake: Option<u64>,
+    /// New protocol fee rate (basis points)
+    pub protocol_fee_bps: Option<u16>,
+    /// New per-keeper execution limit per window (0 disables)
+    pub max_executions_per_window: Option<u32>,
+    /// New rate limit window length in slots
+    pub rate_limit_window_slots: Option<u64>,
+    /// New keeper unregister cooldown in seconds
+    pub unregister_cooldown: Option<i64>,
+    /// New parameter timelock in seconds
+    pub params_timelock: Option<i64>,
+    /// New slash dispute window in seconds
+    pub slash_dispute_window: Option<i64>,
+    /// New insurance share of slashes (basis points)
+    pub insurance_slash_share_bps: Option<u16>,
+    /// New insurance share of protocol fees (basis points)
+    pub insurance_fee_share_bps: Option<u16>,
+    /// New delegation unbonding period in seconds
+    pub delegation_unbonding_period: Option<i64>,
+    /// New keeper tier ladder, replacing the current one
+    pub keeper_tiers: Option<Vec<KeeperTier>>,
+    /// New wait in seconds before a drained inactive job can be reaped
+    pub job_reap_delay: Option<i64>,
+    /// New share of a reaped job's rent paid to the caller (basis points)
+    pub reap_bounty_bps: Option<u16>,
+}
+
+/// Queued parameter change account
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct PendingParams {
+    /// Role holder that queued the change
+    pub proposer: Pubkey,
+    /// Queued parameter changes
+    pub params: RegistryParams,
+    /// Proposal timestamp
+    pub proposed_at: i64,
+    /// Earliest execution timestamp
+    pub executable_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Lamports shared by many jobs of one owner, debited when those jobs execute
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct FundingPool {
+    /// Pool owner, also the owner of its jobs
+    pub owner: Pubkey,
+    /// Owner-chosen pool identifier
+    pub pool_id: u64,
+    /// Lamports available for execution fees
+    pub balance: u64,
+    /// Balance executions may not draw below
+    pub min_balance: u64,
+    /// Lamports paid out in execution fees
+    pub total_spent: u64,
+    /// Creation timestamp
+    pub created_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Job name, tags and metadata URI, stored apart from the job account
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct JobMetadata {
+    /// Described job
+    pub job_id: u64,
+    /// Display name
+    pub name: String,
+    /// Labels jobs can be filtered by
+    pub tags: Vec<String>,
+    /// Off-chain metadata document
+    pub metadata_uri: String,
+    /// Last update timestamp
+    pub updated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+impl JobMetadata {
+    /// Whether the job carries `tag`
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+}
+
+/// A third party's contribution to a job, held as shares of the job balance
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct Sponsorship {
+    /// Sponsored job
+    pub job_id: u64,
+    /// Sponsor's wallet
+    pub sponsor: Pubkey,
+    /// Shares of the job balance held
+    pub shares: u64,
+    /// Lamports contributed in total
+    pub contributed: u64,
+    /// First contribution timestamp
+    pub sponsored_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+    /// Job funding round the shares belong to; shares from an earlier round are worth nothing
+    pub funding_round: u64,
+}
+
+/// Stake delegated to a keeper, held as shares of its delegation pool
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct Delegation {
+    /// Keeper PDA delegated to
+    pub keeper: Pubkey,
+    /// Delegator's wallet
+    pub delegator: Pubkey,
+    /// Pool shares held, including unbonding
+    pub shares: u64,
+    /// Shares waiting out the unbonding period
+    pub unbonding_shares: u64,
+    /// When unbonding shares can be withdrawn
+    pub unbonding_release_at: i64,
+    /// First delegation timestamp
+    pub delegated_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Pool compensating job owners harmed by slashed keepers
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct InsurancePool {
+    /// Lamports available for payouts
+    pub balance: u64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Insurance payout approved for a job owner
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct InsuranceClaim {
+    /// Execution the owner was harmed by
+    pub execution_record: Pubkey,
+    /// Job the execution belongs to
+    pub job_id: u64,
+    /// Job owner that was paid
+    pub owner: Pubkey,
+    /// Keeper that performed the execution
+    pub keeper: Pubkey,
+    /// Slash the claim is backed by
+    pub slash_id: u64,
+    /// Lamports paid out
+    pub amount: u64,
+    /// Admin that approved the payout
+    pub approved_by: Pubkey,
+    /// Approval timestamp
+    pub approved_at: i64,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Proposed slash escrowed until its dispute window closes
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct PendingSlash {
+    /// Keeper PDA being slashed
+    pub keeper: Pubkey,
+    /// Keeper's wallet, which may appeal
+    pub keeper_address: Pubkey,
+    /// Role holder that proposed the slash
+    pub slasher: Pubkey,
+    /// Escrowed from the keeper's stake (lamports)
+    pub stake_amount: u64,
+    /// Escrowed from the keeper's pending rewards (lamports)
+    pub rewards_amount: u64,
+    /// Escrowed pro-rata from delegated stake (lamports)
+    pub delegated_amount: u64,
+    /// Slasher's reason
+    pub reason: String,
+    /// Proposal timestamp
+    pub proposed_at: i64,
+    /// Last moment the keeper may appeal
+    pub dispute_deadline: i64,
+    /// Keeper's appeal, if submitted
+    pub appeal: Option<String>,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Applied slash, which can back at most one insurance claim
+#[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct SlashRecord {
+    /// Sequential slash ID
+    pub slash_id: u64,
+    /// Slashed keeper's wallet
+    pub keeper: Pubkey,
+    /// Lamports slashed
+    pub amount: u64,
+    /// Share sent to the insurance pool (lamports)
+    pub insurance_amount: u64,
+    /// When the slash was applied
+    pub slashed_at: i64,
+    /// Whether an insurance claim has used it
+    pub claimed: bool,
+    /// PDA bump seed
+    pub bump: u8,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
 pub struct ExecutionRecord {
     /// Job ID that was executed
@@ -154,8 +623,10 @@ pub struct ExecutionRecord {
     pub gas_used: u64,
     /// Fee charged for execution
     pub fee_charged: u64,
-    /// Error message if execution failed
-    pub error_message: String,
+    /// Program error code if execution failed
+    pub error_code: Option<u32>,
+    /// Return data set by the target program, truncated by the registry
+    pub return_data: Vec<u8>,
 }
 
 /// Statistics for a keeper
@@ -167,6 +638,8 @@ pub struct KeeperStats {
     pub failed_executions: u64,
     pub success_rate: f64, // 0.0 - 1.0
     pub total_earnings: u64,
+    /// Transaction costs repaid by jobs, not counted in earnings
+    pub total_reimbursed: u64,
     pub reputation_score: u64,
     pub stake_amount: u64,
     pub is_active: bool,
@@ -206,6 +679,41 @@ pub struct ExecutionResult {
     pub fee_charged: u64,
     pub execution_time: u64,
     pub error: Option<String>,
+    /// Return data set by the target program, e.g. a harvested amount
+    pub return_data: Vec<u8>,
+}
+
+/// Whether a job can run now and when it is next scheduled, as computed on-chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct JobStatus {
+    pub job_id: u64,
+    pub is_active: bool,
+    pub is_paused: bool,
+    /// Active, not paused, the trigger is satisfied, and `execute_job` would
+    /// accept it: not expired, within the keeper's tier and the fee cap, and
+    /// funded for the largest reimbursement
+    pub is_due: bool,
+    /// Scheduled run time for time-based jobs
+    pub next_due_at: Option<i64>,
+    /// Scheduled run slot for slot- and epoch-based jobs
+    pub next_due_slot: Option<u64>,
+    pub execution_fee: u64,
+    pub keeper_reward: u64,
+    /// Lowest keeper tier allowed to execute the job
+    pub min_keeper_tier: u8,
+}
+
+/// Fee charged for a job's next execution and how it is split, as computed on-chain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AnchorSerialize, AnchorDeserialize)]
+pub struct ExecutionQuote {
+    pub job_id: u64,
+    pub execution_fee: u64,
+    pub keeper_reward: u64,
+    pub protocol_fee: u64,
+    /// Most the keeper can be repaid for transaction fees and record rent
+    pub max_reimbursement: u64,
+    /// Whether the job can pay the fee and the largest reimbursement and stay at its minimum balance
+    pub is_funded: bool,
 }
 
 /// Trigger evaluation result
@@ -217,6 +725,14 @@ pub struct TriggerEvaluation {
 }
 
 impl AutomationJob {
+    /// Account the registry reads to evaluate this job's trigger, if any
+    pub fn trigger_account(&self) -> Option<Pubkey> {
+        match &self.trigger_type {
+            TriggerType::AccountChange { account } => Some(*account),
+            _ => None,
+        }
+    }
+
     /// Calculate the space required for this account
     pub const ACCOUNT_SIZE: usize = 8 + // discriminator
         8 +  // job_id
@@ -231,28 +747,50 @@ impl AutomationJob {
         1 +  // is_active
         8 +  // execution_count
         8 +  // last_execution
-        8;   // created_at
+        8 +  // created_at
+        1;   // is_paused
 
-    /// Check if the job can be executed (has sufficient balance)
+    /// Fee charged per execution: the registry's base fee plus the job's tip
+    pub fn execution_fee(&self, base_fee: u64) -> u64 {
+        base_fee.saturating_add(self.tip_lamports)
+    }
+
+    /// Check if the job can be executed (has sufficient balance and the fee is within its cap)
+    /// 
+    /// For pooled jobs only the spend cap is checked here; the pool balance is
+    /// checked by `SolCronClient::quote_execution`.
     pub fn can_execute(&self, execution_fee: u64) -> bool {
+        let funded = match self.funding_pool {
+            Some(_) => self.pool_spend_cap == 0 || self.pool_spent.saturating_add(execution_fee) <= self.pool_spend_cap,
+            None => self.balance >= execution_fee && self.balance >= self.min_balance,
+        };
+
         self.is_active && 
-        self.balance >= execution_fee && 
-        self.balance >= self.min_balance
+        !self.is_paused && 
+        funded &&
+        (self.max_fee_per_execution == 0 || execution_fee <= self.max_fee_per_execution)
+    }
+
+    /// True once the job is past its end time
+    pub fn is_expired(&self, now: i64) -> bool {
+        self.end_at != 0 && now >= self.end_at
+    }
+
+    /// Whether anyone may reap the job, as checked on-chain; jobs with
+    /// outstanding sponsorships are never reapable
+    pub fn is_reapable(&self, now: i64, reap_delay: i64) -> bool {
+        let dead = self.is_expired(now)
+            || (!self.is_active && self.balance == 0 && now >= self.deactivated_at.saturating_add(reap_delay));
+        dead && self.sponsor_shares == 0
     }
 
     /// Get time until next execution for time-based jobs
+    #[deprecated(note = "use `SolCronClient::job_status`, which runs the on-chain logic")]
     pub fn time_until_next_execution(&self, current_time: u64) -> Option<u64> {
         match &self.trigger_type {
-            TriggerType::TimeBased { interval } => {
-                if self.last_execution == 0 {
-                    return Some(0); // Can execute immediately
-                }
-                let next_execution = self.last_execution + interval;
-                if current_time >= next_execution {
-                    Some(0) // Can execute now
-                } else {
-                    Some(next_execution - current_time)
-                }
+            TriggerType::TimeBased { .. } => {
+                // Runs are anchored to the on-chain schedule, not the last execution
+                Some(self.next_scheduled_time.saturating_sub(current_time))
             }
             _ => None,
         }
@@ -270,7 +808,26 @@ impl Keeper {
         8 +  // failed_executions
         8 +  // pending_rewards
         8 +  // total_earnings
-        8;   // registered_at
+        8 +  // registered_at
+        8 +  // delegated_stake
+        8 +  // delegator_shares
+        8 +  // unbonding_shares
+        2;   // commission_bps
+
+    /// Own stake plus delegated stake that is not unbonding
+    pub fn effective_stake(&self) -> u64 {
+        let unbonding = self.delegated_value(self.unbonding_shares);
+        self.stake_amount
+            .saturating_add(self.delegated_stake.saturating_sub(unbonding))
+    }
+
+    /// Lamports currently backing `shares` of the delegation pool
+    pub fn delegated_value(&self, shares: u64) -> u64 {
+        if self.delegator_shares == 0 {
+            return 0;
+        }
+        (shares as u128 * self.delegated_stake as u128 / self.delegator_shares as u128) as u64
+    }
 
     /// Calculate success rate as a percentage (0-100)
     pub fn success_rate(&self) -> f64 {
@@ -324,7 +881,19 @@ impl RegistryState {
         8 +  // active_keepers
         8 +  // total_executions
         8 +  // total_fees_collected
-        8;   // created_at
+        8 +  // created_at
+        4 +  // max_executions_per_window
+        8 +  // rate_limit_window_slots
+        8;   // unregister_cooldown
+
+    /// Highest tier whose stake and reputation thresholds the keeper meets, as checked on-chain
+    pub fn keeper_tier(&self, keeper: &Keeper) -> u8 {
+        let stake = keeper.effective_stake();
+        self.keeper_tiers
+            .iter()
+            .take_while(|tier| stake >= tier.min_stake && keeper.reputation_score >= tier.min_reputation)
+            .count() as u8
+    }
 
     /// Calculate execution fee for a job
     pub fn calculate_execution_fee(&self, gas_used: u64) -> u64 {
@@ -342,6 +911,13 @@ impl RegistryState {
     }
 }
 
+impl ExecutionRecord {
+    /// Human readable description of the recorded error code
+    pub fn error_description(&self) -> Option<String> {
+        self.error_code.map(crate::error::SolCronErrorCode::describe)
+    }
+}
+
 impl Default for TriggerType {
     fn default() -> Self {
         TriggerType::TimeBased { interval: 3600 } // 1 hour default
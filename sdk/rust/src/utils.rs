@@ -113,6 +113,22 @@ impl Utils {
                     });
                 }
             }
+            TriggerType::SlotInterval { interval } => {
+                if *interval == 0 {
+                    return Err(SolCronError::InvalidTrigger {
+                        reason: "Slot interval cannot be zero".to_string(),
+                    });
+                }
+            }
+            TriggerType::EpochStart { .. } => {}
+            TriggerType::AccountChange { .. } => {}
+            TriggerType::PriceThreshold { max_staleness, .. } => {
+                if *max_staleness == 0 {
+                    return Err(SolCronError::InvalidTrigger {
+                        reason: "Price max staleness cannot be zero".to_string(),
+                    });
+                }
+            }
         }
 
         // Validate trigger params length
@@ -170,6 +186,43 @@ impl Utils {
         // Validate trigger
         Self::validate_trigger_params(&params.trigger_type, &params.trigger_params)?;
 
+        if let Some(metadata) = &params.metadata {
+            Self::validate_job_metadata(metadata)?;
+        }
+
+        Ok(())
+    }
+
+    /// Validate job metadata against the registry's size limits
+    pub fn validate_job_metadata(metadata: &JobMetadataParams) -> SolCronResult<()> {
+        if metadata.name.len() > 64 {
+            return Err(SolCronError::ValidationError {
+                field: "name".to_string(),
+                reason: "Too long (max 64 bytes)".to_string(),
+            });
+        }
+
+        if metadata.tags.len() > 8 {
+            return Err(SolCronError::ValidationError {
+                field: "tags".to_string(),
+                reason: "Too many tags (max 8)".to_string(),
+            });
+        }
+
+        if metadata.tags.iter().any(|tag| tag.is_empty() || tag.len() > 32) {
+            return Err(SolCronError::ValidationError {
+                field: "tags".to_string(),
+                reason: "Tags must be 1 to 32 bytes".to_string(),
+            });
+        }
+
+        if metadata.metadata_uri.len() > 200 {
+            return Err(SolCronError::ValidationError {
+                field: "metadata_uri".to_string(),
+                reason: "Too long (max 200 bytes)".to_string(),
+            });
+        }
+
         Ok(())
     }
 
@@ -475,6 +528,7 @@ mod tests {
             trigger_params: vec![],
             gas_limit: 200_000,
             min_balance: 1_000_000,
+            metadata: None,
         };
 
         assert!(Utils::validate_job_params(&valid_params).is_ok());
@@ -4,9 +4,13 @@ import {
   PublicKey,
   Keypair,
   SystemProgram,
+  SYSVAR_INSTRUCTIONS_PUBKEY,
   LAMPORTS_PER_SOL,
+  Transaction,
+  sendAndConfirmTransaction,
 } from "@solana/web3.js";
 import { assert } from "chai";
+import * as fs from "fs";
 
 describe("SolCron Registry - Full Integration Tests", () => {
   // Configure the client to use the local cluster
@@ -14,6 +18,9 @@ describe("SolCron Registry - Full Integration Tests", () => {
   
   const provider = anchor.AnchorProvider.env();
     const registryProgram = anchor.workspace.SolcronRegistry;
+  const executionProgramId: PublicKey = anchor.workspace.SolcronExecution.programId;
+  // Jobs target the workspace's solcron program, whose `initialize` always succeeds
+  const targetProgram: PublicKey = anchor.workspace.Solcron.programId;
   
   // Test accounts
   let admin: Keypair;
@@ -22,11 +29,12 @@ describe("SolCron Registry - Full Integration Tests", () => {
   let user2: Keypair;
   let keeper1: Keypair;
   let keeper2: Keypair;
-  let targetProgram: Keypair;
   
   // PDAs
   let registryState: PublicKey;
   let registryStateBump: number;
+  let executionAuthority: PublicKey;
+  let insurancePool: PublicKey;
 
   // Helper functions
   const getAutomationJobPDA = (jobId: number): [PublicKey, number] => {
@@ -36,6 +44,13 @@ describe("SolCron Registry - Full Integration Tests", () => {
     );
   };
 
+  const getJobMetadataPDA = (jobId: number): [PublicKey, number] => {
+    return PublicKey.findProgramAddressSync(
+      [Buffer.from("job_metadata"), new anchor.BN(jobId).toArrayLike(Buffer, "le", 8)],
+      registryProgram.programId
+    );
+  };
+
   const getKeeperPDA = (keeperAddress: PublicKey): [PublicKey, number] => {
     return PublicKey.findProgramAddressSync(
       [Buffer.from("keeper"), keeperAddress.toBuffer()],
@@ -54,6 +69,36 @@ describe("SolCron Registry - Full Integration Tests", () => {
     );
   };
 
+  // Job update that leaves every field unchanged
+  const noJobUpdate = {
+    targetProgram: null,
+    targetInstruction: null,
+    triggerType: null,
+    triggerParams: null,
+    targetAccounts: null,
+    gasLimit: null,
+    minBalance: null,
+    gracePeriod: null,
+    catchUpPolicy: null,
+    checkInstruction: null,
+    tipLamports: null,
+    maxFeePerExecution: null,
+    metadata: null,
+    minKeeperTier: null,
+    maxFeeReimbursement: null,
+    endAt: null,
+  };
+
+  // Accounts forwarded to the target instruction, which reads none
+  const targetAccounts = [{ pubkey: targetProgram, isSigner: false, isWritable: false }];
+
+  // Sleep until a time-based job's next scheduled run
+  const waitUntilDue = async (jobAccount: PublicKey) => {
+    const job = await registryProgram.account.automationJob.fetch(jobAccount);
+    const delay = job.nextScheduledTime.toNumber() * 1000 - Date.now();
+    await new Promise(resolve => setTimeout(resolve, Math.max(delay, 0) + 1000));
+  };
+
   before(async () => {
     // Generate test keypairs
     admin = Keypair.generate();
@@ -62,7 +107,6 @@ describe("SolCron Registry - Full Integration Tests", () => {
     user2 = Keypair.generate();
     keeper1 = Keypair.generate();
     keeper2 = Keypair.generate();
-    targetProgram = Keypair.generate();
 
     // Derive registry state PDA
     [registryState, registryStateBump] = PublicKey.findProgramAddressSync(
@@ -70,6 +114,17 @@ describe("SolCron Registry - Full Integration Tests", () => {
       registryProgram.programId
     );
 
+    // Registry PDA that signs execution engine CPIs
+    [executionAuthority] = PublicKey.findProgramAddressSync(
+      [Buffer.from("execution_authority")],
+      registryProgram.programId
+    );
+
+    [insurancePool] = PublicKey.findProgramAddressSync(
+      [Buffer.from("insurance_pool")],
+      registryProgram.programId
+    );
+
     // Fund all test accounts
     const accounts = [admin, treasury, user1, user2, keeper1, keeper2];
     for (const account of accounts) {
@@ -113,6 +168,22 @@ describe("SolCron Registry - Full Integration Tests", () => {
       assert.equal(registryAccount.nextJobId.toNumber(), 1);
       assert.equal(registryAccount.totalJobs.toNumber(), 0);
       assert.equal(registryAccount.activeJobs.toNumber(), 0);
+      assert.equal(registryAccount.version, 8);
+      assert.equal(registryAccount.feeManager.toString(), admin.publicKey.toString());
+      assert.equal(registryAccount.slasher.toString(), admin.publicKey.toString());
+      assert.equal(registryAccount.pauser.toString(), admin.publicKey.toString());
+      assert.equal(registryAccount.upgrader.toString(), admin.publicKey.toString());
+      assert.equal(registryAccount.paramsTimelock.toNumber(), 86400);
+      assert.equal(registryAccount.slashDisputeWindow.toNumber(), 259200);
+      assert.equal(registryAccount.insuranceSlashShareBps, 5000);
+      assert.equal(registryAccount.insuranceFeeShareBps, 1000);
+      assert.equal(registryAccount.delegationUnbondingPeriod.toNumber(), 604800);
+      assert.equal(registryAccount.keeperTiers.length, 0);
+      assert.equal(registryAccount.jobReapDelay.toNumber(), 2592000);
+      assert.equal(registryAccount.reapBountyBps, 1000);
+      assert.equal(registryAccount.maxExecutionsPerWindow, 10);
+      assert.equal(registryAccount.rateLimitWindowSlots.toNumber(), 150);
+      assert.equal(registryAccount.unregisterCooldown.toNumber(), 86400);
     });
 
     it("Should fail to initialize registry twice", async () => {
@@ -138,11 +209,45 @@ describe("SolCron Registry - Full Integration Tests", () => {
         assert.include(error.toString(), "already in use");
       }
     });
+
+    it("Should initialize the insurance pool", async () => {
+      await registryProgram.methods
+        .initializeInsurancePool()
+        .accounts({
+          registryState: registryState,
+          insurancePool: insurancePool,
+          admin: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pool = await registryProgram.account.insurancePool.fetch(insurancePool);
+      assert.equal(pool.balance.toNumber(), 0);
+    });
+
+    it("Should reject migrating an account already at the current version", async () => {
+      try {
+        await registryProgram.methods
+          .migrateRegistry()
+          .accounts({
+            account: registryState,
+            payer: provider.wallet.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .rpc();
+
+        assert.fail("Current accounts should not be migrated again");
+      } catch (error) {
+        assert.include(error.toString(), "AccountAlreadyMigrated");
+      }
+    });
   });
 
   describe("Job Registration", () => {
     it("Should register a time-based job successfully", async () => {
       const [jobAccount] = getAutomationJobPDA(1);
+      const [jobMetadata] = getJobMetadataPDA(1);
       
       const triggerType = {
         timeBased: { interval: new anchor.BN(3600) }
@@ -150,18 +255,20 @@ describe("SolCron Registry - Full Integration Tests", () => {
       const triggerParams = Buffer.from(JSON.stringify({ interval: 3600 }));
 
       await registryProgram.methods
-        .registerJob(
-          targetProgram.publicKey,
-          "harvest",
+        .registerJob({
+          targetProgram,
+          targetInstruction: "harvest",
           triggerType,
           triggerParams,
-          new anchor.BN(200_000), // gas limit
-          new anchor.BN(1_000_000), // min balance (0.001 SOL)
-          new anchor.BN(100_000_000) // initial funding (0.1 SOL)
-        )
+          gasLimit: new anchor.BN(200_000),
+          minBalance: new anchor.BN(1_000_000), // 0.001 SOL
+          initialFunding: new anchor.BN(100_000_000), // 0.1 SOL
+          metadata: { name: "Hourly harvest", tags: ["defi", "harvest"], metadataUri: "https://example.com/jobs/1.json" },
+        })
         .accounts({
           registryState: registryState,
           automationJob: jobAccount,
+          jobMetadata: jobMetadata,
           owner: user1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -172,7 +279,7 @@ describe("SolCron Registry - Full Integration Tests", () => {
       const job = await registryProgram.account.automationJob.fetch(jobAccount);
       assert.equal(job.jobId.toNumber(), 1);
       assert.equal(job.owner.toString(), user1.publicKey.toString());
-      assert.equal(job.targetProgram.toString(), targetProgram.publicKey.toString());
+      assert.equal(job.targetProgram.toString(), targetProgram.toString());
       assert.equal(job.targetInstruction, "harvest");
       assert.equal(job.balance.toNumber(), 100_000_000);
       assert.equal(job.gasLimit.toNumber(), 200_000);
@@ -180,6 +287,13 @@ describe("SolCron Registry - Full Integration Tests", () => {
       assert.isTrue(job.isActive);
       assert.equal(job.executionCount.toNumber(), 0);
 
+      // Verify metadata stored beside the job
+      const metadata = await registryProgram.account.jobMetadata.fetch(jobMetadata);
+      assert.equal(metadata.jobId.toNumber(), 1);
+      assert.equal(metadata.name, "Hourly harvest");
+      assert.deepEqual(metadata.tags, ["defi", "harvest"]);
+      assert.equal(metadata.metadataUri, "https://example.com/jobs/1.json");
+
       // Verify registry state updated
       const registry = await registryProgram.account.registryState.fetch(registryState);
       assert.equal(registry.nextJobId.toNumber(), 2);
@@ -196,15 +310,16 @@ describe("SolCron Registry - Full Integration Tests", () => {
       const triggerParams = Buffer.from(JSON.stringify({ condition: "balance > 1000000" }));
 
       await registryProgram.methods
-        .registerJob(
-          targetProgram.publicKey,
-          "liquidate",
+        .registerJob({
+          targetProgram,
+          targetInstruction: "liquidate",
           triggerType,
           triggerParams,
-          new anchor.BN(300_000),
-          new anchor.BN(1_000_000),
-          new anchor.BN(200_000_000) // 0.2 SOL
-        )
+          gasLimit: new anchor.BN(300_000),
+          minBalance: new anchor.BN(1_000_000),
+          initialFunding: new anchor.BN(200_000_000), // 0.2 SOL
+          metadata: null,
+        })
         .accounts({
           registryState: registryState,
           automationJob: jobAccount,
@@ -230,15 +345,16 @@ describe("SolCron Registry - Full Integration Tests", () => {
 
       try {
         await registryProgram.methods
-          .registerJob(
-            targetProgram.publicKey,
-            "test",
+          .registerJob({
+            targetProgram,
+            targetInstruction: "test",
             triggerType,
             triggerParams,
-            new anchor.BN(200_000),
-            new anchor.BN(10_000_000), // min balance 0.01 SOL
-            new anchor.BN(5_000_000)   // initial funding 0.005 SOL (less than min)
-          )
+            gasLimit: new anchor.BN(200_000),
+            minBalance: new anchor.BN(10_000_000), // 0.01 SOL
+            initialFunding: new anchor.BN(5_000_000), // 0.005 SOL (less than min)
+            metadata: null,
+          })
           .accounts({
             registryState: registryState,
             automationJob: jobAccount,
@@ -258,11 +374,16 @@ describe("SolCron Registry - Full Integration Tests", () => {
   describe("Job Management", () => {
     let jobId: number;
     let jobAccount: PublicKey;
+    let sponsorship: PublicKey;
 
     before(async () => {
       // Register a job for testing
       jobId = 3;
       [jobAccount] = getAutomationJobPDA(jobId);
+      [sponsorship] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sponsorship"), jobAccount.toBuffer(), user2.publicKey.toBuffer()],
+        registryProgram.programId
+      );
       
       const triggerType = {
         timeBased: { interval: new anchor.BN(3600) }
@@ -270,15 +391,16 @@ describe("SolCron Registry - Full Integration Tests", () => {
       const triggerParams = Buffer.from(JSON.stringify({ interval: 3600 }));
 
       await registryProgram.methods
-        .registerJob(
-          targetProgram.publicKey,
-          "test_job",
+        .registerJob({
+          targetProgram,
+          targetInstruction: "test_job",
           triggerType,
           triggerParams,
-          new anchor.BN(200_000),
-          new anchor.BN(1_000_000),
-          new anchor.BN(50_000_000) // 0.05 SOL
-        )
+          gasLimit: new anchor.BN(200_000),
+          minBalance: new anchor.BN(1_000_000),
+          initialFunding: new anchor.BN(50_000_000), // 0.05 SOL
+          metadata: null,
+        })
         .accounts({
           registryState: registryState,
           automationJob: jobAccount,
@@ -297,6 +419,7 @@ describe("SolCron Registry - Full Integration Tests", () => {
         .fundJob(fundingAmount)
         .accounts({
           automationJob: jobAccount,
+          sponsorship: null, // Owners fund without a sponsorship
           funder: user1.publicKey,
           systemProgram: SystemProgram.programId,
         })
@@ -315,7 +438,7 @@ describe("SolCron Registry - Full Integration Tests", () => {
       const newMinBalance = new anchor.BN(2_000_000);
 
       await registryProgram.methods
-        .updateJob(newGasLimit, newMinBalance, null)
+        .updateJob({ ...noJobUpdate, gasLimit: newGasLimit, minBalance: newMinBalance })
         .accounts({
           automationJob: jobAccount,
           owner: user1.publicKey,
@@ -328,349 +451,2669 @@ describe("SolCron Registry - Full Integration Tests", () => {
       assert.equal(job.minBalance.toNumber(), 2_000_000);
     });
 
-    it("Should cancel a job and refund balance", async () => {
-      const initialUserBalance = await provider.connection.getBalance(user1.publicKey);
-      const jobBalance = (await registryProgram.account.automationJob.fetch(jobAccount)).balance;
+    it("Should set and resize job metadata on update", async () => {
+      const [jobMetadata] = getJobMetadataPDA(jobId);
 
       await registryProgram.methods
-        .cancelJob()
+        .updateJob({ ...noJobUpdate, metadata: { name: "Test job", tags: ["test"], metadataUri: "" } })
         .accounts({
-          registryState: registryState,
           automationJob: jobAccount,
+          jobMetadata: jobMetadata,
           owner: user1.publicKey,
           systemProgram: SystemProgram.programId,
         })
         .signers([user1])
         .rpc();
 
-      // Check job is inactive
-      const job = await registryProgram.account.automationJob.fetch(jobAccount);
-      assert.isFalse(job.isActive);
-      assert.equal(job.balance.toNumber(), 0);
+      const initialSize = (await provider.connection.getAccountInfo(jobMetadata)).data.length;
 
-      // Check registry state updated
-      const registry = await registryProgram.account.registryState.fetch(registryState);
-      assert.equal(registry.activeJobs.toNumber(), 2); // Should decrease by 1
+      const metadataUri = "ipfs://bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, metadata: { name: "Test job", tags: ["test", "crank"], metadataUri } })
+        .accounts({
+          automationJob: jobAccount,
+          jobMetadata: jobMetadata,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      const metadata = await registryProgram.account.jobMetadata.fetch(jobMetadata);
+      assert.deepEqual(metadata.tags, ["test", "crank"]);
+      assert.equal(metadata.metadataUri, metadataUri);
+
+      // The account grows to fit the new tags and URI
+      const size = (await provider.connection.getAccountInfo(jobMetadata)).data.length;
+      assert.equal(size, initialSize + 4 + "crank".length + metadataUri.length);
     });
 
-    it("Should fail to update cancelled job", async () => {
+    it("Should reject oversized job metadata", async () => {
+      const [jobMetadata] = getJobMetadataPDA(jobId);
+
       try {
         await registryProgram.methods
-          .updateJob(new anchor.BN(300_000), null, null)
+          .updateJob({ ...noJobUpdate, metadata: { name: "x".repeat(65), tags: [], metadataUri: "" } })
           .accounts({
             automationJob: jobAccount,
+            jobMetadata: jobMetadata,
             owner: user1.publicKey,
+            systemProgram: SystemProgram.programId,
           })
           .signers([user1])
           .rpc();
 
-        assert.fail("Should not be able to update cancelled job");
+        assert.fail("Should not accept a name over 64 bytes");
       } catch (error) {
-        assert.include(error.toString(), "InvalidJob");
+        assert.include(error.toString(), "InvalidMetadata");
       }
     });
-  });
 
-  describe("Keeper Registration", () => {
-    it("Should register keeper successfully", async () => {
-      const [keeperAccount] = getKeeperPDA(keeper1.publicKey);
-      const stakeAmount = new anchor.BN(2 * LAMPORTS_PER_SOL); // 2 SOL
+    it("Should reconfigure target and trigger without losing the job ID", async () => {
+      const newTarget = Keypair.generate().publicKey;
+      const storedAccount = Keypair.generate().publicKey;
 
       await registryProgram.methods
-        .registerKeeper(stakeAmount)
+        .updateJob({
+          ...noJobUpdate,
+          targetProgram: newTarget,
+          targetInstruction: "reconfigured_job",
+          triggerType: { conditional: {} },
+          triggerParams: Buffer.from([1]),
+          targetAccounts: [{ pubkey: storedAccount, isSigner: false, isWritable: true }],
+        })
         .accounts({
-          registryState: registryState,
-          keeper: keeperAccount,
-          keeperAccount: keeper1.publicKey,
-          systemProgram: SystemProgram.programId,
+          automationJob: jobAccount,
+          owner: user1.publicKey,
         })
-        .signers([keeper1])
+        .signers([user1])
         .rpc();
 
-      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
-      assert.equal(keeper.address.toString(), keeper1.publicKey.toString());
-      assert.equal(keeper.stakeAmount.toNumber(), 2 * LAMPORTS_PER_SOL);
-      assert.equal(keeper.reputationScore.toNumber(), 5000); // Starting reputation
-      assert.isTrue(keeper.isActive);
-      assert.equal(keeper.successfulExecutions.toNumber(), 0);
-      assert.equal(keeper.failedExecutions.toNumber(), 0);
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.equal(job.jobId.toNumber(), jobId);
+      assert.equal(job.targetProgram.toString(), newTarget.toString());
+      assert.equal(job.targetInstruction, "reconfigured_job");
+      assert.deepEqual(job.triggerType, { conditional: {} });
+      assert.equal(job.targetAccounts.length, 1);
+      assert.equal(job.targetAccounts[0].pubkey.toString(), storedAccount.toString());
+    });
 
-      // Check registry state
-      const registry = await registryProgram.account.registryState.fetch(registryState);
-      assert.equal(registry.totalKeepers.toNumber(), 1);
-      assert.equal(registry.activeKeepers.toNumber(), 1);
+    it("Should reject reconfiguration that fails registration checks", async () => {
+      try {
+        await registryProgram.methods
+          .updateJob({ ...noJobUpdate, targetInstruction: "x".repeat(51) })
+          .accounts({
+            automationJob: jobAccount,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Should reject an oversized target instruction");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
+
+      try {
+        await registryProgram.methods
+          .updateJob({ ...noJobUpdate, triggerType: { timeBased: {} }, triggerParams: Buffer.from([]) })
+          .accounts({
+            automationJob: jobAccount,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Should reject a trigger without parameters");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
     });
 
-    it("Should fail to register keeper with insufficient stake", async () => {
-      const [keeperAccount] = getKeeperPDA(keeper2.publicKey);
-      const insufficientStake = new anchor.BN(LAMPORTS_PER_SOL / 2); // 0.5 SOL
+    it("Should reject privileged target accounts", async () => {
+      try {
+        await registryProgram.methods
+          .updateJob({
+            ...noJobUpdate,
+            targetAccounts: [{ pubkey: Keypair.generate().publicKey, isSigner: true, isWritable: false }],
+          })
+          .accounts({
+            automationJob: jobAccount,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Should reject a stored signer account");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidTargetAccount");
+      }
 
       try {
         await registryProgram.methods
-          .registerKeeper(insufficientStake)
+          .updateJob({
+            ...noJobUpdate,
+            targetAccounts: [{ pubkey: executionAuthority, isSigner: false, isWritable: false }],
+          })
           .accounts({
-            registryState: registryState,
-            keeper: keeperAccount,
-            keeperAccount: keeper2.publicKey,
-            systemProgram: SystemProgram.programId,
+            automationJob: jobAccount,
+            owner: user1.publicKey,
           })
-          .signers([keeper2])
+          .signers([user1])
           .rpc();
 
-        assert.fail("Should fail with insufficient stake");
+        assert.fail("Should reject the execution authority as a target account");
       } catch (error) {
-        assert.include(error.toString(), "InsufficientStake");
+        assert.include(error.toString(), "InvalidTargetAccount");
       }
     });
 
-    it("Should register second keeper successfully", async () => {
-      const [keeperAccount] = getKeeperPDA(keeper2.publicKey);
-      const stakeAmount = new anchor.BN(LAMPORTS_PER_SOL); // 1 SOL (minimum)
+    it("Should configure the grace window and catch-up policy", async () => {
+      let job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.equal(job.gracePeriod.toNumber(), 0);
+      assert.equal(job.missedExecutions.toNumber(), 0);
+      assert.deepEqual(job.catchUpPolicy, { runOnce: {} });
 
       await registryProgram.methods
-        .registerKeeper(stakeAmount)
+        .updateJob({ ...noJobUpdate, gracePeriod: new anchor.BN(30), catchUpPolicy: { backfillEach: {} } })
         .accounts({
-          registryState: registryState,
-          keeper: keeperAccount,
-          keeperAccount: keeper2.publicKey,
-          systemProgram: SystemProgram.programId,
+          automationJob: jobAccount,
+          owner: user1.publicKey,
         })
-        .signers([keeper2])
+        .signers([user1])
         .rpc();
 
-      const registry = await registryProgram.account.registryState.fetch(registryState);
-      assert.equal(registry.totalKeepers.toNumber(), 2);
-      assert.equal(registry.activeKeepers.toNumber(), 2);
-    });
-  });
+      job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.equal(job.gracePeriod.toNumber(), 30);
+      assert.deepEqual(job.catchUpPolicy, { backfillEach: {} });
 
-  describe("Job Execution", () => {
-    let executionJobId: number;
-    let executionJobAccount: PublicKey;
-    let keeperAccount: PublicKey;
+      try {
+        await registryProgram.methods
+          .updateJob({ ...noJobUpdate, gracePeriod: new anchor.BN(-1) })
+          .accounts({
+            automationJob: jobAccount,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
 
-    before(async () => {
-      // Register a job for execution testing
-      executionJobId = 4;
-      [executionJobAccount] = getAutomationJobPDA(executionJobId);
-      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
-      
-      const triggerType = {
-        timeBased: { interval: new anchor.BN(1) } // 1 second for testing
-      };
-      const triggerParams = Buffer.from(JSON.stringify({ interval: 1 }));
+        assert.fail("Should reject a negative grace period");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
+    });
+
+    it("Should set and remove a check instruction", async () => {
+      let job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.isNull(job.checkInstruction);
 
       await registryProgram.methods
-        .registerJob(
-          targetProgram.publicKey,
-          "execute_test",
-          triggerType,
-          triggerParams,
-          new anchor.BN(200_000),
-          new anchor.BN(1_000_000),
-          new anchor.BN(100_000_000) // 0.1 SOL
-        )
+        .updateJob({ ...noJobUpdate, checkInstruction: "check_upkeep" })
         .accounts({
-          registryState: registryState,
-          automationJob: executionJobAccount,
+          automationJob: jobAccount,
           owner: user1.publicKey,
-          systemProgram: SystemProgram.programId,
         })
         .signers([user1])
         .rpc();
-    });
-
-    it("Should execute job successfully", async () => {
-      const [executionRecord] = getExecutionRecordPDA(executionJobId, 0);
 
-      // Wait a bit to ensure time condition is met
-      await new Promise(resolve => setTimeout(resolve, 2000));
-
-      const initialKeeperRewards = (await registryProgram.account.keeper.fetch(keeperAccount)).pendingRewards;
-      const initialJobBalance = (await registryProgram.account.automationJob.fetch(executionJobAccount)).balance;
+      job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.equal(job.checkInstruction, "check_upkeep");
 
       await registryProgram.methods
-        .executeJob(new anchor.BN(executionJobId))
+        .updateJob({ ...noJobUpdate, checkInstruction: "" })
         .accounts({
-          registryState: registryState,
-          automationJob: executionJobAccount,
-          keeper: keeperAccount,
-          executionRecord: executionRecord,
-          keeperAccount: keeper1.publicKey,
-          targetProgram: targetProgram.publicKey,
-          systemProgram: SystemProgram.programId,
+          automationJob: jobAccount,
+          owner: user1.publicKey,
         })
-        .signers([keeper1])
+        .signers([user1])
         .rpc();
 
-      // Verify job state updated
-      const job = await registryProgram.account.automationJob.fetch(executionJobAccount);
-      assert.equal(job.executionCount.toNumber(), 1);
-      assert.isTrue(job.lastExecution.toNumber() > 0);
-      assert.isTrue(job.balance.toNumber() < initialJobBalance.toNumber()); // Fee deducted
-
-      // Verify keeper state updated
-      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
-      assert.equal(keeper.successfulExecutions.toNumber(), 1);
-      assert.isTrue(keeper.pendingRewards.toNumber() > initialKeeperRewards.toNumber());
-
-      // Verify execution record created
-      const execution = await registryProgram.account.executionRecord.fetch(executionRecord);
-      assert.equal(execution.jobId.toNumber(), executionJobId);
-      assert.equal(execution.keeper.toString(), keeper1.publicKey.toString());
-      assert.isTrue(execution.success);
-
-      // Verify registry stats updated
-      const registry = await registryProgram.account.registryState.fetch(registryState);
-      assert.equal(registry.totalExecutions.toNumber(), 1);
+      job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.isNull(job.checkInstruction);
     });
 
-    it("Should fail to execute job too soon", async () => {
-      const [executionRecord] = getExecutionRecordPDA(executionJobId, 1);
-
+    it("Should fail to resume a job that is not paused", async () => {
       try {
         await registryProgram.methods
-          .executeJob(new anchor.BN(executionJobId))
+          .resumeJob(false)
           .accounts({
-            registryState: registryState,
-            automationJob: executionJobAccount,
-            keeper: keeperAccount,
-            executionRecord: executionRecord,
-            keeperAccount: keeper1.publicKey,
-            targetProgram: targetProgram.publicKey,
-            systemProgram: SystemProgram.programId,
+            automationJob: jobAccount,
+            owner: user1.publicKey,
           })
-          .signers([keeper1])
+          .signers([user1])
           .rpc();
 
-        assert.fail("Should fail to execute too soon");
+        assert.fail("Should not be able to resume a running job");
       } catch (error) {
-        assert.include(error.toString(), "InvalidTrigger");
+        assert.include(error.toString(), "InvalidJob");
       }
     });
-  });
 
-  describe("Keeper Rewards", () => {
-    let keeperAccount: PublicKey;
+    it("Should pause a job while keeping its balance", async () => {
+      const initialJob = await registryProgram.account.automationJob.fetch(jobAccount);
 
-    before(async () => {
-      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
-    });
+      await registryProgram.methods
+        .pauseJob()
+        .accounts({
+          automationJob: jobAccount,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
 
-    it("Should claim keeper rewards", async () => {
-      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
-      const pendingRewards = keeper.pendingRewards.toNumber();
-      
-      if (pendingRewards > 0) {
-        const initialBalance = await provider.connection.getBalance(keeper1.publicKey);
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.isTrue(job.isPaused);
+      assert.isTrue(job.isActive);
+      assert.equal(job.balance.toNumber(), initialJob.balance.toNumber());
 
+      try {
         await registryProgram.methods
-          .claimRewards()
+          .pauseJob()
           .accounts({
-            keeper: keeperAccount,
-            keeperAccount: keeper1.publicKey,
-            systemProgram: SystemProgram.programId,
+            automationJob: jobAccount,
+            owner: user1.publicKey,
           })
-          .signers([keeper1])
+          .signers([user1])
           .rpc();
 
-        // Verify rewards were claimed
-        const updatedKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
-        assert.equal(updatedKeeper.pendingRewards.toNumber(), 0);
-        assert.equal(
-          updatedKeeper.totalEarnings.toNumber(),
-          keeper.totalEarnings.toNumber() + pendingRewards
-        );
+        assert.fail("Should not pause a paused job");
+      } catch (error) {
+        assert.include(error.toString(), "JobPaused");
       }
     });
 
-    it("Should fail to claim rewards when none available", async () => {
+    it("Should resume a paused job and restart its schedule", async () => {
+      await registryProgram.methods
+        .resumeJob(true)
+        .accounts({
+          automationJob: jobAccount,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.isFalse(job.isPaused);
+      assert.isTrue(job.lastExecution.toNumber() > 0);
+      assert.isTrue(job.nextScheduledTime.toNumber() > job.lastExecution.toNumber()); // Not due immediately
+    });
+
+    it("Should let any wallet sponsor a job", async () => {
+      const sponsorAmount = new anchor.BN(10_000_000);
+      const initialJob = await registryProgram.account.automationJob.fetch(jobAccount);
+
+      await registryProgram.methods
+        .fundJob(sponsorAmount)
+        .accounts({
+          automationJob: jobAccount,
+          sponsorship: sponsorship,
+          funder: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.equal(job.balance.toNumber(), initialJob.balance.toNumber() + sponsorAmount.toNumber());
+      assert.equal(job.sponsorShares.toNumber(), sponsorAmount.toNumber()); // Nothing spent yet, so 1:1
+
+      const account = await registryProgram.account.sponsorship.fetch(sponsorship);
+      assert.equal(account.sponsor.toString(), user2.publicKey.toString());
+      assert.equal(account.shares.toNumber(), sponsorAmount.toNumber());
+      assert.equal(account.contributed.toNumber(), sponsorAmount.toNumber());
+    });
+
+    it("Should fail to fund as the owner from another wallet", async () => {
       try {
         await registryProgram.methods
-          .claimRewards()
+          .fundJob(new anchor.BN(1_000_000))
           .accounts({
-            keeper: keeperAccount,
-            keeperAccount: keeper1.publicKey,
+            automationJob: jobAccount,
+            sponsorship: null,
+            funder: user2.publicKey,
             systemProgram: SystemProgram.programId,
           })
-          .signers([keeper1])
+          .signers([user2])
           .rpc();
 
-        assert.fail("Should fail when no rewards to claim");
+        assert.fail("Non-owners must fund through a sponsorship");
       } catch (error) {
-        assert.include(error.toString(), "NoRewardsToClaim");
+        assert.include(error.toString(), "Unauthorized");
       }
     });
-  });
 
-  describe("Admin Functions", () => {
-    it("Should update registry parameters", async () => {
-      const newBaseFee = new anchor.BN(7500);
-      const newMinStake = new anchor.BN(2 * LAMPORTS_PER_SOL);
-      const newProtocolFeeBps = 300;
+    it("Should cancel a job and refund the owner's share", async () => {
+      const initialJob = await registryProgram.account.automationJob.fetch(jobAccount);
+      const sponsoredBalance = initialJob.sponsorShares.toNumber(); // Nothing spent, so 1:1
 
       await registryProgram.methods
-        .updateRegistryParams(newBaseFee, newMinStake, newProtocolFeeBps)
+        .cancelJob()
         .accounts({
           registryState: registryState,
-          admin: admin.publicKey,
+          automationJob: jobAccount,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
         })
-        .signers([admin])
+        .signers([user1])
         .rpc();
 
+      // Check job is inactive and holds only the sponsor's share
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.isFalse(job.isActive);
+      assert.equal(job.balance.toNumber(), sponsoredBalance);
+      assert.equal(job.fundingShares.toNumber(), job.sponsorShares.toNumber());
+
+      // Check registry state updated
       const registry = await registryProgram.account.registryState.fetch(registryState);
-      assert.equal(registry.baseFee.toNumber(), 7500);
-      assert.equal(registry.minStake.toNumber(), 2 * LAMPORTS_PER_SOL);
-      assert.equal(registry.protocolFeeBps, 300);
+      assert.equal(registry.activeJobs.toNumber(), 2); // Should decrease by 1
+    });
+
+    it("Should refund sponsors their share of a cancelled job", async () => {
+      const initialSponsorBalance = await provider.connection.getBalance(user2.publicKey);
+      const sponsoredBalance = (await registryProgram.account.automationJob.fetch(jobAccount)).balance;
+
+      await registryProgram.methods
+        .withdrawSponsorship()
+        .accounts({
+          automationJob: jobAccount,
+          sponsorship: sponsorship,
+          sponsor: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.equal(job.balance.toNumber(), 0);
+      assert.equal(job.sponsorShares.toNumber(), 0);
+
+      // Refund plus the closed sponsorship's rent, less the transaction fee
+      const sponsorBalance = await provider.connection.getBalance(user2.publicKey);
+      assert.isTrue(sponsorBalance > initialSponsorBalance + sponsoredBalance.toNumber() - 10_000);
+      assert.isNull(await provider.connection.getAccountInfo(sponsorship));
     });
 
-    it("Should fail to update parameters as non-admin", async () => {
+    it("Should fail to update cancelled job", async () => {
       try {
         await registryProgram.methods
-          .updateRegistryParams(
-            new anchor.BN(10000),
-            new anchor.BN(LAMPORTS_PER_SOL),
-            400
-          )
+          .updateJob({ ...noJobUpdate, gasLimit: new anchor.BN(300_000) })
           .accounts({
-            registryState: registryState,
-            admin: user1.publicKey, // Not the admin
+            automationJob: jobAccount,
+            owner: user1.publicKey,
           })
           .signers([user1])
           .rpc();
 
-        assert.fail("Non-admin should not be able to update parameters");
+        assert.fail("Should not be able to update cancelled job");
       } catch (error) {
-        assert.include(error.toString(), "Unauthorized");
+        assert.include(error.toString(), "InvalidJob");
       }
     });
+  });
 
-    it("Should slash malicious keeper", async () => {
-      const [keeperAccount] = getKeeperPDA(keeper2.publicKey);
-      const slashAmount = new anchor.BN(LAMPORTS_PER_SOL / 2); // Slash 0.5 SOL
-      const reason = "Test slashing";
+  describe("Keeper Registration", () => {
+    it("Should register keeper successfully", async () => {
+      const [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+      const stakeAmount = new anchor.BN(2 * LAMPORTS_PER_SOL); // 2 SOL
+
+      await registryProgram.methods
+        .registerKeeper(stakeAmount)
+        .accounts({
+          registryState: registryState,
+          keeper: keeperAccount,
+          keeperAccount: keeper1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([keeper1])
+        .rpc();
 
       const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
-      const initialStake = keeper.stakeAmount.toNumber();
+      assert.equal(keeper.address.toString(), keeper1.publicKey.toString());
+      assert.equal(keeper.stakeAmount.toNumber(), 2 * LAMPORTS_PER_SOL);
+      assert.equal(keeper.reputationScore.toNumber(), 5000); // Starting reputation
+      assert.isTrue(keeper.isActive);
+      assert.equal(keeper.successfulExecutions.toNumber(), 0);
+      assert.equal(keeper.failedExecutions.toNumber(), 0);
+
+      // Check registry state
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      assert.equal(registry.totalKeepers.toNumber(), 1);
+      assert.equal(registry.activeKeepers.toNumber(), 1);
+    });
+
+    it("Should fail to register keeper with insufficient stake", async () => {
+      const [keeperAccount] = getKeeperPDA(keeper2.publicKey);
+      const insufficientStake = new anchor.BN(LAMPORTS_PER_SOL / 2); // 0.5 SOL
+
+      try {
+        await registryProgram.methods
+          .registerKeeper(insufficientStake)
+          .accounts({
+            registryState: registryState,
+            keeper: keeperAccount,
+            keeperAccount: keeper2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([keeper2])
+          .rpc();
+
+        assert.fail("Should fail with insufficient stake");
+      } catch (error) {
+        assert.include(error.toString(), "InsufficientStake");
+      }
+    });
+
+    it("Should register second keeper successfully", async () => {
+      const [keeperAccount] = getKeeperPDA(keeper2.publicKey);
+      const stakeAmount = new anchor.BN(LAMPORTS_PER_SOL); // 1 SOL (minimum)
 
       await registryProgram.methods
-        .slashKeeper(keeper2.publicKey, slashAmount, reason)
+        .registerKeeper(stakeAmount)
         .accounts({
           registryState: registryState,
           keeper: keeperAccount,
-          admin: admin.publicKey,
-          treasury: treasury.publicKey,
+          keeperAccount: keeper2.publicKey,
           systemProgram: SystemProgram.programId,
         })
-        .signers([admin])
+        .signers([keeper2])
         .rpc();
 
-      const slashedKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
-      assert.equal(
-        slashedKeeper.stakeAmount.toNumber(),
-        initialStake - slashAmount.toNumber()
-      );
-      assert.isTrue(slashedKeeper.reputationScore.toNumber() < keeper.reputationScore.toNumber());
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      assert.equal(registry.totalKeepers.toNumber(), 2);
+      assert.equal(registry.activeKeepers.toNumber(), 2);
+    });
+  });
+
+  describe("Job Execution", () => {
+    let executionJobId: number;
+    let executionJobAccount: PublicKey;
+    let keeperAccount: PublicKey;
+
+    before(async () => {
+      // Register a job for execution testing
+      executionJobId = 4;
+      [executionJobAccount] = getAutomationJobPDA(executionJobId);
+      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+      
+      const triggerType = { timeBased: {} };
+      const triggerParams = new anchor.BN(5).toArrayLike(Buffer, "le", 8); // 5 seconds for testing
+
+      await registryProgram.methods
+        .registerJob({
+          targetProgram,
+          targetInstruction: "initialize",
+          triggerType,
+          triggerParams,
+          gasLimit: new anchor.BN(200_000),
+          minBalance: new anchor.BN(1_000_000),
+          initialFunding: new anchor.BN(100_000_000), // 0.1 SOL
+          metadata: null,
+        })
+        .accounts({
+          registryState: registryState,
+          automationJob: executionJobAccount,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("Should execute job successfully", async () => {
+      const [executionRecord] = getExecutionRecordPDA(executionJobId, 0);
+
+      await waitUntilDue(executionJobAccount);
+
+      const initialKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      const initialKeeperRewards = initialKeeper.pendingRewards;
+      const initialKeeperLamports = await provider.connection.getBalance(keeper1.publicKey);
+      const initialJobBalance = (await registryProgram.account.automationJob.fetch(executionJobAccount)).balance;
+
+      const signature = await registryProgram.methods
+        .executeJob(new anchor.BN(executionJobId), Buffer.alloc(0))
+        .accounts({
+          registryState: registryState,
+          automationJob: executionJobAccount,
+          keeper: keeperAccount,
+          executionRecord: executionRecord,
+          keeperAccount: keeper1.publicKey,
+          targetProgram: targetProgram,
+          executionAuthority: executionAuthority,
+          executionProgram: executionProgramId,
+          insurancePool: insurancePool,
+          systemProgram: SystemProgram.programId,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .remainingAccounts(targetAccounts)
+        .signers([keeper1])
+        .rpc({ commitment: "confirmed" });
+
+      // The target is only reached through the execution engine
+      const transaction = await provider.connection.getTransaction(signature, {
+        commitment: "confirmed",
+        maxSupportedTransactionVersion: 0,
+      });
+      const logs = transaction.meta.logMessages;
+      assert.include(logs, `Program ${executionProgramId} invoke [2]`);
+      assert.include(logs, `Program ${targetProgram} invoke [3]`);
+
+      // Verify job state updated
+      const job = await registryProgram.account.automationJob.fetch(executionJobAccount);
+      assert.equal(job.executionCount.toNumber(), 1);
+      assert.isTrue(job.lastExecution.toNumber() > 0);
+      assert.isTrue(job.nextScheduledTime.toNumber() > job.lastExecution.toNumber());
+      assert.equal(job.missedExecutions.toNumber(), 0);
+      assert.isTrue(job.balance.toNumber() < initialJobBalance.toNumber()); // Fee deducted
+
+      // Verify keeper state updated
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(keeper.successfulExecutions.toNumber(), 1);
+      assert.isTrue(keeper.pendingRewards.toNumber() > initialKeeperRewards.toNumber());
+
+      // The job repaid the record rent and the signature fee, apart from rewards
+      const reimbursed = keeper.totalReimbursed.toNumber() - initialKeeper.totalReimbursed.toNumber();
+      const recordRent = (await provider.connection.getAccountInfo(executionRecord)).lamports;
+      assert.isAtLeast(reimbursed, recordRent + 5000);
+      assert.isAtLeast(await provider.connection.getBalance(keeper1.publicKey), initialKeeperLamports);
+
+      // Verify execution record created
+      const execution = await registryProgram.account.executionRecord.fetch(executionRecord);
+      assert.equal(execution.jobId.toNumber(), executionJobId);
+      assert.equal(execution.keeper.toString(), keeper1.publicKey.toString());
+      assert.isTrue(execution.success);
+      assert.equal(execution.returnData.length, 0); // Target sets no return data
+
+      // Verify registry stats updated
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      assert.equal(registry.totalExecutions.toNumber(), 1);
+      assert.isTrue(registry.insuranceDeposits.toNumber() > 0); // Share of the protocol fee
+    });
+
+    it("Should fail to execute job too soon", async () => {
+      const [executionRecord] = getExecutionRecordPDA(executionJobId, 1);
+
+      try {
+        await registryProgram.methods
+          .executeJob(new anchor.BN(executionJobId), Buffer.alloc(0))
+          .accounts({
+            registryState: registryState,
+            automationJob: executionJobAccount,
+            keeper: keeperAccount,
+            executionRecord: executionRecord,
+            keeperAccount: keeper1.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .signers([keeper1])
+          .rpc();
+
+        assert.fail("Should fail to execute too soon");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidTrigger");
+      }
+    });
+
+    it("Should reject perform data for a job without a check instruction", async () => {
+      const [executionRecord] = getExecutionRecordPDA(executionJobId, 1);
+
+      try {
+        await registryProgram.methods
+          .executeJob(new anchor.BN(executionJobId), Buffer.from([1, 2, 3]))
+          .accounts({
+            registryState: registryState,
+            automationJob: executionJobAccount,
+            keeper: keeperAccount,
+            executionRecord: executionRecord,
+            keeperAccount: keeper1.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .signers([keeper1])
+          .rpc();
+
+        assert.fail("Should reject perform data without a check instruction");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
+    });
+
+    it("Should reject execution when the fee exceeds the job's cap", async () => {
+      const [executionRecord] = getExecutionRecordPDA(executionJobId, 1);
+
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, maxFeePerExecution: new anchor.BN(1) })
+        .accounts({
+          automationJob: executionJobAccount,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      await waitUntilDue(executionJobAccount);
+
+      try {
+        await registryProgram.methods
+          .executeJob(new anchor.BN(executionJobId), Buffer.alloc(0))
+          .accounts({
+            registryState: registryState,
+            automationJob: executionJobAccount,
+            keeper: keeperAccount,
+            executionRecord: executionRecord,
+            keeperAccount: keeper1.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .signers([keeper1])
+          .rpc();
+
+        assert.fail("Should reject a fee above the cap");
+      } catch (error) {
+        assert.include(error.toString(), "FeeExceedsCap");
+      } finally {
+        await registryProgram.methods
+          .updateJob({ ...noJobUpdate, maxFeePerExecution: new anchor.BN(0) })
+          .accounts({
+            automationJob: executionJobAccount,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+      }
+    });
+
+    it("Should update the job's fee reimbursement cap", async () => {
+      const initialJob = await registryProgram.account.automationJob.fetch(executionJobAccount);
+      assert.equal(initialJob.maxFeeReimbursement.toNumber(), 50_000);
+
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, maxFeeReimbursement: new anchor.BN(0) })
+        .accounts({
+          automationJob: executionJobAccount,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(executionJobAccount);
+      assert.equal(job.maxFeeReimbursement.toNumber(), 0);
+
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, maxFeeReimbursement: initialJob.maxFeeReimbursement })
+        .accounts({
+          automationJob: executionJobAccount,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("Should reject keepers below the job's minimum tier", async () => {
+      const [executionRecord] = getExecutionRecordPDA(executionJobId, 1);
+
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, minKeeperTier: 1 })
+        .accounts({
+          automationJob: executionJobAccount,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(executionJobAccount);
+      assert.equal(job.minKeeperTier, 1);
+
+      try {
+        // No tiers are configured, so every keeper is tier 0
+        await registryProgram.methods
+          .executeJob(new anchor.BN(executionJobId), Buffer.alloc(0))
+          .accounts({
+            registryState: registryState,
+            automationJob: executionJobAccount,
+            keeper: keeperAccount,
+            executionRecord: executionRecord,
+            keeperAccount: keeper1.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .signers([keeper1])
+          .rpc();
+
+        assert.fail("Should reject a keeper below the job's tier");
+      } catch (error) {
+        assert.include(error.toString(), "KeeperTierTooLow");
+      } finally {
+        await registryProgram.methods
+          .updateJob({ ...noJobUpdate, minKeeperTier: 0 })
+          .accounts({
+            automationJob: executionJobAccount,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+      }
+    });
+
+    it("Should reject a minimum tier above the tier limit", async () => {
+      try {
+        await registryProgram.methods
+          .updateJob({ ...noJobUpdate, minKeeperTier: 5 })
+          .accounts({
+            automationJob: executionJobAccount,
+            owner: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Should reject a tier the registry cannot define");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
+    });
+
+    it("Should split the transaction fee between executions bundled together", async () => {
+      const jobIds: number[] = [];
+      for (let i = 0; i < 2; i++) {
+        const registry = await registryProgram.account.registryState.fetch(registryState);
+        const jobId = registry.nextJobId.toNumber();
+        await registryProgram.methods
+          .registerJob({
+            targetProgram,
+            targetInstruction: "initialize",
+            triggerType: { timeBased: {} },
+            triggerParams: new anchor.BN(5).toArrayLike(Buffer, "le", 8),
+            gasLimit: new anchor.BN(200_000),
+            minBalance: new anchor.BN(1_000_000),
+            initialFunding: new anchor.BN(100_000_000),
+            metadata: null,
+          })
+          .accounts({
+            registryState: registryState,
+            automationJob: getAutomationJobPDA(jobId)[0],
+            owner: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+        jobIds.push(jobId);
+      }
+      for (const jobId of jobIds) {
+        await waitUntilDue(getAutomationJobPDA(jobId)[0]);
+      }
+
+      // Records are keyed by the registry's running count, which the first execution advances
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const executionRecords = jobIds.map(
+        (jobId, i) => getExecutionRecordPDA(jobId, registry.totalExecutions.toNumber() + i)[0]
+      );
+      const instructions = await Promise.all(
+        jobIds.map((jobId, i) =>
+          registryProgram.methods
+            .executeJob(new anchor.BN(jobId), Buffer.alloc(0))
+            .accounts({
+              registryState: registryState,
+              automationJob: getAutomationJobPDA(jobId)[0],
+              keeper: keeperAccount,
+              executionRecord: executionRecords[i],
+              keeperAccount: keeper1.publicKey,
+              targetProgram: targetProgram,
+              executionAuthority: executionAuthority,
+              executionProgram: executionProgramId,
+              insurancePool: insurancePool,
+              systemProgram: SystemProgram.programId,
+              instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+            })
+            .remainingAccounts(targetAccounts)
+            .instruction()
+        )
+      );
+
+      const initialKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+
+      // The keeper is the only signer, so the transaction costs one signature fee
+      const transaction = new Transaction().add(...instructions);
+      transaction.feePayer = keeper1.publicKey;
+      await sendAndConfirmTransaction(provider.connection, transaction, [keeper1]);
+
+      // Both runs repay their record rent but share the single 5000 lamport fee
+      let recordRent = 0;
+      for (const executionRecord of executionRecords) {
+        recordRent += (await provider.connection.getAccountInfo(executionRecord)).lamports;
+      }
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(
+        keeper.totalReimbursed.toNumber() - initialKeeper.totalReimbursed.toNumber(),
+        recordRent + 5000
+      );
+      assert.equal(
+        keeper.successfulExecutions.toNumber(),
+        initialKeeper.successfulExecutions.toNumber() + 2
+      );
+    });
+  });
+
+  describe("Job Expiry", () => {
+    let expiringJobId: number;
+    let expiringJobAccount: PublicKey;
+    let expiringJobMetadata: PublicKey;
+    let sponsorship: PublicKey;
+    let keeperAccount: PublicKey;
+    const sponsorAmount = 20_000_000;
+
+    before(async () => {
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      expiringJobId = registry.nextJobId.toNumber();
+      [expiringJobAccount] = getAutomationJobPDA(expiringJobId);
+      [expiringJobMetadata] = getJobMetadataPDA(expiringJobId);
+      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+      [sponsorship] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sponsorship"), expiringJobAccount.toBuffer(), user2.publicKey.toBuffer()],
+        registryProgram.programId
+      );
+
+      await registryProgram.methods
+        .registerJob({
+          targetProgram,
+          targetInstruction: "expire_test",
+          triggerType: { timeBased: { interval: new anchor.BN(1) } },
+          triggerParams: Buffer.from(JSON.stringify({ interval: 1 })),
+          gasLimit: new anchor.BN(200_000),
+          minBalance: new anchor.BN(1_000_000),
+          initialFunding: new anchor.BN(100_000_000),
+          metadata: { name: "Expiring job", tags: [], metadataUri: "" },
+        })
+        .accounts({
+          registryState: registryState,
+          automationJob: expiringJobAccount,
+          jobMetadata: expiringJobMetadata,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await registryProgram.methods
+        .fundJob(new anchor.BN(sponsorAmount))
+        .accounts({
+          automationJob: expiringJobAccount,
+          sponsorship: sponsorship,
+          funder: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+    });
+
+    it("Should reject reaping a live job", async () => {
+      try {
+        await registryProgram.methods
+          .reapJob()
+          .accounts({
+            registryState: registryState,
+            automationJob: expiringJobAccount,
+            jobMetadata: expiringJobMetadata,
+            owner: user1.publicKey,
+            reaper: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+        assert.fail("Should not reap an active job without an end time");
+      } catch (error) {
+        assert.include(error.toString(), "JobNotReapable");
+      }
+    });
+
+    it("Should refuse to execute a job past its end time", async () => {
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, endAt: new anchor.BN(1) })
+        .accounts({
+          automationJob: expiringJobAccount,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(expiringJobAccount);
+      assert.equal(job.endAt.toNumber(), 1);
+
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const [executionRecord] = getExecutionRecordPDA(expiringJobId, registry.totalExecutions.toNumber());
+
+      try {
+        await registryProgram.methods
+          .executeJob(new anchor.BN(expiringJobId), Buffer.alloc(0))
+          .accounts({
+            registryState: registryState,
+            automationJob: expiringJobAccount,
+            keeper: keeperAccount,
+            executionRecord: executionRecord,
+            keeperAccount: keeper1.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .signers([keeper1])
+          .rpc();
+
+        assert.fail("Should not execute an expired job");
+      } catch (error) {
+        assert.include(error.toString(), "JobExpired");
+      }
+    });
+
+    it("Should refuse to fund a job past its end time", async () => {
+      try {
+        await registryProgram.methods
+          .fundJob(new anchor.BN(1_000_000))
+          .accounts({
+            automationJob: expiringJobAccount,
+            sponsorship: sponsorship,
+            funder: user2.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user2])
+          .rpc();
+
+        assert.fail("Should not fund an expired job");
+      } catch (error) {
+        assert.include(error.toString(), "JobExpired");
+      }
+    });
+
+    it("Should refuse to reap past sponsors without refunding them", async () => {
+      try {
+        await registryProgram.methods
+          .reapJob()
+          .accounts({
+            registryState: registryState,
+            automationJob: expiringJobAccount,
+            jobMetadata: expiringJobMetadata,
+            owner: user1.publicKey,
+            reaper: keeper2.publicKey,
+          })
+          .signers([keeper2])
+          .rpc();
+
+        assert.fail("Should not reap a job while a sponsor still has funds in it");
+      } catch (error) {
+        assert.include(error.toString(), "JobNotReapable");
+      }
+    });
+
+    it("Should let anyone reap an expired job for a rent bounty", async () => {
+      const job = await registryProgram.account.automationJob.fetch(expiringJobAccount);
+      const jobLamports = (await provider.connection.getAccountInfo(expiringJobAccount)).lamports;
+      const metadataLamports = (await provider.connection.getAccountInfo(expiringJobMetadata)).lamports;
+      const sponsorshipLamports = (await provider.connection.getAccountInfo(sponsorship)).lamports;
+      const rent = jobLamports - job.balance.toNumber();
+      const bounty = Math.floor(rent * 1000 / 10000);
+
+      const initialOwnerLamports = await provider.connection.getBalance(user1.publicKey);
+      const initialSponsorLamports = await provider.connection.getBalance(user2.publicKey);
+      const initialReaperLamports = await provider.connection.getBalance(keeper2.publicKey);
+      const initialRegistry = await registryProgram.account.registryState.fetch(registryState);
+
+      await registryProgram.methods
+        .reapJob()
+        .accounts({
+          registryState: registryState,
+          automationJob: expiringJobAccount,
+          jobMetadata: expiringJobMetadata,
+          owner: user1.publicKey,
+          reaper: keeper2.publicKey,
+        })
+        .remainingAccounts([
+          { pubkey: sponsorship, isSigner: false, isWritable: true },
+          { pubkey: user2.publicKey, isSigner: false, isWritable: true },
+        ])
+        .signers([keeper2])
+        .rpc();
+
+      // The job, its metadata and the sponsorship are closed
+      assert.isNull(await provider.connection.getAccountInfo(expiringJobAccount));
+      assert.isNull(await provider.connection.getAccountInfo(expiringJobMetadata));
+      assert.isNull(await provider.connection.getAccountInfo(sponsorship));
+
+      // Nothing was spent, so the sponsor gets back what they put in plus the sponsorship rent
+      assert.equal(
+        await provider.connection.getBalance(user2.publicKey),
+        initialSponsorLamports + sponsorAmount + sponsorshipLamports
+      );
+
+      // The owner gets their balance, the metadata rent and the rest of the job's rent
+      assert.equal(
+        await provider.connection.getBalance(user1.publicKey),
+        initialOwnerLamports + jobLamports - sponsorAmount - bounty + metadataLamports
+      );
+      assert.equal(
+        await provider.connection.getBalance(keeper2.publicKey),
+        initialReaperLamports + bounty - 5000
+      );
+
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      assert.equal(registry.activeJobs.toNumber(), initialRegistry.activeJobs.toNumber() - 1);
+    });
+
+    it("Should refund sponsors passed to cancel_job", async () => {
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const jobId = registry.nextJobId.toNumber();
+      const [jobAccount] = getAutomationJobPDA(jobId);
+      const [jobMetadata] = getJobMetadataPDA(jobId);
+      const [cancelSponsorship] = PublicKey.findProgramAddressSync(
+        [Buffer.from("sponsorship"), jobAccount.toBuffer(), user2.publicKey.toBuffer()],
+        registryProgram.programId
+      );
+
+      await registryProgram.methods
+        .registerJob({
+          targetProgram,
+          targetInstruction: "cancel_test",
+          triggerType: { timeBased: { interval: new anchor.BN(60) } },
+          triggerParams: Buffer.from(JSON.stringify({ interval: 60 })),
+          gasLimit: new anchor.BN(200_000),
+          minBalance: new anchor.BN(1_000_000),
+          initialFunding: new anchor.BN(100_000_000),
+          metadata: null,
+        })
+        .accounts({
+          registryState: registryState,
+          automationJob: jobAccount,
+          jobMetadata: jobMetadata,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await registryProgram.methods
+        .fundJob(new anchor.BN(sponsorAmount))
+        .accounts({
+          automationJob: jobAccount,
+          sponsorship: cancelSponsorship,
+          funder: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const sponsorshipLamports = (await provider.connection.getAccountInfo(cancelSponsorship)).lamports;
+      const initialSponsorLamports = await provider.connection.getBalance(user2.publicKey);
+
+      await registryProgram.methods
+        .cancelJob()
+        .accounts({
+          registryState: registryState,
+          automationJob: jobAccount,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .remainingAccounts([
+          { pubkey: cancelSponsorship, isSigner: false, isWritable: true },
+          { pubkey: user2.publicKey, isSigner: false, isWritable: true },
+        ])
+        .signers([user1])
+        .rpc();
+
+      // Both the owner and the sponsor are paid out, leaving nothing in the job
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.isFalse(job.isActive);
+      assert.equal(job.balance.toNumber(), 0);
+      assert.equal(job.fundingShares.toNumber(), 0);
+      assert.equal(job.sponsorShares.toNumber(), 0);
+
+      assert.isNull(await provider.connection.getAccountInfo(cancelSponsorship));
+      assert.equal(
+        await provider.connection.getBalance(user2.publicKey),
+        initialSponsorLamports + sponsorAmount + sponsorshipLamports
+      );
+    });
+  });
+
+  describe("Target Failures", () => {
+    let failingJobId: number;
+    let failingJobAccount: PublicKey;
+    let keeperAccount: PublicKey;
+
+    before(async () => {
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      failingJobId = registry.nextJobId.toNumber();
+      [failingJobAccount] = getAutomationJobPDA(failingJobId);
+      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+
+      // The solcron program has no such instruction, so every call to it fails
+      await registryProgram.methods
+        .registerJob({
+          targetProgram,
+          targetInstruction: "no_such_instruction",
+          triggerType: { timeBased: {} },
+          triggerParams: new anchor.BN(5).toArrayLike(Buffer, "le", 8),
+          gasLimit: new anchor.BN(200_000),
+          minBalance: new anchor.BN(1_000_000),
+          initialFunding: new anchor.BN(100_000_000),
+          metadata: null,
+        })
+        .accounts({
+          registryState: registryState,
+          automationJob: failingJobAccount,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+    });
+
+    it("Should abort execution when the target fails", async () => {
+      await waitUntilDue(failingJobAccount);
+      const initialJob = await registryProgram.account.automationJob.fetch(failingJobAccount);
+
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const [executionRecord] = getExecutionRecordPDA(failingJobId, registry.totalExecutions.toNumber());
+
+      try {
+        await registryProgram.methods
+          .executeJob(new anchor.BN(failingJobId), Buffer.alloc(0))
+          .accounts({
+            registryState: registryState,
+            automationJob: failingJobAccount,
+            keeper: keeperAccount,
+            executionRecord: executionRecord,
+            keeperAccount: keeper1.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .remainingAccounts(targetAccounts)
+          .signers([keeper1])
+          .rpc();
+
+        assert.fail("Should not execute a job whose target fails");
+      } catch (error) {
+        // Anchor's InstructionFallbackNotFound (101) from the target
+        assert.include(error.toString(), "custom program error: 0x65");
+      }
+
+      // Nothing from the aborted transaction persists
+      assert.isNull(await provider.connection.getAccountInfo(executionRecord));
+      const job = await registryProgram.account.automationJob.fetch(failingJobAccount);
+      assert.equal(job.executionCount.toNumber(), 0);
+      assert.equal(job.balance.toNumber(), initialJob.balance.toNumber());
+    });
+  });
+
+  describe("Legacy Accounts", () => {
+    // Loaded by the validator from tests/fixtures/legacy_job.json in the
+    // baseline layout: no version byte and nothing after `bump`
+    const legacyJobId = 1_000_000;
+    let legacyJobAccount: PublicKey;
+    let legacyOwner: Keypair;
+    let keeperAccount: PublicKey;
+
+    before(async () => {
+      [legacyJobAccount] = getAutomationJobPDA(legacyJobId);
+      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+      legacyOwner = Keypair.fromSecretKey(
+        Uint8Array.from(JSON.parse(fs.readFileSync("tests/fixtures/legacy_job_owner.json", "utf8")))
+      );
+
+      await provider.connection.confirmTransaction(
+        await provider.connection.requestAirdrop(legacyOwner.publicKey, LAMPORTS_PER_SOL)
+      );
+    });
+
+    it("Should migrate a baseline job and keep its PDA bump", async () => {
+      const [, expectedBump] = getAutomationJobPDA(legacyJobId);
+
+      await registryProgram.methods
+        .migrateJob()
+        .accounts({
+          account: legacyJobAccount,
+          payer: provider.wallet.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(legacyJobAccount);
+      assert.equal(job.jobId.toNumber(), legacyJobId);
+      assert.equal(job.owner.toString(), legacyOwner.publicKey.toString());
+      assert.equal(job.targetInstruction, "initialize");
+      assert.equal(job.bump, expectedBump);
+      assert.equal(job.balance.toNumber(), 100_000_000);
+      assert.equal(job.fundingShares.toNumber(), 100_000_000);
+      assert.isFalse(job.isPaused);
+      assert.isTrue(job.nextScheduledTime.toNumber() > 0);
+    });
+
+    it("Should update a migrated job as its owner", async () => {
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, gasLimit: new anchor.BN(300_000) })
+        .accounts({
+          automationJob: legacyJobAccount,
+          owner: legacyOwner.publicKey,
+        })
+        .signers([legacyOwner])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(legacyJobAccount);
+      assert.equal(job.gasLimit.toNumber(), 300_000);
+    });
+
+    it("Should execute a migrated job", async () => {
+      await waitUntilDue(legacyJobAccount);
+
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const [executionRecord] = getExecutionRecordPDA(legacyJobId, registry.totalExecutions.toNumber());
+
+      await registryProgram.methods
+        .executeJob(new anchor.BN(legacyJobId), Buffer.alloc(0))
+        .accounts({
+          registryState: registryState,
+          automationJob: legacyJobAccount,
+          keeper: keeperAccount,
+          executionRecord: executionRecord,
+          keeperAccount: keeper1.publicKey,
+          targetProgram: targetProgram,
+          executionAuthority: executionAuthority,
+          executionProgram: executionProgramId,
+          insurancePool: insurancePool,
+          systemProgram: SystemProgram.programId,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .remainingAccounts(targetAccounts)
+        .signers([keeper1])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(legacyJobAccount);
+      assert.equal(job.executionCount.toNumber(), 1);
+      assert.isTrue((await registryProgram.account.executionRecord.fetch(executionRecord)).success);
+    });
+  });
+
+  describe("Keeper Rewards", () => {
+    let keeperAccount: PublicKey;
+
+    before(async () => {
+      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+    });
+
+    it("Should claim keeper rewards", async () => {
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      const pendingRewards = keeper.pendingRewards.toNumber();
+      assert.isTrue(pendingRewards > 0); // Earned in the execution tests
+
+      const initialBalance = await provider.connection.getBalance(keeper1.publicKey);
+      const initialKeeperLamports = await provider.connection.getBalance(keeperAccount);
+
+      await registryProgram.methods
+        .claimRewards()
+        .accounts({
+          keeper: keeperAccount,
+          keeperAccount: keeper1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([keeper1])
+        .rpc();
+
+      // Verify rewards were claimed
+      const updatedKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(updatedKeeper.pendingRewards.toNumber(), 0);
+      assert.equal(updatedKeeper.totalEarnings.toNumber(), keeper.totalEarnings.toNumber());
+      assert.equal(await provider.connection.getBalance(keeper1.publicKey), initialBalance + pendingRewards - 5000);
+      assert.equal(await provider.connection.getBalance(keeperAccount), initialKeeperLamports - pendingRewards);
+    });
+
+    it("Should fail to claim rewards when none available", async () => {
+      try {
+        await registryProgram.methods
+          .claimRewards()
+          .accounts({
+            keeper: keeperAccount,
+            keeperAccount: keeper1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([keeper1])
+          .rpc();
+
+        assert.fail("Should fail when no rewards to claim");
+      } catch (error) {
+        assert.include(error.toString(), "NoRewardsToClaim");
+      }
+    });
+  });
+
+  describe("Delegation", () => {
+    let keeperAccount: PublicKey;
+    let delegation: PublicKey;
+    const delegatedAmount = LAMPORTS_PER_SOL;
+
+    before(async () => {
+      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+      [delegation] = PublicKey.findProgramAddressSync(
+        [Buffer.from("delegation"), keeperAccount.toBuffer(), user2.publicKey.toBuffer()],
+        registryProgram.programId
+      );
+    });
+
+    it("Should count delegated stake toward the keeper's effective stake", async () => {
+      await registryProgram.methods
+        .delegateStake(keeper1.publicKey, new anchor.BN(delegatedAmount))
+        .accounts({
+          registryState: registryState,
+          keeper: keeperAccount,
+          delegation: delegation,
+          delegator: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(keeper.delegatedStake.toNumber(), delegatedAmount);
+      assert.equal(keeper.delegatorShares.toNumber(), delegatedAmount); // First delegation mints 1:1
+      assert.equal(keeper.commissionBps, 1000);
+
+      const account = await registryProgram.account.delegation.fetch(delegation);
+      assert.equal(account.delegator.toString(), user2.publicKey.toString());
+      assert.equal(account.shares.toNumber(), delegatedAmount);
+      assert.equal(account.unbondingShares.toNumber(), 0);
+    });
+
+    it("Should only let the keeper set its commission", async () => {
+      try {
+        await registryProgram.methods
+          .setCommission(0)
+          .accounts({
+            registryState: registryState,
+            keeper: keeperAccount,
+            keeperAccount: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+        assert.fail("Delegators should not set the commission");
+      } catch (error) {
+        assert.include(error.toString(), "ConstraintSeeds");
+      }
+
+      // Lowering the commission applies at once
+      await registryProgram.methods
+        .setCommission(500)
+        .accounts({
+          registryState: registryState,
+          keeper: keeperAccount,
+          keeperAccount: keeper1.publicKey,
+        })
+        .signers([keeper1])
+        .rpc();
+
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(keeper.commissionBps, 500);
+      assert.equal(keeper.commissionEffectiveAt.toNumber(), 0);
+    });
+
+    it("Should queue commission increases for the unbonding period", async () => {
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const before = Math.floor(Date.now() / 1000);
+
+      await registryProgram.methods
+        .setCommission(10000)
+        .accounts({
+          registryState: registryState,
+          keeper: keeperAccount,
+          keeperAccount: keeper1.publicKey,
+        })
+        .signers([keeper1])
+        .rpc();
+
+      // Delegators keep the current commission until they have had time to leave
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(keeper.commissionBps, 500);
+      assert.equal(keeper.pendingCommissionBps, 10000);
+      assert.isAtLeast(
+        keeper.commissionEffectiveAt.toNumber(),
+        before + registry.delegationUnbondingPeriod.toNumber() - 5
+      );
+
+      // Setting it back down cancels the queued increase
+      await registryProgram.methods
+        .setCommission(500)
+        .accounts({
+          registryState: registryState,
+          keeper: keeperAccount,
+          keeperAccount: keeper1.publicKey,
+        })
+        .signers([keeper1])
+        .rpc();
+
+      const settled = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(settled.commissionBps, 500);
+      assert.equal(settled.pendingCommissionBps, 0);
+      assert.equal(settled.commissionEffectiveAt.toNumber(), 0);
+    });
+
+    it("Should share keeper rewards with delegators after commission", async () => {
+      const jobId = 4; // Executed by keeper1 in the execution tests
+      const [automationJob] = getAutomationJobPDA(jobId);
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const [executionRecord] = getExecutionRecordPDA(jobId, registry.totalExecutions.toNumber());
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+
+      await waitUntilDue(automationJob);
+
+      await registryProgram.methods
+        .executeJob(new anchor.BN(jobId), Buffer.alloc(0))
+        .accounts({
+          registryState: registryState,
+          automationJob: automationJob,
+          keeper: keeperAccount,
+          executionRecord: executionRecord,
+          keeperAccount: keeper1.publicKey,
+          targetProgram: targetProgram,
+          executionAuthority: executionAuthority,
+          executionProgram: executionProgramId,
+          insurancePool: insurancePool,
+          systemProgram: SystemProgram.programId,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .remainingAccounts(targetAccounts)
+        .signers([keeper1])
+        .rpc();
+
+      // Delegators earn pro-rata to their stake, less the keeper's 5% commission
+      const baseFee = registry.baseFee.toNumber();
+      const keeperFee = baseFee - Math.floor((baseFee * registry.protocolFeeBps) / 10_000);
+      const stake = keeper.stakeAmount.toNumber();
+      const delegatorGross = Math.floor((keeperFee * delegatedAmount) / (stake + delegatedAmount));
+      const delegatorReward = delegatorGross - Math.floor((delegatorGross * 500) / 10_000);
+
+      const rewardedKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(rewardedKeeper.delegatedStake.toNumber(), delegatedAmount + delegatorReward);
+      assert.equal(
+        rewardedKeeper.pendingRewards.toNumber(),
+        keeper.pendingRewards.toNumber() + keeperFee - delegatorReward
+      );
+    });
+
+    it("Should pay claimed rewards without touching delegated stake", async () => {
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.isTrue(keeper.pendingRewards.toNumber() > 0); // Earned alongside the delegators above
+      const delegationAccount = await registryProgram.account.delegation.fetch(delegation);
+      const delegatedValue = Math.floor(
+        (delegationAccount.shares.toNumber() * keeper.delegatedStake.toNumber()) / keeper.delegatorShares.toNumber()
+      );
+
+      await registryProgram.methods
+        .claimRewards()
+        .accounts({
+          keeper: keeperAccount,
+          keeperAccount: keeper1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([keeper1])
+        .rpc();
+
+      // The delegator's claim on the pool is unchanged and still fully backed
+      const claimedKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(claimedKeeper.delegatedStake.toNumber(), keeper.delegatedStake.toNumber());
+      assert.equal(claimedKeeper.delegatorShares.toNumber(), keeper.delegatorShares.toNumber());
+      assert.equal(
+        Math.floor(
+          (delegationAccount.shares.toNumber() * claimedKeeper.delegatedStake.toNumber()) / claimedKeeper.delegatorShares.toNumber()
+        ),
+        delegatedValue
+      );
+
+      const keeperInfo = await provider.connection.getAccountInfo(keeperAccount);
+      const rent = await provider.connection.getMinimumBalanceForRentExemption(keeperInfo.data.length);
+      assert.isAtLeast(
+        keeperInfo.lamports,
+        rent + claimedKeeper.stakeAmount.toNumber() + claimedKeeper.delegatedStake.toNumber()
+      );
+    });
+
+    it("Should hold undelegated stake for the unbonding period", async () => {
+      const account = await registryProgram.account.delegation.fetch(delegation);
+      const unbondShares = account.shares.divn(2);
+
+      await registryProgram.methods
+        .undelegateStake(unbondShares)
+        .accounts({
+          registryState: registryState,
+          keeper: keeperAccount,
+          delegation: delegation,
+          delegator: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const unbonding = await registryProgram.account.delegation.fetch(delegation);
+      assert.equal(unbonding.unbondingShares.toNumber(), unbondShares.toNumber());
+      assert.isTrue(unbonding.unbondingReleaseAt.toNumber() >= unbonding.delegatedAt.toNumber() + 604800);
+
+      const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+      assert.equal(keeper.unbondingShares.toNumber(), unbondShares.toNumber());
+
+      try {
+        await registryProgram.methods
+          .withdrawDelegation()
+          .accounts({
+            keeper: keeperAccount,
+            delegation: delegation,
+            delegator: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+        assert.fail("Should not withdraw during the unbonding period");
+      } catch (error) {
+        assert.include(error.toString(), "UnbondingInProgress");
+      }
+    });
+
+    it("Should fail to unbond more than the bonded shares", async () => {
+      const account = await registryProgram.account.delegation.fetch(delegation);
+
+      try {
+        await registryProgram.methods
+          .undelegateStake(account.shares)
+          .accounts({
+            registryState: registryState,
+            keeper: keeperAccount,
+            delegation: delegation,
+            delegator: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+        assert.fail("Should not unbond shares already unbonding");
+      } catch (error) {
+        assert.include(error.toString(), "InsufficientDelegation");
+      }
+    });
+  });
+
+  describe("Funding Pools", () => {
+    const jobId = 4; // Owned by user1, executed by keeper1
+    let automationJob: PublicKey;
+    let keeperAccount: PublicKey;
+    let fundingPool: PublicKey;
+
+    const executeFromPool = async () => {
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const [executionRecord] = getExecutionRecordPDA(jobId, registry.totalExecutions.toNumber());
+
+      await waitUntilDue(automationJob);
+
+      await registryProgram.methods
+        .executeJob(new anchor.BN(jobId), Buffer.alloc(0))
+        .accounts({
+          registryState: registryState,
+          automationJob: automationJob,
+          keeper: keeperAccount,
+          executionRecord: executionRecord,
+          keeperAccount: keeper1.publicKey,
+          targetProgram: targetProgram,
+          executionAuthority: executionAuthority,
+          executionProgram: executionProgramId,
+          fundingPool: fundingPool,
+          insurancePool: insurancePool,
+          systemProgram: SystemProgram.programId,
+          instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+        })
+        .remainingAccounts(targetAccounts)
+        .signers([keeper1])
+        .rpc();
+    };
+
+    before(async () => {
+      [automationJob] = getAutomationJobPDA(jobId);
+      [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+      [fundingPool] = PublicKey.findProgramAddressSync(
+        [Buffer.from("funding_pool"), user1.publicKey.toBuffer(), new anchor.BN(0).toArrayLike(Buffer, "le", 8)],
+        registryProgram.programId
+      );
+    });
+
+    it("Should create, fund and withdraw from a funding pool", async () => {
+      await registryProgram.methods
+        .createFundingPool(new anchor.BN(0), new anchor.BN(1_000_000))
+        .accounts({
+          fundingPool: fundingPool,
+          owner: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await registryProgram.methods
+        .fundPool(new anchor.BN(100_000_000))
+        .accounts({
+          fundingPool: fundingPool,
+          funder: user1.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user1])
+        .rpc();
+
+      await registryProgram.methods
+        .withdrawPool(new anchor.BN(20_000_000))
+        .accounts({
+          fundingPool: fundingPool,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const pool = await registryProgram.account.fundingPool.fetch(fundingPool);
+      assert.equal(pool.owner.toString(), user1.publicKey.toString());
+      assert.equal(pool.balance.toNumber(), 80_000_000);
+      assert.equal(pool.minBalance.toNumber(), 1_000_000);
+    });
+
+    it("Should fail to withdraw from another owner's pool", async () => {
+      try {
+        await registryProgram.methods
+          .withdrawPool(new anchor.BN(1_000_000))
+          .accounts({
+            fundingPool: fundingPool,
+            owner: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+        assert.fail("Should not withdraw from another owner's pool");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+
+    it("Should pay execution fees from the job's funding pool", async () => {
+      // Room for one run at its largest cost: the fee plus the keeper's reimbursement
+      const quote = await registryProgram.methods
+        .quoteExecution(new anchor.BN(jobId))
+        .accounts({ registryState: registryState, automationJob: automationJob })
+        .view();
+      const spendCap = quote.executionFee.toNumber() + quote.maxReimbursement.toNumber();
+
+      await registryProgram.methods
+        .setJobFundingPool(new anchor.BN(spendCap))
+        .accounts({
+          automationJob: automationJob,
+          fundingPool: fundingPool,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const initialJob = await registryProgram.account.automationJob.fetch(automationJob);
+      assert.equal(initialJob.fundingPool.toString(), fundingPool.toString());
+      const initialPool = await registryProgram.account.fundingPool.fetch(fundingPool);
+
+      await executeFromPool();
+
+      const job = await registryProgram.account.automationJob.fetch(automationJob);
+      assert.equal(job.balance.toNumber(), initialJob.balance.toNumber()); // Job balance untouched
+      const spent = job.poolSpent.toNumber();
+      assert.isAbove(spent, quote.executionFee.toNumber()); // Fee plus reimbursement
+      assert.isAtMost(spent, spendCap);
+
+      const pool = await registryProgram.account.fundingPool.fetch(fundingPool);
+      assert.equal(pool.balance.toNumber(), initialPool.balance.toNumber() - spent);
+      assert.equal(pool.totalSpent.toNumber(), spent);
+    });
+
+    it("Should stop drawing from the pool once the job's spend cap is reached", async () => {
+      try {
+        await executeFromPool();
+        assert.fail("Should not exceed the pool spend cap");
+      } catch (error) {
+        assert.include(error.toString(), "PoolSpendCapExceeded");
+      }
+
+      // Detach so later tests pay from the job balance again
+      await registryProgram.methods
+        .setJobFundingPool(new anchor.BN(0))
+        .accounts({
+          automationJob: automationJob,
+          fundingPool: null,
+          owner: user1.publicKey,
+        })
+        .signers([user1])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(automationJob);
+      assert.isNull(job.fundingPool);
+      assert.equal(job.poolSpent.toNumber(), 0);
+    });
+  });
+
+  describe("Admin Functions", () => {
+    const [pendingParams] = PublicKey.findProgramAddressSync(
+      [Buffer.from("pending_params")],
+      registryProgram.programId
+    );
+
+    const emptyParams = {
+      baseFee: null,
+      minStake: null,
+      protocolFeeBps: null,
+      maxExecutionsPerWindow: null,
+      rateLimitWindowSlots: null,
+      unregisterCooldown: null,
+      paramsTimelock: null,
+      slashDisputeWindow: null,
+    };
+
+    it("Should queue parameter changes behind the timelock", async () => {
+      await registryProgram.methods
+        .proposeParams({
+          ...emptyParams,
+          baseFee: new anchor.BN(7500),
+          minStake: new anchor.BN(2 * LAMPORTS_PER_SOL),
+          protocolFeeBps: 300,
+        })
+        .accounts({
+          registryState: registryState,
+          pendingParams: pendingParams,
+          proposer: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pending = await registryProgram.account.pendingParams.fetch(pendingParams);
+      assert.equal(pending.proposer.toString(), admin.publicKey.toString());
+      assert.equal(pending.params.baseFee.toNumber(), 7500);
+      assert.equal(
+        pending.executableAt.toNumber() - pending.proposedAt.toNumber(),
+        86400
+      );
+
+      // Nothing changes until the proposal is executed
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      assert.equal(registry.baseFee.toNumber(), 5000);
+    });
+
+    it("Should fail to execute parameters before the timelock expires", async () => {
+      try {
+        await registryProgram.methods
+          .executeParams()
+          .accounts({
+            registryState: registryState,
+            pendingParams: pendingParams,
+            proposer: admin.publicKey,
+          })
+          .rpc();
+
+        assert.fail("Parameters should not apply before the timelock");
+      } catch (error) {
+        assert.include(error.toString(), "TimelockNotExpired");
+      }
+    });
+
+    it("Should fail to cancel parameters as a third party", async () => {
+      try {
+        await registryProgram.methods
+          .cancelParams()
+          .accounts({
+            registryState: registryState,
+            pendingParams: pendingParams,
+            proposer: admin.publicKey,
+            authority: user1.publicKey,
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Only the proposer or admin may cancel");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+
+    it("Should cancel queued parameters", async () => {
+      await registryProgram.methods
+        .cancelParams()
+        .accounts({
+          registryState: registryState,
+          pendingParams: pendingParams,
+          proposer: admin.publicKey,
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pending = await provider.connection.getAccountInfo(pendingParams);
+      assert.isNull(pending);
+    });
+
+    it("Should queue a keeper tier ladder", async () => {
+      const keeperTiers = [
+        { minStake: new anchor.BN(5 * LAMPORTS_PER_SOL), minReputation: new anchor.BN(6000) },
+        { minStake: new anchor.BN(20 * LAMPORTS_PER_SOL), minReputation: new anchor.BN(8000) },
+      ];
+
+      await registryProgram.methods
+        .proposeParams({ ...emptyParams, keeperTiers })
+        .accounts({
+          registryState: registryState,
+          pendingParams: pendingParams,
+          proposer: admin.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([admin])
+        .rpc();
+
+      const pending = await registryProgram.account.pendingParams.fetch(pendingParams);
+      assert.equal(pending.params.keeperTiers.length, 2);
+      assert.equal(pending.params.keeperTiers[1].minStake.toNumber(), 20 * LAMPORTS_PER_SOL);
+
+      await registryProgram.methods
+        .cancelParams()
+        .accounts({
+          registryState: registryState,
+          pendingParams: pendingParams,
+          proposer: admin.publicKey,
+          authority: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("Should reject keeper tiers that are not ascending", async () => {
+      try {
+        await registryProgram.methods
+          .proposeParams({
+            ...emptyParams,
+            keeperTiers: [
+              { minStake: new anchor.BN(20 * LAMPORTS_PER_SOL), minReputation: new anchor.BN(6000) },
+              { minStake: new anchor.BN(5 * LAMPORTS_PER_SOL), minReputation: new anchor.BN(8000) },
+            ],
+          })
+          .accounts({
+            registryState: registryState,
+            pendingParams: pendingParams,
+            proposer: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+
+        assert.fail("Each tier must require more stake than the one below");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
+    });
+
+    it("Should fail to propose fee changes as non-fee-manager", async () => {
+      try {
+        await registryProgram.methods
+          .proposeParams({ ...emptyParams, baseFee: new anchor.BN(10000) })
+          .accounts({
+            registryState: registryState,
+            pendingParams: pendingParams,
+            proposer: user1.publicKey, // Not the fee manager
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Non-fee-manager should not be able to propose fee changes");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+
+    it("Should reject registrations while the registry is paused", async () => {
+      await registryProgram.methods
+        .setRegistryPaused(true)
+        .accounts({
+          registryState: registryState,
+          pauser: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      const [keeperAccount] = getKeeperPDA(user1.publicKey);
+      try {
+        await registryProgram.methods
+          .registerKeeper(new anchor.BN(2 * LAMPORTS_PER_SOL))
+          .accounts({
+            registryState: registryState,
+            keeper: keeperAccount,
+            keeperAccount: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Registrations should be rejected while paused");
+      } catch (error) {
+        assert.include(error.toString(), "RegistryPaused");
+      }
+
+      await registryProgram.methods
+        .setRegistryPaused(false)
+        .accounts({
+          registryState: registryState,
+          pauser: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+    });
+
+    it("Should restrict role assignment to the admin", async () => {
+      await registryProgram.methods
+        .setRole({ slasher: {} }, admin.publicKey)
+        .accounts({
+          registryState: registryState,
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      try {
+        await registryProgram.methods
+          .setRole({ slasher: {} }, user1.publicKey)
+          .accounts({
+            registryState: registryState,
+            admin: user1.publicKey, // Not the admin
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Non-admin should not be able to assign roles");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+    });
+
+    it("Should sweep protocol revenue to the treasury", async () => {
+      try {
+        await registryProgram.methods
+          .withdrawProtocolRevenue()
+          .accounts({
+            registryState: registryState,
+            treasury: treasury.publicKey,
+            feeManager: user1.publicKey, // Not the fee manager
+          })
+          .signers([user1])
+          .rpc();
+
+        assert.fail("Only the fee manager should be able to withdraw revenue");
+      } catch (error) {
+        assert.include(error.toString(), "Unauthorized");
+      }
+
+      // Executions so far have left protocol revenue in the registry account
+      const registryInfo = await provider.connection.getAccountInfo(registryState);
+      const rentExempt = await provider.connection.getMinimumBalanceForRentExemption(registryInfo.data.length);
+      const accumulated = registryInfo.lamports - rentExempt;
+      assert.isAbove(accumulated, 0);
+
+      const initialTreasury = await provider.connection.getBalance(treasury.publicKey);
+
+      await registryProgram.methods
+        .withdrawProtocolRevenue()
+        .accounts({
+          registryState: registryState,
+          treasury: treasury.publicKey,
+          feeManager: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+      assert.equal(
+        await provider.connection.getBalance(treasury.publicKey),
+        initialTreasury + accumulated
+      );
+      assert.equal(await provider.connection.getBalance(registryState), rentExempt);
+    });
+
+    describe("Slashing", () => {
+      const slashAmount = new anchor.BN(LAMPORTS_PER_SOL / 2); // Slash 0.5 SOL
+      let keeperAccount: PublicKey;
+      let pendingSlash: PublicKey;
+      let claimJobId: number;
+      let claimExecutionRecord: PublicKey;
+
+      const getSlashRecordPDA = (slashId: number): [PublicKey, number] => {
+        return PublicKey.findProgramAddressSync(
+          [Buffer.from("slash_record"), new anchor.BN(slashId).toArrayLike(Buffer, "le", 8)],
+          registryProgram.programId
+        );
+      };
+
+      const nextSlashRecord = async () => {
+        const registry = await registryProgram.account.registryState.fetch(registryState);
+        return getSlashRecordPDA(registry.slashCount.toNumber())[0];
+      };
+
+      before(async () => {
+        [keeperAccount] = getKeeperPDA(keeper2.publicKey);
+        [pendingSlash] = PublicKey.findProgramAddressSync(
+          [Buffer.from("pending_slash"), keeperAccount.toBuffer()],
+          registryProgram.programId
+        );
+
+        // Give keeper2 an execution for the insurance claim to compensate
+        const registry = await registryProgram.account.registryState.fetch(registryState);
+        claimJobId = registry.nextJobId.toNumber();
+        const [claimJobAccount] = getAutomationJobPDA(claimJobId);
+
+        await registryProgram.methods
+          .registerJob({
+            targetProgram,
+            targetInstruction: "initialize",
+            triggerType: { timeBased: {} },
+            triggerParams: new anchor.BN(5).toArrayLike(Buffer, "le", 8),
+            gasLimit: new anchor.BN(200_000),
+            minBalance: new anchor.BN(1_000_000),
+            initialFunding: new anchor.BN(100_000_000),
+            metadata: null,
+          })
+          .accounts({
+            registryState: registryState,
+            automationJob: claimJobAccount,
+            owner: user1.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([user1])
+          .rpc();
+
+        await waitUntilDue(claimJobAccount);
+
+        const executingRegistry = await registryProgram.account.registryState.fetch(registryState);
+        [claimExecutionRecord] = getExecutionRecordPDA(
+          claimJobId,
+          executingRegistry.totalExecutions.toNumber()
+        );
+
+        await registryProgram.methods
+          .executeJob(new anchor.BN(claimJobId), Buffer.alloc(0))
+          .accounts({
+            registryState: registryState,
+            automationJob: claimJobAccount,
+            keeper: keeperAccount,
+            executionRecord: claimExecutionRecord,
+            keeperAccount: keeper2.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .remainingAccounts(targetAccounts)
+          .signers([keeper2])
+          .rpc();
+      });
+
+      const proposeSlash = async (reason: string) => {
+        await registryProgram.methods
+          .slashKeeper(keeper2.publicKey, slashAmount, reason)
+          .accounts({
+            registryState: registryState,
+            keeper: keeperAccount,
+            pendingSlash: pendingSlash,
+            slasher: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      };
+
+      const resolveSlash = async (uphold: boolean) => {
+        await registryProgram.methods
+          .resolveSlash(uphold)
+          .accounts({
+            registryState: registryState,
+            keeper: keeperAccount,
+            pendingSlash: pendingSlash,
+            slasher: admin.publicKey,
+            treasury: treasury.publicKey,
+            insurancePool: insurancePool,
+            keeperAccount: keeper2.publicKey,
+            slashRecord: uphold ? await nextSlashRecord() : null,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      };
+
+      it("Should escrow a proposed slash for the dispute window", async () => {
+        const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+
+        await proposeSlash("Test slashing");
+
+        const slashedKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+        assert.equal(
+          slashedKeeper.stakeAmount.toNumber(),
+          keeper.stakeAmount.toNumber() - slashAmount.toNumber()
+        );
+        // Reputation is only cut once the slash is final
+        assert.equal(
+          slashedKeeper.reputationScore.toNumber(),
+          keeper.reputationScore.toNumber()
+        );
+
+        const pending = await registryProgram.account.pendingSlash.fetch(pendingSlash);
+        assert.equal(pending.stakeAmount.toNumber(), slashAmount.toNumber());
+        assert.equal(pending.keeperAddress.toString(), keeper2.publicKey.toString());
+        assert.equal(pending.disputeDeadline.toNumber() - pending.proposedAt.toNumber(), 259200);
+        assert.isNull(pending.appeal);
+      });
+
+      it("Should fail to finalize a slash during the dispute window", async () => {
+        try {
+          await registryProgram.methods
+            .finalizeSlash()
+            .accounts({
+              registryState: registryState,
+              keeper: keeperAccount,
+              pendingSlash: pendingSlash,
+              slasher: admin.publicKey,
+              treasury: treasury.publicKey,
+              insurancePool: insurancePool,
+              slashRecord: await nextSlashRecord(),
+              payer: provider.wallet.publicKey,
+              systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+
+          assert.fail("Slash should not finalize during the dispute window");
+        } catch (error) {
+          assert.include(error.toString(), "DisputeWindowOpen");
+        }
+      });
+
+      it("Should only let the slashed keeper appeal", async () => {
+        try {
+          await registryProgram.methods
+            .appealSlash("Not my keeper")
+            .accounts({
+              pendingSlash: pendingSlash,
+              keeperAccount: keeper1.publicKey,
+            })
+            .signers([keeper1])
+            .rpc();
+
+          assert.fail("Another keeper should not be able to appeal");
+        } catch (error) {
+          assert.include(error.toString(), "Unauthorized");
+        }
+
+        await registryProgram.methods
+          .appealSlash("Execution failed because the target was paused")
+          .accounts({
+            pendingSlash: pendingSlash,
+            keeperAccount: keeper2.publicKey,
+          })
+          .signers([keeper2])
+          .rpc();
+
+        const pending = await registryProgram.account.pendingSlash.fetch(pendingSlash);
+        assert.equal(pending.appeal, "Execution failed because the target was paused");
+      });
+
+      it("Should restore the keeper when the admin rejects an appealed slash", async () => {
+        const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+
+        await resolveSlash(false);
+
+        const restoredKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+        assert.equal(
+          restoredKeeper.stakeAmount.toNumber(),
+          keeper.stakeAmount.toNumber() + slashAmount.toNumber()
+        );
+        assert.isNull(await provider.connection.getAccountInfo(pendingSlash));
+      });
+
+      it("Should slash the keeper when the admin upholds the slash", async () => {
+        const keeper = await registryProgram.account.keeper.fetch(keeperAccount);
+        const initialTreasury = await provider.connection.getBalance(treasury.publicKey);
+        const initialPool = await registryProgram.account.insurancePool.fetch(insurancePool);
+
+        await proposeSlash("Repeated failed executions");
+        await resolveSlash(true);
+
+        const slashedKeeper = await registryProgram.account.keeper.fetch(keeperAccount);
+        assert.equal(
+          slashedKeeper.stakeAmount.toNumber(),
+          keeper.stakeAmount.toNumber() - slashAmount.toNumber()
+        );
+        assert.isTrue(slashedKeeper.reputationScore.toNumber() < keeper.reputationScore.toNumber());
+        assert.isNull(await provider.connection.getAccountInfo(pendingSlash));
+
+        // Half of the slash funds the insurance pool, the rest goes to the treasury
+        const insuranceAmount = slashAmount.toNumber() / 2;
+        const pool = await registryProgram.account.insurancePool.fetch(insurancePool);
+        assert.equal(pool.balance.toNumber(), initialPool.balance.toNumber() + insuranceAmount);
+        assert.equal(
+          await provider.connection.getBalance(treasury.publicKey),
+          initialTreasury + slashAmount.toNumber() - insuranceAmount
+        );
+
+        const registry = await registryProgram.account.registryState.fetch(registryState);
+        assert.equal(registry.slashCount.toNumber(), 1);
+
+        const slashRecord = await registryProgram.account.slashRecord.fetch(getSlashRecordPDA(0)[0]);
+        assert.equal(slashRecord.slashId.toNumber(), 0);
+        assert.equal(slashRecord.keeper.toString(), keeper2.publicKey.toString());
+        assert.equal(slashRecord.amount.toNumber(), slashAmount.toNumber());
+        assert.equal(slashRecord.insuranceAmount.toNumber(), insuranceAmount);
+        assert.isFalse(slashRecord.claimed);
+      });
+
+      const approveClaim = (jobId: number, executionRecord: PublicKey, payout: anchor.BN) => {
+        const [insuranceClaim] = PublicKey.findProgramAddressSync(
+          [Buffer.from("insurance_claim"), executionRecord.toBuffer()],
+          registryProgram.programId
+        );
+
+        return registryProgram.methods
+          .approveInsuranceClaim(new anchor.BN(0), payout)
+          .accounts({
+            registryState: registryState,
+            insurancePool: insurancePool,
+            automationJob: getAutomationJobPDA(jobId)[0],
+            executionRecord: executionRecord,
+            slashRecord: getSlashRecordPDA(0)[0],
+            insuranceClaim: insuranceClaim,
+            owner: user1.publicKey,
+            admin: admin.publicKey,
+            systemProgram: SystemProgram.programId,
+          })
+          .signers([admin])
+          .rpc();
+      };
+
+      it("Should not pay for another keeper's execution from a slash", async () => {
+        // Job 4 was executed by keeper1, but slash 0 penalized keeper2
+        const [executionRecord] = getExecutionRecordPDA(4, 0);
+
+        try {
+          await approveClaim(4, executionRecord, new anchor.BN(LAMPORTS_PER_SOL / 10));
+          assert.fail("Should not back a claim with an unrelated keeper's slash");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidKeeper");
+        }
+      });
+
+      it("Should not pay more than the slash's insurance share", async () => {
+        const slashRecord = await registryProgram.account.slashRecord.fetch(getSlashRecordPDA(0)[0]);
+        const payout = slashRecord.insuranceAmount.addn(1);
+
+        try {
+          await approveClaim(claimJobId, claimExecutionRecord, payout);
+          assert.fail("Should not pay out more than the slash funded");
+        } catch (error) {
+          assert.include(error.toString(), "ClaimExceedsSlash");
+        }
+      });
+
+      it("Should pay a job owner from the insurance pool", async () => {
+        const jobId = claimJobId;
+        const executionRecord = claimExecutionRecord;
+        const [insuranceClaim] = PublicKey.findProgramAddressSync(
+          [Buffer.from("insurance_claim"), executionRecord.toBuffer()],
+          registryProgram.programId
+        );
+        const payout = new anchor.BN(LAMPORTS_PER_SOL / 10);
+        const initialOwnerBalance = await provider.connection.getBalance(user1.publicKey);
+        const initialRegistry = await registryProgram.account.registryState.fetch(registryState);
+
+        await approveClaim(jobId, executionRecord, payout);
+
+        assert.equal(
+          await provider.connection.getBalance(user1.publicKey),
+          initialOwnerBalance + payout.toNumber()
+        );
+        const claim = await registryProgram.account.insuranceClaim.fetch(insuranceClaim);
+        assert.equal(claim.jobId.toNumber(), jobId);
+        assert.equal(claim.keeper.toString(), keeper2.publicKey.toString());
+        assert.equal(claim.slashId.toNumber(), 0);
+
+        const slashRecord = await registryProgram.account.slashRecord.fetch(getSlashRecordPDA(0)[0]);
+        assert.isTrue(slashRecord.claimed);
+
+        const registry = await registryProgram.account.registryState.fetch(registryState);
+        assert.equal(
+          registry.insurancePayouts.toNumber(),
+          initialRegistry.insurancePayouts.toNumber() + payout.toNumber()
+        );
+
+        // Each slash backs a single payout
+        try {
+          await approveClaim(jobId, executionRecord, payout);
+          assert.fail("Should not pay twice from the same slash");
+        } catch (error) {
+          assert.include(error.toString(), "SlashAlreadyClaimed");
+        }
+      });
+    });
+  });
+
+  describe("Trigger Types", () => {
+    const u64Param = (value: number) => new anchor.BN(value).toArrayLike(Buffer, "le", 8);
+
+    const registerTriggerJob = async (triggerType: object, triggerParams: Buffer) => {
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      const jobId = registry.nextJobId.toNumber();
+      const [jobAccount] = getAutomationJobPDA(jobId);
+
+      await registryProgram.methods
+        .registerJob({
+          targetProgram,
+          targetInstruction: "crank",
+          triggerType,
+          triggerParams,
+          gasLimit: new anchor.BN(200_000),
+          minBalance: new anchor.BN(1_000_000),
+          initialFunding: new anchor.BN(10_000_000),
+          metadata: null,
+        })
+        .accounts({
+          registryState: registryState,
+          automationJob: jobAccount,
+          owner: user2.publicKey,
+          systemProgram: SystemProgram.programId,
+        })
+        .signers([user2])
+        .rpc();
+
+      return jobAccount;
+    };
+
+    it("Should register a slot interval job anchored at the current slot", async () => {
+      const slotBefore = await provider.connection.getSlot();
+      const jobAccount = await registerTriggerJob({ slotInterval: {} }, u64Param(100));
+
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.deepEqual(job.triggerType, { slotInterval: {} });
+      assert.isTrue(job.nextScheduledSlot.toNumber() >= slotBefore);
+    });
+
+    it("Should register an epoch start job for the next epoch", async () => {
+      const epochInfo = await provider.connection.getEpochInfo();
+      const jobAccount = await registerTriggerJob({ epochStart: {} }, u64Param(32));
+
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.deepEqual(job.triggerType, { epochStart: {} });
+      assert.isTrue(job.nextEpoch.toNumber() > epochInfo.epoch);
+    });
+
+    it("Should report job status with the execution logic", async () => {
+      const slotJob = await registerTriggerJob({ slotInterval: {} }, u64Param(1_000_000));
+      const epochJob = await registerTriggerJob({ epochStart: {} }, u64Param(0));
+
+      const viewStatus = async (jobAccount: PublicKey) => {
+        const job = await registryProgram.account.automationJob.fetch(jobAccount);
+        const status = await registryProgram.methods
+          .jobStatus(job.jobId)
+          .accounts({ registryState: registryState, automationJob: jobAccount })
+          .view();
+        return { job, status };
+      };
+
+      // Slot jobs are due from the slot they are registered in
+      const slot = await viewStatus(slotJob);
+      assert.isTrue(slot.status.isDue);
+      assert.equal(slot.status.nextDueSlot.toNumber(), slot.job.nextScheduledSlot.toNumber());
+      assert.isNull(slot.status.nextDueAt);
+
+      // Epoch jobs wait for the next boundary
+      const epoch = await viewStatus(epochJob);
+      assert.isFalse(epoch.status.isDue);
+      assert.isTrue(epoch.status.nextDueSlot.toNumber() > (await provider.connection.getSlot()));
+    });
+
+    it("Should not report a job due that execution would reject", async () => {
+      const jobAccount = await registerTriggerJob({ slotInterval: {} }, u64Param(1_000_000));
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+
+      const isDue = async () => {
+        const status = await registryProgram.methods
+          .jobStatus(job.jobId)
+          .accounts({ registryState: registryState, automationJob: jobAccount })
+          .view();
+        return status.isDue;
+      };
+
+      const updateJob = (update: object) =>
+        registryProgram.methods
+          .updateJob({ ...noJobUpdate, ...update })
+          .accounts({
+            automationJob: jobAccount,
+            owner: user2.publicKey,
+          })
+          .signers([user2])
+          .rpc();
+
+      assert.isTrue(await isDue());
+
+      // No keeper was given, so only jobs open to any tier can be due
+      await updateJob({ minKeeperTier: 1 });
+      assert.isFalse(await isDue());
+      await updateJob({ minKeeperTier: 0 });
+
+      // A fee above the owner's cap would be rejected
+      await updateJob({ maxFeePerExecution: new anchor.BN(1) });
+      assert.isFalse(await isDue());
+      await updateJob({ maxFeePerExecution: new anchor.BN(0) });
+
+      // Nor can the job run once its balance only covers the minimum
+      await updateJob({ minBalance: job.balance });
+      assert.isFalse(await isDue());
+    });
+
+    it("Should quote the execution fee and keeper reward", async () => {
+      const jobAccount = await registerTriggerJob({ slotInterval: {} }, u64Param(100));
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+
+      const quote = await registryProgram.methods
+        .quoteExecution(job.jobId)
+        .accounts({ registryState: registryState, automationJob: jobAccount })
+        .view();
+
+      assert.equal(quote.executionFee.toNumber(), registry.baseFee.toNumber());
+      assert.equal(
+        quote.keeperReward.toNumber() + quote.protocolFee.toNumber(),
+        quote.executionFee.toNumber()
+      );
+      assert.isTrue(quote.isFunded);
+    });
+
+    it("Should pay the whole tip to the keeper", async () => {
+      const jobAccount = await registerTriggerJob({ slotInterval: {} }, u64Param(100));
+      const tip = 5_000;
+
+      await registryProgram.methods
+        .updateJob({ ...noJobUpdate, tipLamports: new anchor.BN(tip) })
+        .accounts({
+          automationJob: jobAccount,
+          owner: user2.publicKey,
+        })
+        .signers([user2])
+        .rpc();
+
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      const registry = await registryProgram.account.registryState.fetch(registryState);
+      assert.equal(job.tipLamports.toNumber(), tip);
+
+      const quote = await registryProgram.methods
+        .quoteExecution(job.jobId)
+        .accounts({ registryState: registryState, automationJob: jobAccount })
+        .view();
+
+      const baseFee = registry.baseFee.toNumber();
+      const protocolFee = Math.floor((baseFee * registry.protocolFeeBps) / 10_000);
+      assert.equal(quote.executionFee.toNumber(), baseFee + tip);
+      assert.equal(quote.protocolFee.toNumber(), protocolFee);
+      assert.equal(quote.keeperReward.toNumber(), baseFee - protocolFee + tip);
+    });
+
+    it("Should reject a zero slot interval", async () => {
+      try {
+        await registerTriggerJob({ slotInterval: {} }, u64Param(0));
+        assert.fail("Should reject a zero slot interval");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
+    });
+
+    it("Should register an account change job with an empty data hash", async () => {
+      const jobAccount = await registerTriggerJob({ accountChange: {} }, registryState.toBuffer());
+
+      const job = await registryProgram.account.automationJob.fetch(jobAccount);
+      assert.deepEqual(job.triggerType, { accountChange: {} });
+      assert.deepEqual(Array.from(job.lastDataHash), new Array(32).fill(0));
+    });
+
+    it("Should reject an account change job without a watched account", async () => {
+      try {
+        await registerTriggerJob({ accountChange: {} }, Buffer.alloc(8));
+        assert.fail("Should reject a missing watched account");
+      } catch (error) {
+        assert.include(error.toString(), "InvalidParameters");
+      }
+    });
+
+    describe("Price thresholds", () => {
+      // Fixture price is 150.00000000 (expo -8), published in November 2023
+      const priceAccount = new PublicKey("Bv27yCUkCBMadn46R3vL92BueViGCxGLbAJaKQ4BnBLR");
+      const TEN_YEARS = 10 * 365 * 24 * 60 * 60;
+
+      const priceParams = (comparison: number, threshold: number, maxStaleness: number) =>
+        Buffer.concat([
+          priceAccount.toBuffer(),
+          Buffer.from([comparison]),
+          new anchor.BN(threshold).toTwos(64).toArrayLike(Buffer, "le", 8),
+          u64Param(maxStaleness),
+        ]);
+
+      const executePriceJob = async (jobAccount: PublicKey, remainingAccounts: PublicKey[]) => {
+        const job = await registryProgram.account.automationJob.fetch(jobAccount);
+        const [executionRecord] = getExecutionRecordPDA(job.jobId.toNumber(), 0);
+        const [keeperAccount] = getKeeperPDA(keeper1.publicKey);
+
+        await registryProgram.methods
+          .executeJob(job.jobId, Buffer.alloc(0))
+          .accounts({
+            registryState: registryState,
+            automationJob: jobAccount,
+            keeper: keeperAccount,
+            executionRecord: executionRecord,
+            keeperAccount: keeper1.publicKey,
+            targetProgram: targetProgram,
+            executionAuthority: executionAuthority,
+            executionProgram: executionProgramId,
+            insurancePool: insurancePool,
+            systemProgram: SystemProgram.programId,
+            instructionsSysvar: SYSVAR_INSTRUCTIONS_PUBKEY,
+          })
+          .remainingAccounts(
+            remainingAccounts.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+          )
+          .signers([keeper1])
+          .rpc();
+      };
+
+      it("Should register a price threshold job", async () => {
+        const jobAccount = await registerTriggerJob(
+          { priceThreshold: {} },
+          priceParams(0, 100_00000000, TEN_YEARS)
+        );
+
+        const job = await registryProgram.account.automationJob.fetch(jobAccount);
+        assert.deepEqual(job.triggerType, { priceThreshold: {} });
+      });
+
+      it("Should reject a price threshold job without a max staleness", async () => {
+        try {
+          await registerTriggerJob({ priceThreshold: {} }, priceParams(0, 100_00000000, 0));
+          assert.fail("Should reject a zero max staleness");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidParameters");
+        }
+      });
+
+      it("Should require the price account on execution", async () => {
+        const jobAccount = await registerTriggerJob(
+          { priceThreshold: {} },
+          priceParams(0, 100_00000000, TEN_YEARS)
+        );
+
+        try {
+          await executePriceJob(jobAccount, [user1.publicKey]);
+          assert.fail("Should require the price account");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidTriggerAccount");
+        }
+      });
+
+      it("Should not execute before the price crosses the threshold", async () => {
+        const jobAccount = await registerTriggerJob(
+          { priceThreshold: {} },
+          priceParams(0, 200_00000000, TEN_YEARS)
+        );
+
+        try {
+          await executePriceJob(jobAccount, [priceAccount]);
+          assert.fail("Should not execute below the threshold");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidTrigger");
+        }
+      });
+
+      it("Should reject a stale price", async () => {
+        const jobAccount = await registerTriggerJob(
+          { priceThreshold: {} },
+          priceParams(1, 200_00000000, 60)
+        );
+
+        try {
+          await executePriceJob(jobAccount, [priceAccount]);
+          assert.fail("Should reject a stale price");
+        } catch (error) {
+          assert.include(error.toString(), "StalePrice");
+        }
+      });
+
+      it("Should reject an account that is not a price account", async () => {
+        const jobAccount = await registerTriggerJob(
+          { priceThreshold: {} },
+          Buffer.concat([registryState.toBuffer(), priceParams(0, 0, TEN_YEARS).subarray(32)])
+        );
+
+        try {
+          await executePriceJob(jobAccount, [registryState]);
+          assert.fail("Should reject a non-Pyth account");
+        } catch (error) {
+          assert.include(error.toString(), "InvalidPriceAccount");
+        }
+      });
     });
   });
 });
@@ -167,15 +167,16 @@ export class TestFixture {
     const triggerParams = Buffer.from(JSON.stringify({ interval: 3600 })); // Placeholder
 
     await this.registryProgram.methods
-      .registerJob(
+      .registerJob({
         targetProgram,
         targetInstruction,
         triggerType,
         triggerParams,
-        new anchor.BN(gasLimit),
-        new anchor.BN(1000000), // min balance: 0.001 SOL
-        new anchor.BN(initialFunding)
-      )
+        gasLimit: new anchor.BN(gasLimit),
+        minBalance: new anchor.BN(1000000), // 0.001 SOL
+        initialFunding: new anchor.BN(initialFunding),
+        metadata: null,
+      })
       .accounts({
         registryState: this.registryState,
         automationJob: jobAccount,